@@ -1,13 +1,24 @@
 use crate::aws;
-use crate::aws::client::AwsClients;
-use crate::config::Config;
+use crate::aws::client::{AwsClients, DetailProgress};
+use crate::aws::profiles::Profile;
+use crate::config::{Config, DisplayTimezone};
+use crate::ui::theme::Theme;
 use crossterm::event::KeyCode;
+use ratatui::layout::Rect;
 use crate::resource::{
-    get_resource, get_all_resource_keys, ResourceDef, ResourceFilter, 
-    fetch_resources_paginated, extract_json_value,
+    get_resource, get_all_resource_keys, Dump, ResourceBackend, ResourceDef, ResourceFilter, 
+    fetch_resources_paginated, fetch_across_regions, extract_json_value, format_timestamp,
+    dry_run_action, reveal_value, supports_dry_run, ColumnDef, DryRunOutcome, PaginatedResult, RAW_FIELD,
+    REGION_FIELD, SubResourceSource, items_from_parent, console_url, ShellCommandDef,
 };
 use anyhow::Result;
+use futures_util::future::join_all;
 use serde_json::Value;
+use std::cell::Cell;
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use std::time::Instant;
+use tokio::sync::mpsc;
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum Mode {
@@ -21,6 +32,24 @@ pub enum Mode {
     Describe,    // Viewing JSON details of selected item
     SsoLogin,    // SSO login dialog
     LogTail,     // Tailing CloudWatch logs
+    Metrics,     // CloudWatch metrics sparkline popup
+    Input,       // Text input for an action (e.g., tags)
+    QuickSwitch, // Ctrl+p resource jump
+    ErrorDetail, // Full text of the last error
+    Search,      // :search results across core resources
+    ConfirmQuit, // Quit requested while actions are still running
+    Reveal,      // Popup showing a secret value on request
+    Columns,     // Column visibility picker
+    Favorites,   // ~ favorite resources
+}
+
+/// External command waiting for the main loop to suspend the TUI and run it
+#[derive(Debug, Clone)]
+pub struct PendingShell {
+    /// Program and arguments, with the item's values substituted
+    pub args: Vec<String>,
+    /// Region passed to the command as AWS_REGION
+    pub region: String,
 }
 
 /// Pending action that requires confirmation
@@ -32,6 +61,10 @@ pub struct PendingAction {
     pub sdk_method: String,
     /// Resource ID to act on
     pub resource_id: String,
+    /// Resource name shown in the dialog (falls back to the ID)
+    pub resource_name: String,
+    /// Action name for the status line (e.g., "Stop Instance")
+    pub display_name: String,
     /// Display message for confirmation dialog
     pub message: String,
     /// If true, default selection is No (kept for potential future use)
@@ -41,6 +74,50 @@ pub struct PendingAction {
     pub destructive: bool,
     /// Currently selected option (true = Yes, false = No)
     pub selected_yes: bool,
+    /// If true, the user must type the resource name or "yes" instead of pressing y
+    pub require_typed: bool,
+    /// Result of the last dry run (Ctrl+r in the dialog), for actions that support one
+    pub dry_run: Option<DryRunOutcome>,
+}
+
+impl PendingAction {
+    /// Whether typed confirmation text matches this action's resource
+    pub fn matches_typed(&self, text: &str) -> bool {
+        let text = text.trim();
+        text.eq_ignore_ascii_case("yes") || text == self.resource_name || text == self.resource_id
+    }
+}
+
+/// Action waiting for a value typed by the user
+#[derive(Debug, Clone)]
+pub struct ActionInput {
+    /// Service name (e.g., "ec2")
+    pub service: String,
+    /// SDK method to call with the input (e.g., "create_tags")
+    pub sdk_method: String,
+    /// Resource ID to act on
+    pub resource_id: String,
+    /// Resource name for the status line (falls back to the ID)
+    pub resource_name: String,
+    /// Action name for the status line (e.g., "Edit Tags")
+    pub display_name: String,
+    /// Prompt shown above the input field
+    pub prompt: String,
+    /// Hint shown while the input is empty
+    pub placeholder: Option<String>,
+    /// Text typed so far
+    pub text: String,
+}
+
+/// Secret value shown in the reveal popup (no Debug, so it can't end up in a log line)
+pub struct RevealedValue {
+    /// Resource name the value belongs to
+    pub resource_name: String,
+    /// Action name (e.g., "Reveal Value")
+    pub display_name: String,
+    pub value: String,
+    /// When the popup opened; it closes itself after `REVEAL_DURATION`
+    pub shown_at: Instant,
 }
 
 /// Parent context for hierarchical navigation
@@ -52,6 +129,10 @@ pub struct ParentContext {
     pub item: Value,
     /// Display name for breadcrumb
     pub display_name: String,
+    /// Selection index in the parent list, restored on navigate_back
+    pub selected: usize,
+    /// Filter text in the parent list, restored on navigate_back
+    pub filter_text: String,
 }
 
 pub struct App {
@@ -70,6 +151,8 @@ pub struct App {
     pub mode: Mode,
     pub filter_text: String,
     pub filter_active: bool,
+    filter_history: HashMap<String, Vec<String>>,  // Applied filters per resource key, newest first
+    filter_history_pos: Option<usize>,  // Entry recalled with Up/Down while typing a filter
     
     // Hierarchical navigation
     pub parent_context: Option<ParentContext>,
@@ -80,23 +163,46 @@ pub struct App {
     pub command_suggestions: Vec<String>,
     pub command_suggestion_selected: usize,
     pub command_preview: Option<String>, // Ghost text for hovered suggestion
+    command_history_pos: Option<usize>,  // Entry of config.command_history recalled with Ctrl+p/Ctrl+n
     
     // Profile/Region
     pub profile: String,
     pub region: String,
-    pub available_profiles: Vec<String>,
+    pub available_profiles: Vec<Profile>,
     pub available_regions: Vec<String>,
+    pub all_regions: bool,  // Listing the current resource from every region (:allregions)
     pub profiles_selected: usize,
     pub regions_selected: usize,
+    pub picker_filter: String,  // Narrows the profile or region picker
+    pub picker_filter_active: bool,  // Typing into picker_filter (after `/`)
     
     // Confirmation
     pub pending_action: Option<PendingAction>,
+    pub pending_shell: Option<PendingShell>,  // Run by the main loop with the TUI suspended
+    pub confirm_text: String,  // Typed confirmation for highly destructive actions
+    
+    // Action input (e.g., tag editing)
+    pub action_input: Option<ActionInput>,
     
     // UI state
     pub loading: bool,
+    pub last_fetch: Option<FetchOutcome>,  // None until the current view's first fetch finishes
+    pub sticky_error: Option<String>,  // Banner kept until a list loads, the profile changes or X dismisses it (sticky_errors)
+    pub fetch_progress: Arc<DetailProgress>,  // Describe calls of the fetch in flight
+    pub spinner_frame: usize,
     pub error_message: Option<String>,
+    pub error_detail: Option<String>,  // Untruncated text of the last error, for the `e` popup
+    pub error_scroll: usize,
+    pub status_message: Option<(String, std::time::Instant)>,  // Transient success message for the crumb
     pub describe_scroll: usize,
     pub describe_data: Option<Value>,  // Full resource details from describe API
+    pub describe_projected: bool,      // Show the trimmed table projection instead of the full structure
+    pub describe_formatted: bool,      // Show aligned label/value sections instead of raw JSON
+    
+    // Describe view search
+    pub describe_search_text: String,
+    pub describe_search_active: bool,   // Typing the search term
+    pub describe_search_match: usize,   // Index into the current list of matches
     
     // Auto-refresh
     pub last_refresh: std::time::Instant,
@@ -104,26 +210,118 @@ pub struct App {
     // Persistent configuration
     pub config: Config,
     
+    // Color scheme used by all rendering
+    pub theme: Theme,
+    
+    // Timezone of date columns
+    pub timezone: DisplayTimezone,
+    
     // Key press tracking for sequences (e.g., 'gg')
     pub last_key_press: Option<(KeyCode, std::time::Instant)>,
+    count_prefix: Option<(usize, std::time::Instant)>,  // Pending count typed before a movement key (e.g. 5j)
+    
+    // Mouse support
+    pub mouse_enabled: bool,  // Whether the app captures the mouse (off leaves text selection to the terminal)
+    pub details_panel: bool,  // Show the selected row's fields in a panel beside the table
+    pub hidden_columns: Vec<String>,  // Headers of the current resource's hidden columns
+    pub group_by: Option<usize>,  // Index of the resource column rows are grouped by
+    pub marked: HashSet<String>,      // IDs of the rows marked with Space (for :export)
+    pub columns_selected: usize,      // Cursor in the column picker
+    pub favorites_selected: usize,    // Cursor in the favorites list
+    pub table_layout: Cell<Option<TableLayout>>,  // Set while rendering, used to map clicks to rows
+    pub table_offset: Cell<usize>,  // First visible row of the resource table, kept between frames so it doesn't jump
+    pub last_click: Option<(usize, std::time::Instant)>,  // Row and time of the last click, for double-click
     
     // Read-only mode (blocks all write operations)
     pub readonly: bool,
     
+    // Dump that lists are served from instead of AWS (`--replay`)
+    pub replay: Option<Arc<Dump>>,
+    
     // Warning message for modal dialog
     pub warning_message: Option<String>,
+
+    // Value shown by a reveal action; dropped when the popup closes
+    pub revealed: Option<RevealedValue>,
     
     // Custom endpoint URL (for LocalStack, etc.)
     pub endpoint_url: Option<String>,
     
+    // Account/principal of the active credentials
+    pub identity: Option<CallerIdentity>,
+    
     // SSO login state
     pub sso_state: Option<SsoLoginState>,
     
     // Pagination state
     pub pagination: PaginationState,
+    pagination_before_fetch: Option<PaginationState>,  // Restored when a ]/[ page load is canceled
     
     // Log tail state
     pub log_tail_state: Option<LogTailState>,
+    
+    // Metrics popup state
+    pub metrics_state: Option<MetricsState>,
+    
+    // :search results
+    pub search_state: Option<SearchState>,
+    
+    // Background fetches
+    fetch_tx: mpsc::UnboundedSender<FetchMessage>,
+    fetch_rx: mpsc::UnboundedReceiver<FetchMessage>,
+    fetch_generation: u64,
+    fetch_task: Option<tokio::task::AbortHandle>,  // The fetch in flight, aborted when superseded
+    
+    // Background :search
+    search_tx: mpsc::UnboundedSender<SearchMessage>,
+    search_rx: mpsc::UnboundedReceiver<SearchMessage>,
+    search_generation: u64,
+    
+    // Background actions (start, stop, delete, ...)
+    pub actions_in_flight: usize,
+    action_tx: mpsc::UnboundedSender<ActionMessage>,
+    action_rx: mpsc::UnboundedReceiver<ActionMessage>,
+    
+    // First pages of recently fetched lists, reused within the cache TTL
+    resource_cache: HashMap<CacheKey, CachedPage>,
+    pending_cache_key: Option<CacheKey>,  // Where the in-flight fetch's result will be cached
+}
+
+/// Identifies one list: the same resource with the same parent filters, account and region
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct CacheKey {
+    profile: String,
+    region: String,
+    resource_key: String,
+    filters: Vec<ResourceFilter>,
+}
+
+/// A cached first page of results
+struct CachedPage {
+    items: Vec<Value>,
+    next_token: Option<String>,
+    fetched_at: Instant,
+}
+
+/// How the last list fetch of the current view ended
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FetchOutcome {
+    /// AWS answered with at least one item
+    Items,
+    /// AWS answered successfully with nothing (which scoped permissions can also cause)
+    Empty,
+    /// The call failed; the error is in `error_message`
+    Failed,
+}
+
+impl FetchOutcome {
+    fn of(items: &[Value]) -> Self {
+        if items.is_empty() {
+            FetchOutcome::Empty
+        } else {
+            FetchOutcome::Items
+        }
+    }
 }
 
 /// Pagination state for resource listings
@@ -219,6 +417,306 @@ pub struct LogTailState {
     pub error: Option<String>,
 }
 
+/// Result of a background resource fetch, delivered to the event loop
+pub struct FetchMessage {
+    /// Fetch generation this result belongs to (stale results are dropped)
+    pub generation: u64,
+    pub result: Result<PaginatedResult>,
+    /// (region, error) for regions an all-regions fetch couldn't list
+    pub failed_regions: Vec<(String, String)>,
+}
+
+/// Lists fetched for a `:search`, delivered to the event loop
+pub struct SearchMessage {
+    /// Search generation this result belongs to (stale results are dropped)
+    pub generation: u64,
+    /// Each of `SEARCH_RESOURCES` with its list or the error listing it
+    pub responses: Vec<(&'static str, Result<Vec<Value>>)>,
+}
+
+/// Result of a background action, delivered to the event loop
+pub struct ActionMessage {
+    pub display_name: String,
+    pub resource_name: String,
+    pub result: Result<()>,
+}
+
+/// State for the CloudWatch metrics popup
+#[derive(Debug, Clone)]
+pub struct MetricsState {
+    /// Resource the metric belongs to (e.g., instance ID)
+    pub resource_id: String,
+    /// Metric name (e.g., CPUUtilization)
+    pub metric_name: String,
+    /// Datapoint values in chronological order
+    pub values: Vec<f64>,
+    /// Error message if the fetch failed
+    pub error: Option<String>,
+}
+
+/// How long a status message stays in the crumb
+const STATUS_DURATION: std::time::Duration = std::time::Duration::from_secs(4);
+
+/// How long a revealed secret stays on screen
+pub const REVEAL_DURATION: std::time::Duration = std::time::Duration::from_secs(10);
+
+/// Rows moved by Ctrl+d/u before any table has been rendered
+const DEFAULT_PAGE_SIZE: usize = 10;
+
+/// Share of the table width, in percent, taken by the all-regions REGION column
+const REGION_COLUMN_WIDTH: u16 = 12;
+
+/// Filters remembered per resource for Up/Down recall
+const FILTER_HISTORY_LEN: usize = 20;
+
+/// How long a typed count waits for its movement key before it is dropped
+const COUNT_PREFIX_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(2);
+
+/// Largest count accepted, so a stray run of digits can't overflow
+const MAX_COUNT: usize = 99_999;
+
+/// Resources queried by `:search`
+const SEARCH_RESOURCES: &[&str] = &["ec2-instances", "s3-buckets", "lambda-functions", "rds-instances"];
+
+/// A resource matching a `:search` term
+#[derive(Debug, Clone)]
+pub struct SearchResult {
+    /// Resource the match belongs to (e.g., "s3-buckets")
+    pub resource_key: String,
+    /// Display name of that resource, shown as the KIND column
+    pub kind: String,
+    pub id: String,
+    pub name: String,
+}
+
+/// State for the `:search` results list
+#[derive(Debug, Clone)]
+pub struct SearchState {
+    /// Term that was searched for
+    pub term: String,
+    pub results: Vec<SearchResult>,
+    pub selected: usize,
+    /// Resources that could not be listed (e.g., missing permissions)
+    pub failed: Vec<String>,
+    /// The lists are still being fetched
+    pub searching: bool,
+}
+
+/// Screen position of the visible table rows from the last render
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TableLayout {
+    /// Area covered by data rows (excludes borders and the header)
+    pub rows: Rect,
+    /// Index of the first visible row
+    pub offset: usize,
+}
+
+/// Identity of the credentials in use (from STS GetCallerIdentity)
+#[derive(Debug, Clone)]
+pub struct CallerIdentity {
+    pub account: String,
+    pub arn: String,
+}
+
+impl CallerIdentity {
+    /// Look up the account and principal behind `clients` (None if STS can't be reached)
+    pub async fn fetch(clients: &AwsClients) -> Option<Self> {
+        match crate::resource::invoke_sdk("sts", "get_caller_identity", clients, &Value::Null).await {
+            Ok(response) => response.pointer("/identity/0").map(|identity| Self {
+                account: extract_json_value(identity, "Account"),
+                arn: extract_json_value(identity, "Arn"),
+            }),
+            Err(e) => {
+                tracing::warn!("Failed to fetch caller identity: {}", e);
+                None
+            }
+        }
+    }
+
+    /// Short principal name from the ARN (role name, user name or "root")
+    pub fn principal_name(&self) -> &str {
+        let resource = self.arn.rsplit(':').next().unwrap_or(&self.arn);
+        let mut segments = resource.split('/');
+        match (segments.next(), segments.next()) {
+            (Some("assumed-role" | "user" | "role" | "federated-user"), Some(name)) => name,
+            (Some(other), _) => other,
+            _ => resource,
+        }
+    }
+}
+
+/// Score how well `candidate` matches `query` (lower is better, None = no match)
+///
+/// Substring matches rank by position; otherwise the query characters must
+/// appear in order and rank by how spread out they are.
+fn fuzzy_score(candidate: &str, query: &str) -> Option<usize> {
+    if let Some(pos) = candidate.find(query) {
+        return Some(pos);
+    }
+
+    let mut chars = candidate.char_indices();
+    let mut first = None;
+    let mut last = 0;
+    for q in query.chars() {
+        let (idx, _) = chars.find(|(_, c)| *c == q)?;
+        first.get_or_insert(idx);
+        last = idx;
+    }
+    Some(1000 + last - first.unwrap_or(0))
+}
+
+/// File name for an exported resource ID; ARNs and paths contain `/` and `:`
+fn export_file_stem(id: &str) -> String {
+    id.chars()
+        .map(|c| if c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | '_') { c } else { '_' })
+        .collect()
+}
+
+/// The columns not in `hidden`, their percentage widths scaled up to fill the space of the hidden ones
+fn visible_columns(columns: &[ColumnDef], hidden: &[String]) -> Vec<ColumnDef> {
+    let total: u32 = columns.iter().map(|c| u32::from(c.width)).sum();
+    let mut visible: Vec<ColumnDef> = columns
+        .iter()
+        .filter(|c| !hidden.contains(&c.header))
+        .cloned()
+        .collect();
+    let visible_total: u32 = visible.iter().map(|c| u32::from(c.width)).sum();
+    if visible_total > 0 && visible_total < total {
+        for column in &mut visible {
+            column.width = (u32::from(column.width) * total / visible_total) as u16;
+        }
+    }
+    visible
+}
+
+/// Items whose name or ID contains `filter` (case-insensitive); all items when it is empty
+fn filter_items(items: &[Value], filter: &str, resource: Option<&ResourceDef>) -> Vec<Value> {
+    let filter = filter.to_lowercase();
+    if filter.is_empty() {
+        return items.to_vec();
+    }
+    items
+        .iter()
+        .filter(|item| {
+            // Search in name field and id field
+            if let Some(res) = resource {
+                let name = extract_json_value(item, &res.name_field).to_lowercase();
+                let id = extract_json_value(item, &res.id_field).to_lowercase();
+                name.contains(&filter) || id.contains(&filter)
+            } else {
+                // Fallback: search in JSON string
+                item.to_string().to_lowercase().contains(&filter)
+            }
+        })
+        .cloned()
+        .collect()
+}
+
+/// Stable-sort `items` so rows with the same `column` value sit together,
+/// keeping the existing order within each group
+fn group_items(items: &mut [Value], column: &ColumnDef) {
+    items.sort_by_cached_key(|item| column.value(item));
+}
+
+/// Indexes of the first item of each group in grouped `items`
+fn group_starts(items: &[Value], column: &ColumnDef) -> Vec<usize> {
+    let mut starts = Vec::new();
+    let mut previous = None;
+    for (i, item) in items.iter().enumerate() {
+        let value = column.value(item);
+        if previous.as_ref() != Some(&value) {
+            starts.push(i);
+            previous = Some(value);
+        }
+    }
+    starts
+}
+
+/// Item under table row `row` when a header row sits before each group start
+fn item_at_grouped_row(starts: &[usize], row: usize) -> Option<usize> {
+    let mut headers = 0;
+    for (k, &start) in starts.iter().enumerate() {
+        let header_row = start + k;
+        if header_row == row {
+            return None;
+        }
+        if header_row > row {
+            break;
+        }
+        headers += 1;
+    }
+    Some(row - headers)
+}
+
+/// Whether `region` or its console name contains `filter` (case-insensitive)
+fn region_matches(region: &str, filter: &str) -> bool {
+    let filter = filter.to_lowercase();
+    region.contains(&filter)
+        || aws::profiles::region_name(region).is_some_and(|name| name.to_lowercase().contains(&filter))
+}
+
+/// Row to select after a list is reloaded: the previously selected resource
+/// (`(id_field, id)`) wherever it moved, otherwise the same row clamped to the list
+fn follow_selection(items: &[Value], selected_id: Option<(&str, &str)>, prev_selected: usize) -> usize {
+    selected_id
+        .and_then(|(field, id)| items.iter().position(|item| extract_json_value(item, field) == id))
+        .unwrap_or_else(|| prev_selected.min(items.len().saturating_sub(1)))
+}
+
+/// Items of the listed resources whose name or ID contains `term`
+/// (case-insensitive), and the display names of the resources that failed
+fn search_matches(term: &str, responses: Vec<(&str, Result<Vec<Value>>)>) -> (Vec<SearchResult>, Vec<String>) {
+    let query = term.to_lowercase();
+    let mut results = Vec::new();
+    let mut failed = Vec::new();
+    for (key, response) in responses {
+        let Some(resource) = get_resource(key) else {
+            continue;
+        };
+        match response {
+            Ok(items) => {
+                for item in &items {
+                    let id = extract_json_value(item, &resource.id_field);
+                    let name = extract_json_value(item, &resource.name_field);
+                    if id.to_lowercase().contains(&query) || name.to_lowercase().contains(&query) {
+                        results.push(SearchResult {
+                            resource_key: key.to_string(),
+                            kind: resource.display_name.clone(),
+                            id,
+                            name,
+                        });
+                    }
+                }
+            }
+            Err(e) => {
+                tracing::warn!("Search could not list {}: {}", key, e);
+                failed.push(resource.display_name.clone());
+            }
+        }
+    }
+    (results, failed)
+}
+
+/// Index after `index` in a list of `len` rows, stopping at the end unless `wrap`
+fn step_next(index: usize, len: usize, wrap: bool) -> usize {
+    if len == 0 {
+        index
+    } else if index + 1 >= len {
+        if wrap { 0 } else { len - 1 }
+    } else {
+        index + 1
+    }
+}
+
+/// Index before `index` in a list of `len` rows, stopping at the start unless `wrap`
+fn step_previous(index: usize, len: usize, wrap: bool) -> usize {
+    if index == 0 && wrap && len > 0 {
+        len - 1
+    } else {
+        index.saturating_sub(1)
+    }
+}
+
 impl App {
     /// Create App from pre-initialized components (used with splash screen)
     #[allow(clippy::too_many_arguments)]
@@ -226,7 +724,7 @@ impl App {
         clients: AwsClients,
         profile: String,
         region: String,
-        available_profiles: Vec<String>,
+        available_profiles: Vec<Profile>,
         available_regions: Vec<String>,
         initial_items: Vec<Value>,
         config: Config,
@@ -234,8 +732,13 @@ impl App {
         endpoint_url: Option<String>,
     ) -> Self {
         let filtered_items = initial_items.clone();
+        let (fetch_tx, fetch_rx) = mpsc::unbounded_channel();
+        let (action_tx, action_rx) = mpsc::unbounded_channel();
+        let (search_tx, search_rx) = mpsc::unbounded_channel();
+        let mut clients = clients;
+        clients.apply_config(&config);
         
-        Self {
+        let mut app = Self {
             clients,
             current_resource_key: "ec2-instances".to_string(),
             items: initial_items,
@@ -244,33 +747,87 @@ impl App {
             mode: Mode::Normal,
             filter_text: String::new(),
             filter_active: false,
+            filter_history: HashMap::new(),
+            filter_history_pos: None,
             parent_context: None,
             navigation_stack: Vec::new(),
             command_text: String::new(),
             command_suggestions: Vec::new(),
             command_suggestion_selected: 0,
             command_preview: None,
+            command_history_pos: None,
             profile,
             region,
             available_profiles,
             available_regions,
+            all_regions: false,
             profiles_selected: 0,
             regions_selected: 0,
+            picker_filter: String::new(),
+            picker_filter_active: false,
             pending_action: None,
+            pending_shell: None,
+            confirm_text: String::new(),
+            action_input: None,
             loading: false,
+            last_fetch: None,
+            sticky_error: None,
+            fetch_progress: Arc::default(),
+            spinner_frame: 0,
             error_message: None,
+            error_detail: None,
+            error_scroll: 0,
+            status_message: None,
             describe_scroll: 0,
             describe_data: None,
+            describe_projected: false,
+            describe_formatted: false,
+            describe_search_text: String::new(),
+            describe_search_active: false,
+            describe_search_match: 0,
             last_refresh: std::time::Instant::now(),
+            theme: config.theme(),
+            timezone: config.timezone(),
             config,
             last_key_press: None,
+            count_prefix: None,
+            mouse_enabled: true,
+            details_panel: false,
+            hidden_columns: Vec::new(),
+            group_by: None,
+            favorites_selected: 0,
+            marked: HashSet::new(),
+            columns_selected: 0,
+            table_layout: Cell::new(None),
+            table_offset: Cell::new(0),
+            last_click: None,
             readonly,
+            replay: None,
             warning_message: None,
+            revealed: None,
             endpoint_url,
             sso_state: None,
             pagination: PaginationState::default(),
+            pagination_before_fetch: None,
             log_tail_state: None,
-        }
+            metrics_state: None,
+            search_state: None,
+            identity: None,
+            fetch_tx,
+            fetch_rx,
+            fetch_generation: 0,
+            search_tx,
+            search_rx,
+            search_generation: 0,
+            fetch_task: None,
+            actions_in_flight: 0,
+            action_tx,
+            action_rx,
+            resource_cache: HashMap::new(),
+            pending_cache_key: None,
+        };
+        app.apply_view_prefs();
+        app
     }
     
     /// Check if auto-refresh is needed
@@ -293,6 +850,40 @@ impl App {
         get_resource(&self.current_resource_key)
     }
 
+    /// Where lists come from: the replayed dump, otherwise AWS through `clients`
+    fn backend(&self, clients: AwsClients) -> Arc<dyn ResourceBackend> {
+        match &self.replay {
+            Some(dump) => dump.clone(),
+            None => Arc::new(clients),
+        }
+    }
+
+    /// Fail in replay mode, for operations that need AWS credentials
+    fn ensure_live(&self) -> Result<()> {
+        if self.replay.is_some() {
+            anyhow::bail!("Not available in replay mode");
+        }
+        Ok(())
+    }
+
+    /// Restore the saved view preferences (details panel, ...) of the current resource
+    fn apply_view_prefs(&mut self) {
+        let prefs = self.config.view_prefs(&self.current_resource_key);
+        self.details_panel = prefs.details_panel.unwrap_or(false);
+        self.hidden_columns = prefs.hidden_columns;
+        self.group_by = None;
+    }
+
+    /// Show or hide the details panel and remember the choice for this resource
+    pub fn toggle_details_panel(&mut self) {
+        self.details_panel = !self.details_panel;
+        let mut prefs = self.config.view_prefs(&self.current_resource_key);
+        prefs.details_panel = Some(self.details_panel);
+        if let Err(e) = self.config.set_view_prefs(&self.current_resource_key, prefs) {
+            tracing::warn!("Failed to save view preferences: {}", e);
+        }
+    }
+
     /// Get available commands for autocomplete
     pub fn get_available_commands(&self) -> Vec<String> {
         let mut commands: Vec<String> = get_all_resource_keys()
@@ -303,6 +894,7 @@ impl App {
         // Add profiles and regions commands
         commands.push("profiles".to_string());
         commands.push("regions".to_string());
+        commands.push("allregions".to_string());
         
         commands.sort();
         commands
@@ -313,13 +905,102 @@ impl App {
     // =========================================================================
 
     /// Fetch data for current resource (first page or current page based on pagination state)
+    ///
+    /// A first page fetched within the cache TTL is reused instead of calling AWS.
     pub async fn refresh_current(&mut self) -> Result<()> {
+        if self.serve_from_parent() {
+            return Ok(());
+        }
         // Fetch the current page (uses pagination.next_token if set by next_page/prev_page)
-        self.fetch_page(self.pagination.next_token.clone()).await
+        let page_token = self.pagination.next_token.clone();
+        if page_token.is_none() && !self.all_regions && self.serve_from_cache() {
+            return Ok(());
+        }
+        self.fetch_page(page_token).await
+    }
+
+    /// Refetch the current resource from AWS, dropping every cached list
+    /// (used by `R` and after actions, which may have changed any list)
+    pub async fn force_refresh_current(&mut self) -> Result<()> {
+        self.resource_cache.clear();
+        self.refresh_current().await
+    }
+
+    fn cache_key(&self) -> CacheKey {
+        CacheKey {
+            profile: self.profile.clone(),
+            region: self.region.clone(),
+            resource_key: self.current_resource_key.clone(),
+            filters: self.build_filters_from_context(),
+        }
+    }
+
+    /// Show the cached first page of the current resource if it is fresh enough
+    fn serve_from_cache(&mut self) -> bool {
+        let ttl = self.config.cache_ttl();
+        if ttl.is_zero() {
+            return false;
+        }
+        let Some(cached) = self
+            .resource_cache
+            .get(&self.cache_key())
+            .filter(|cached| cached.fetched_at.elapsed() < ttl)
+        else {
+            return false;
+        };
+        let items = cached.items.clone();
+        let next_token = cached.next_token.clone();
+
+        // A fetch still in flight for another view must not overwrite this one
+        self.abandon_fetch();
+        self.pending_cache_key = None;
+        self.loading = false;
+        self.error_message = None;
+        self.last_fetch = Some(FetchOutcome::of(&items));
+        self.apply_page(items, next_token);
+        true
     }
     
-    /// Fetch a specific page of resources
+    /// Show a sub-resource whose items are already in the parent item (`"source": "parent"`)
+    fn serve_from_parent(&mut self) -> bool {
+        let Some(parent) = &self.parent_context else {
+            return false;
+        };
+        let Some(json_path) = get_resource(&parent.resource_key)
+            .and_then(|r| r.sub_resources.iter().find(|s| s.resource_key == self.current_resource_key))
+            .filter(|sub| sub.source == SubResourceSource::Parent)
+            .and_then(|sub| sub.json_path.as_deref())
+        else {
+            return false;
+        };
+        let result = items_from_parent(&self.current_resource_key, &parent.item, json_path);
+
+        self.abandon_fetch();
+        self.pending_cache_key = None;
+        self.loading = false;
+        self.error_message = None;
+        match result {
+            Ok(items) => {
+                self.last_fetch = Some(FetchOutcome::of(&items));
+                self.apply_page(items, None);
+            }
+            Err(e) => {
+                self.last_fetch = Some(FetchOutcome::Failed);
+                self.record_error(format!("{:#}", e), &e);
+                self.clear_items();
+            }
+        }
+        self.mark_refreshed();
+        true
+    }
+
+    /// Start fetching a specific page of resources in the background
+    ///
+    /// The result is delivered through the fetch channel and applied by
+    /// `poll_fetch_results`, so the UI keeps redrawing while AWS responds.
     async fn fetch_page(&mut self, page_token: Option<String>) -> Result<()> {
+        self.last_fetch = None;
+        self.pagination_before_fetch = None;
         if self.current_resource().is_none() {
             self.error_message = Some(format!("Unknown resource: {}", self.current_resource_key));
             return Ok(());
@@ -328,46 +1009,211 @@ impl App {
         self.loading = true;
         self.error_message = None;
 
+        // Any result from an earlier fetch is now stale
+        self.abandon_fetch();
+        let generation = self.fetch_generation;
+
+        // Only first pages are cached; later pages depend on the token chain
+        self.pending_cache_key = (page_token.is_none() && !self.all_regions).then(|| self.cache_key());
+
         // Build filters from parent context
         let filters = self.build_filters_from_context();
-        
-        // Use paginated fetch - returns only one page of results
-        match fetch_resources_paginated(
-            &self.current_resource_key, 
-            &self.clients, 
-            &filters,
-            page_token.as_deref(),
-        ).await {
-            Ok(result) => {
-                // Preserve selection if possible
-                let prev_selected = self.selected;
-                self.items = result.items;
-                self.apply_filter();
-                
-                // Update pagination state
-                self.pagination.has_more = result.next_token.is_some();
-                self.pagination.next_token = result.next_token;
-                
-                // Try to keep the same selection index
-                if prev_selected < self.filtered_items.len() {
-                    self.selected = prev_selected;
-                } else {
-                    self.selected = 0;
-                }
+        let resource_key = self.current_resource_key.clone();
+        // A fresh counter, so a stale fetch can't move this one's progress
+        let mut clients = self.clients.clone();
+        clients.progress = Arc::default();
+        self.fetch_progress = clients.progress.clone();
+        let tx = self.fetch_tx.clone();
+
+        if self.all_regions {
+            let backends: Vec<(String, AwsClients)> = self
+                .available_regions
+                .iter()
+                .map(|region| (region.clone(), clients.for_region(region)))
+                .collect();
+            let task = tokio::spawn(async move {
+                let fetched = fetch_across_regions(&backends, &resource_key, &filters).await;
+                let result = Ok(PaginatedResult { items: fetched.items, next_token: None });
+                let _ = tx.send(FetchMessage { generation, result, failed_regions: fetched.failed });
+            });
+            self.fetch_task = Some(task.abort_handle());
+            return Ok(());
+        }
+
+        let backend = self.backend(clients);
+        let task = tokio::spawn(async move {
+            // Use paginated fetch - returns only one page of results
+            let result = fetch_resources_paginated(
+                &resource_key,
+                backend.as_ref(),
+                &filters,
+                page_token.as_deref(),
+            ).await;
+            let _ = tx.send(FetchMessage { generation, result, failed_regions: Vec::new() });
+        });
+        self.fetch_task = Some(task.abort_handle());
+
+        Ok(())
+    }
+
+    /// Drop the fetch in flight: abort its task and ignore anything it already sent
+    fn abandon_fetch(&mut self) {
+        self.fetch_generation += 1;
+        if let Some(task) = self.fetch_task.take() {
+            task.abort();
+        }
+    }
+
+    /// Stop waiting for the list being loaded (Esc while loading)
+    pub fn cancel_fetch(&mut self) {
+        if !self.loading {
+            return;
+        }
+        self.abandon_fetch();
+        self.pending_cache_key = None;
+        self.loading = false;
+        // A canceled ]/[ stays on the page that is shown
+        if let Some(pagination) = self.pagination_before_fetch.take() {
+            self.pagination = pagination;
+        }
+        self.set_status("Loading canceled - R loads again".to_string());
+    }
+
+    /// Apply any finished background fetches (called from the event loop)
+    pub fn poll_fetch_results(&mut self) {
+        while let Ok(message) = self.fetch_rx.try_recv() {
+            if message.generation != self.fetch_generation {
+                continue;
             }
-            Err(e) => {
-                self.error_message = Some(aws::client::format_aws_error(&e));
-                // Clear items to prevent mismatch between current_resource_key and stale items
-                self.items.clear();
-                self.filtered_items.clear();
-                self.selected = 0;
-                self.pagination = PaginationState::default();
+            self.fetch_task = None;
+            self.pagination_before_fetch = None;
+
+            let cache_key = self.pending_cache_key.take();
+            match message.result {
+                Ok(result) => {
+                    if let Some(key) = cache_key.filter(|_| !self.config.cache_ttl().is_zero()) {
+                        self.resource_cache.insert(key, CachedPage {
+                            items: result.items.clone(),
+                            next_token: result.next_token.clone(),
+                            fetched_at: Instant::now(),
+                        });
+                    }
+                    self.last_fetch = Some(FetchOutcome::of(&result.items));
+                    // A list loading again means the problem behind the banner is gone
+                    self.sticky_error = None;
+                    self.apply_page(result.items, result.next_token);
+                    self.report_failed_regions(&message.failed_regions);
+                }
+                Err(e) => {
+                    self.last_fetch = Some(FetchOutcome::Failed);
+                    self.record_error(aws::client::format_aws_error(&e, &self.profile), &e);
+                    // Clear items to prevent mismatch between current_resource_key and stale items
+                    self.clear_items();
+                    self.pagination = PaginationState::default();
+                }
             }
+
+            self.loading = false;
+            self.mark_refreshed();
         }
+
+        // The spinner also shows in the status line while actions run
+        if self.loading || self.actions_in_flight > 0 {
+            self.spinner_frame = self.spinner_frame.wrapping_add(1);
+        }
+    }
+
+    /// Show which regions an all-regions fetch couldn't list (the rest are still shown)
+    fn report_failed_regions(&mut self, failed: &[(String, String)]) {
+        if failed.is_empty() {
+            return;
+        }
+        let regions: Vec<&str> = failed.iter().map(|(region, _)| region.as_str()).collect();
+        self.error_message = Some(format!(
+            "Couldn't list {} region(s): {}",
+            failed.len(),
+            regions.join(", ")
+        ));
+        self.error_detail = Some(
+            failed
+                .iter()
+                .map(|(region, err)| format!("{}: {}", region, err))
+                .collect::<Vec<_>>()
+                .join("\n"),
+        );
+    }
+
+    /// Show a page of results, keeping the same resource selected where possible
+    fn apply_page(&mut self, items: Vec<Value>, next_token: Option<String>) {
+        let prev_selected = self.selected;
+        let id_field = self.current_resource().map(|r| r.id_field.as_str());
+        let prev_id = id_field
+            .zip(self.selected_item())
+            .map(|(field, item)| extract_json_value(item, field))
+            .filter(|id| id != "-");
+
+        // The applied filter (filter_text) is kept and re-applied to the new items
+        self.items = items;
+        self.apply_filter();
         
-        self.loading = false;
-        self.mark_refreshed();
-        Ok(())
+        // Update pagination state
+        self.pagination.has_more = next_token.is_some();
+        self.pagination.next_token = next_token;
+        
+        self.selected = follow_selection(&self.filtered_items, id_field.zip(prev_id.as_deref()), prev_selected);
+    }
+
+    /// Run an action in the background; the result is applied by `poll_action_results`
+    pub fn start_action(&mut self, service: &str, sdk_method: &str, resource_id: &str, display_name: &str, resource_name: &str) {
+        self.actions_in_flight += 1;
+
+        let service = service.to_string();
+        let sdk_method = sdk_method.to_string();
+        let resource_id = resource_id.to_string();
+        let display_name = display_name.to_string();
+        let resource_name = resource_name.to_string();
+        let clients = self.clients.clone();
+        let tx = self.action_tx.clone();
+
+        tokio::spawn(async move {
+            let result = crate::resource::execute_action(&service, &sdk_method, &clients, &resource_id).await;
+            let _ = tx.send(ActionMessage { display_name, resource_name, result });
+        });
+    }
+
+    /// Report finished background actions and refresh the list they changed
+    pub async fn poll_action_results(&mut self) {
+        let mut finished = false;
+        while let Ok(message) = self.action_rx.try_recv() {
+            self.actions_in_flight = self.actions_in_flight.saturating_sub(1);
+            finished = true;
+            match message.result {
+                Ok(()) => self.report_action_success(&message.display_name, &message.resource_name),
+                Err(e) => self.record_error(format!("Action failed: {}", aws::client::format_aws_error(&e, &self.profile)), &e),
+            }
+        }
+        if finished {
+            let _ = self.force_refresh_current().await;
+        }
+    }
+
+    /// Handle a quit request: returns true to quit now, or asks first while
+    /// actions are still running (their outcome would otherwise be unknown)
+    pub fn request_quit(&mut self) -> bool {
+        if self.actions_in_flight == 0 {
+            return true;
+        }
+        self.mode = Mode::ConfirmQuit;
+        false
+    }
+
+    /// Drop the current rows (e.g. when switching to a different resource type)
+    fn clear_items(&mut self) {
+        self.items.clear();
+        self.filtered_items.clear();
+        self.marked.clear();
+        self.selected = 0;
+        self.table_offset.set(0);
     }
     
     /// Fetch next page of resources
@@ -376,13 +1222,20 @@ impl App {
             return Ok(());
         }
         
+        // Pages pressed while one is loading go back to the page still shown
+        let shown = self.pagination_before_fetch.take().unwrap_or_else(|| self.pagination.clone());
+
         // Save current token to stack for going back
         let current_token = self.pagination.next_token.clone();
         self.pagination.token_stack.push(current_token.clone());
         self.pagination.current_page += 1;
         
         // Fetch next page
-        self.fetch_page(current_token).await
+        self.fetch_page(current_token).await?;
+        if self.loading {
+            self.pagination_before_fetch = Some(shown);
+        }
+        Ok(())
     }
     
     /// Fetch previous page of resources
@@ -391,13 +1244,19 @@ impl App {
             return Ok(());
         }
         
+        let shown = self.pagination_before_fetch.take().unwrap_or_else(|| self.pagination.clone());
+
         // Pop the previous token from stack
         self.pagination.token_stack.pop(); // Remove current page's token
         let prev_token = self.pagination.token_stack.pop().flatten(); // Get previous page's token
         self.pagination.current_page -= 1;
         
         // Fetch previous page
-        self.fetch_page(prev_token).await
+        self.fetch_page(prev_token).await?;
+        if self.loading {
+            self.pagination_before_fetch = Some(shown);
+        }
+        Ok(())
     }
     
     /// Reset pagination state (call when navigating to new resource)
@@ -491,28 +1350,9 @@ impl App {
 
     /// Apply text filter to items
     pub fn apply_filter(&mut self) {
-        let filter = self.filter_text.to_lowercase();
-
-        if filter.is_empty() {
-            self.filtered_items = self.items.clone();
-        } else {
-            let resource = self.current_resource();
-            self.filtered_items = self
-                .items
-                .iter()
-                .filter(|item| {
-                    // Search in name field and id field
-                    if let Some(res) = resource {
-                        let name = extract_json_value(item, &res.name_field).to_lowercase();
-                        let id = extract_json_value(item, &res.id_field).to_lowercase();
-                        name.contains(&filter) || id.contains(&filter)
-                    } else {
-                        // Fallback: search in JSON string
-                        item.to_string().to_lowercase().contains(&filter)
-                    }
-                })
-                .cloned()
-                .collect();
+        self.filtered_items = filter_items(&self.items, &self.filter_text, self.current_resource());
+        if let Some(column) = self.group_column().cloned() {
+            group_items(&mut self.filtered_items, &column);
         }
 
         // Adjust selection
@@ -523,6 +1363,38 @@ impl App {
 
     pub fn toggle_filter(&mut self) {
         self.filter_active = !self.filter_active;
+        self.filter_history_pos = None;
+    }
+
+    /// Remember the current filter for this resource (on Enter)
+    pub fn record_filter(&mut self) {
+        self.filter_history_pos = None;
+        let filter = self.filter_text.trim().to_string();
+        if filter.is_empty() {
+            return;
+        }
+        let history = self.filter_history.entry(self.current_resource_key.clone()).or_default();
+        history.retain(|f| *f != filter);
+        history.insert(0, filter);
+        history.truncate(FILTER_HISTORY_LEN);
+    }
+
+    /// Recall an older (Up) or newer (Down) filter for this resource and apply it.
+    /// Going past the newest entry clears the filter.
+    pub fn recall_filter(&mut self, older: bool) {
+        let Some(history) = self.filter_history.get(&self.current_resource_key) else {
+            return;
+        };
+        let pos = match (self.filter_history_pos, older) {
+            (None, true) => Some(0),
+            (None, false) => return,
+            (Some(pos), true) => Some((pos + 1).min(history.len() - 1)),
+            (Some(0), false) => None,
+            (Some(pos), false) => Some(pos - 1),
+        };
+        self.filter_text = pos.map(|pos| history[pos].clone()).unwrap_or_default();
+        self.filter_history_pos = pos;
+        self.apply_filter();
     }
 
     pub fn clear_filter(&mut self) {
@@ -544,20 +1416,67 @@ impl App {
         self.filtered_items.get(self.selected)
     }
 
+    /// The value shown in Describe (full details, or the table projection)
+    pub fn describe_value(&self) -> Option<Value> {
+        if self.describe_projected {
+            // Trimmed projection as shown in the table
+            self.selected_item().map(|item| {
+                let mut item = item.clone();
+                if let Some(map) = item.as_object_mut() {
+                    map.remove(RAW_FIELD);
+                }
+                item
+            })
+        } else {
+            // Use describe_data if available (full details), otherwise fall back to the
+            // untouched API item kept alongside the list row
+            self.describe_data
+                .as_ref()
+                .or_else(|| self.selected_item())
+                .map(|data| data.get(RAW_FIELD).unwrap_or(data).clone())
+        }
+    }
+
     pub fn selected_item_json(&self) -> Option<String> {
-        // Use describe_data if available (full details), otherwise fall back to list data
-        if let Some(ref data) = self.describe_data {
-            return Some(serde_json::to_string_pretty(data).unwrap_or_default());
+        self.describe_value()
+            .map(|v| serde_json::to_string_pretty(&v).unwrap_or_default())
+    }
+
+    /// Plain text of each Describe line in the current view (raw JSON or formatted)
+    pub fn describe_text_lines(&self) -> Vec<String> {
+        if self.describe_formatted {
+            self.describe_value()
+                .map(|value| {
+                    crate::ui::details::formatted_lines(&value, &self.theme)
+                        .iter()
+                        .map(|line| line.spans.iter().map(|s| s.content.as_ref()).collect())
+                        .collect()
+                })
+                .unwrap_or_default()
+        } else {
+            self.selected_item_json()
+                .map(|json| json.lines().map(str::to_string).collect())
+                .unwrap_or_default()
         }
-        self.selected_item()
-            .map(|item| serde_json::to_string_pretty(item).unwrap_or_default())
+    }
+
+    /// Toggle Describe between the full structure and the trimmed table projection
+    pub fn toggle_describe_projection(&mut self) {
+        self.describe_projected = !self.describe_projected;
+        self.describe_scroll = 0;
+        self.describe_search_match = 0;
+    }
+
+    /// Toggle Describe between raw JSON and the formatted sections
+    pub fn toggle_describe_format(&mut self) {
+        self.describe_formatted = !self.describe_formatted;
+        self.describe_scroll = 0;
+        self.describe_search_match = 0;
     }
 
     /// Get the number of lines in the describe content
     pub fn describe_line_count(&self) -> usize {
-        self.selected_item_json()
-            .map(|s| s.lines().count())
-            .unwrap_or(0)
+        self.describe_text_lines().len()
     }
 
     /// Clamp describe scroll to valid range
@@ -574,44 +1493,162 @@ impl App {
         self.describe_scroll = total.saturating_sub(visible_lines);
     }
 
+    /// Line indices in the describe content containing the search term (case-insensitive)
+    pub fn describe_search_matches(&self) -> Vec<usize> {
+        if self.describe_search_text.is_empty() {
+            return Vec::new();
+        }
+        let term = self.describe_search_text.to_ascii_lowercase();
+        self.describe_text_lines()
+            .iter()
+            .enumerate()
+            .filter(|(_, line)| line.to_ascii_lowercase().contains(&term))
+            .map(|(i, _)| i)
+            .collect()
+    }
+
+    /// Confirm the describe search term and jump to the first match
+    pub fn confirm_describe_search(&mut self) {
+        self.describe_search_active = false;
+        self.describe_search_match = 0;
+        if let Some(&line) = self.describe_search_matches().first() {
+            self.describe_scroll = line;
+        }
+    }
+
+    /// Jump to the next describe search match (wraps around)
+    pub fn describe_next_match(&mut self) {
+        let matches = self.describe_search_matches();
+        if matches.is_empty() {
+            return;
+        }
+        self.describe_search_match = (self.describe_search_match + 1) % matches.len();
+        self.describe_scroll = matches[self.describe_search_match];
+    }
+
+    /// Jump to the previous describe search match (wraps around)
+    pub fn describe_prev_match(&mut self) {
+        let matches = self.describe_search_matches();
+        if matches.is_empty() {
+            return;
+        }
+        self.describe_search_match = if self.describe_search_match == 0 {
+            matches.len() - 1
+        } else {
+            self.describe_search_match - 1
+        };
+        self.describe_scroll = matches[self.describe_search_match];
+    }
+
+    pub fn clear_describe_search(&mut self) {
+        self.describe_search_text.clear();
+        self.describe_search_active = false;
+        self.describe_search_match = 0;
+    }
+
     pub fn next(&mut self) {
+        let wrap = self.config.wrap_navigation();
         match self.mode {
             Mode::Profiles => {
-                if !self.available_profiles.is_empty() {
-                    self.profiles_selected = (self.profiles_selected + 1).min(self.available_profiles.len() - 1);
-                }
+                self.profiles_selected = step_next(self.profiles_selected, self.visible_profiles().len(), wrap);
             }
             Mode::Regions => {
-                if !self.available_regions.is_empty() {
-                    self.regions_selected = (self.regions_selected + 1).min(self.available_regions.len() - 1);
+                self.regions_selected = step_next(self.regions_selected, self.visible_regions().len(), wrap);
+            }
+            Mode::Search => {
+                if let Some(search) = &mut self.search_state {
+                    search.selected = step_next(search.selected, search.results.len(), wrap);
                 }
             }
             _ => {
-                if !self.filtered_items.is_empty() {
-                    self.selected = (self.selected + 1).min(self.filtered_items.len() - 1);
-                }
+                self.selected = step_next(self.selected, self.filtered_items.len(), wrap);
             }
         }
     }
 
     pub fn previous(&mut self) {
+        let wrap = self.config.wrap_navigation();
         match self.mode {
             Mode::Profiles => {
-                self.profiles_selected = self.profiles_selected.saturating_sub(1);
+                self.profiles_selected = step_previous(self.profiles_selected, self.visible_profiles().len(), wrap);
             }
             Mode::Regions => {
-                self.regions_selected = self.regions_selected.saturating_sub(1);
+                self.regions_selected = step_previous(self.regions_selected, self.visible_regions().len(), wrap);
+            }
+            Mode::Search => {
+                if let Some(search) = &mut self.search_state {
+                    search.selected = step_previous(search.selected, search.results.len(), wrap);
+                }
             }
             _ => {
-                self.selected = self.selected.saturating_sub(1);
+                self.selected = step_previous(self.selected, self.filtered_items.len(), wrap);
             }
         }
     }
 
+    /// Row index under a screen position in the last rendered table
+    pub fn row_at(&self, column: u16, row: u16) -> Option<usize> {
+        let layout = self.table_layout.get()?;
+        let rows = layout.rows;
+        if column < rows.x || column >= rows.right() || row < rows.y || row >= rows.bottom() {
+            return None;
+        }
+        let mut index = layout.offset + (row - rows.y) as usize;
+        if matches!(self.mode, Mode::Normal) && self.group_by.is_some() {
+            index = item_at_grouped_row(&self.group_starts(), index)?;
+        }
+        let len = match self.mode {
+            Mode::Profiles => self.visible_profiles().len(),
+            Mode::Regions => self.visible_regions().len(),
+            Mode::Search => self.search_state.as_ref().map_or(0, |s| s.results.len()),
+            _ => self.filtered_items.len(),
+        };
+        (index < len).then_some(index)
+    }
+
+    /// Append a digit to the pending count (starting over if the previous one timed out)
+    pub fn push_count_digit(&mut self, digit: u32) {
+        let current = self.pending_count().unwrap_or(0);
+        let count = (current * 10 + digit as usize).min(MAX_COUNT);
+        self.count_prefix = Some((count, std::time::Instant::now()));
+    }
+
+    /// The count typed so far, if it hasn't timed out
+    pub fn pending_count(&self) -> Option<usize> {
+        self.count_prefix
+            .filter(|(_, typed_at)| typed_at.elapsed() < COUNT_PREFIX_TIMEOUT)
+            .map(|(count, _)| count)
+    }
+
+    /// Consume the pending count; any key uses it up, whether or not it moves
+    pub fn take_count(&mut self) -> Option<usize> {
+        let count = self.pending_count();
+        self.count_prefix = None;
+        count.filter(|count| *count > 0)
+    }
+
+    pub fn select_row(&mut self, index: usize) {
+        match self.mode {
+            Mode::Profiles => self.profiles_selected = index,
+            Mode::Regions => self.regions_selected = index,
+            Mode::Search => {
+                if let Some(search) = &mut self.search_state {
+                    search.selected = index;
+                }
+            }
+            _ => self.selected = index,
+        }
+    }
+
     pub fn go_to_top(&mut self) {
         match self.mode {
             Mode::Profiles => self.profiles_selected = 0,
             Mode::Regions => self.regions_selected = 0,
+            Mode::Search => {
+                if let Some(search) = &mut self.search_state {
+                    search.selected = 0;
+                }
+            }
             _ => self.selected = 0,
         }
     }
@@ -619,13 +1656,14 @@ impl App {
     pub fn go_to_bottom(&mut self) {
         match self.mode {
             Mode::Profiles => {
-                if !self.available_profiles.is_empty() {
-                    self.profiles_selected = self.available_profiles.len() - 1;
-                }
+                self.profiles_selected = self.visible_profiles().len().saturating_sub(1);
             }
             Mode::Regions => {
-                if !self.available_regions.is_empty() {
-                    self.regions_selected = self.available_regions.len() - 1;
+                self.regions_selected = self.visible_regions().len().saturating_sub(1);
+            }
+            Mode::Search => {
+                if let Some(search) = &mut self.search_state {
+                    search.selected = search.results.len().saturating_sub(1);
                 }
             }
             _ => {
@@ -636,16 +1674,27 @@ impl App {
         }
     }
 
+    /// Rows visible in the last rendered table, so paging moves one screen at a time
+    pub fn page_size(&self) -> usize {
+        self.table_layout
+            .get()
+            .map(|layout| layout.rows.height as usize)
+            .filter(|&height| height > 0)
+            .unwrap_or(DEFAULT_PAGE_SIZE)
+    }
+
     pub fn page_down(&mut self, page_size: usize) {
         match self.mode {
             Mode::Profiles => {
-                if !self.available_profiles.is_empty() {
-                    self.profiles_selected = (self.profiles_selected + page_size).min(self.available_profiles.len() - 1);
+                let len = self.visible_profiles().len();
+                if len > 0 {
+                    self.profiles_selected = (self.profiles_selected + page_size).min(len - 1);
                 }
             }
             Mode::Regions => {
-                if !self.available_regions.is_empty() {
-                    self.regions_selected = (self.regions_selected + page_size).min(self.available_regions.len() - 1);
+                let len = self.visible_regions().len();
+                if len > 0 {
+                    self.regions_selected = (self.regions_selected + page_size).min(len - 1);
                 }
             }
             _ => {
@@ -676,12 +1725,46 @@ impl App {
 
     pub fn enter_command_mode(&mut self) {
         self.mode = Mode::Command;
+        self.command_history_pos = None;
         self.command_text.clear();
         self.command_suggestions = self.get_available_commands();
         self.command_suggestion_selected = 0;
         self.command_preview = None;
     }
 
+    /// Open the resource-only quick switcher (shares the command box state)
+    pub fn enter_quick_switch_mode(&mut self) {
+        self.mode = Mode::QuickSwitch;
+        self.command_text.clear();
+        self.command_suggestion_selected = 0;
+        self.update_quick_switch_suggestions();
+    }
+
+    /// Fuzzy-filter resource keys: substring matches first, then subsequence matches
+    pub fn update_quick_switch_suggestions(&mut self) {
+        let query = self.command_text.to_lowercase();
+        let mut scored: Vec<(usize, &str)> = get_all_resource_keys()
+            .into_iter()
+            .filter_map(|key| fuzzy_score(key, &query).map(|score| (score, key)))
+            .collect();
+        scored.sort();
+
+        self.command_suggestions = scored.into_iter().map(|(_, key)| key.to_string()).collect();
+        if self.command_suggestion_selected >= self.command_suggestions.len() {
+            self.command_suggestion_selected = 0;
+        }
+        self.update_preview();
+    }
+
+    /// Navigate to the highlighted quick-switch suggestion
+    pub async fn execute_quick_switch(&mut self) -> Result<()> {
+        let Some(resource_key) = self.command_preview.clone() else {
+            return Ok(());
+        };
+        self.exit_mode();
+        self.navigate_to_resource(&resource_key).await
+    }
+
     pub fn update_command_suggestions(&mut self) {
         let input = self.command_text.to_lowercase();
         let all_commands = self.get_available_commands();
@@ -713,6 +1796,33 @@ impl App {
         }
     }
 
+    /// Typing after a recall starts a new command: Ctrl+p goes back to the newest entry
+    pub fn command_edited(&mut self) {
+        self.command_history_pos = None;
+        self.update_command_suggestions();
+    }
+
+    /// Recall an older (Ctrl+p) or newer (Ctrl+n) command from the history.
+    /// Going past the newest entry clears the command line.
+    pub fn recall_command(&mut self, older: bool) {
+        let history = &self.config.command_history;
+        if history.is_empty() {
+            return;
+        }
+        let pos = match (self.command_history_pos, older) {
+            (None, true) => Some(0),
+            (None, false) => return,
+            (Some(pos), true) => Some((pos + 1).min(history.len() - 1)),
+            (Some(0), false) => None,
+            (Some(pos), false) => Some(pos - 1),
+        };
+        self.command_text = pos.map(|pos| history[pos].clone()).unwrap_or_default();
+        self.command_history_pos = pos;
+        self.update_command_suggestions();
+        // Run the recalled text as typed, not a suggestion it happens to match
+        self.command_preview = None;
+    }
+
     pub fn next_suggestion(&mut self) {
         if !self.command_suggestions.is_empty() {
             self.command_suggestion_selected = 
@@ -754,8 +1864,10 @@ impl App {
         self.mode = Mode::Describe;
         self.describe_scroll = 0;
         self.describe_data = None;
+        self.clear_describe_search();
         
         // Get the selected item's ID
+        let clients = self.clients_for_selected();
         if let Some(item) = self.selected_item().cloned() {
             if let Some(resource_def) = self.current_resource() {
                 // Check if this resource has a detail_sdk_method defined
@@ -775,7 +1887,7 @@ impl App {
                     match crate::resource::invoke_sdk(
                         &resource_def.service,
                         detail_method,
-                        &self.clients,
+                        &clients,
                         &serde_json::Value::Object(params),
                     ).await {
                         Ok(data) => {
@@ -792,7 +1904,7 @@ impl App {
                     if id != "-" && !id.is_empty() {
                         match crate::resource::describe_resource(
                             &self.current_resource_key,
-                            &self.clients,
+                            &clients,
                             &id,
                         ).await {
                             Ok(data) => {
@@ -812,6 +1924,7 @@ impl App {
     /// Enter confirmation mode for an action
     pub fn enter_confirm_mode(&mut self, pending: PendingAction) {
         self.pending_action = Some(pending);
+        self.confirm_text.clear();
         self.mode = Mode::Confirm;
     }
     
@@ -820,6 +1933,61 @@ impl App {
         self.warning_message = Some(message.to_string());
         self.mode = Mode::Warning;
     }
+
+    /// Show a success message in the crumb for a few seconds
+    pub fn set_status(&mut self, message: String) {
+        self.status_message = Some((message, std::time::Instant::now()));
+    }
+
+    /// The status message, while it is still fresh
+    pub fn status(&self) -> Option<&str> {
+        self.status_message
+            .as_ref()
+            .filter(|(_, at)| at.elapsed() < STATUS_DURATION)
+            .map(|(message, _)| message.as_str())
+    }
+
+    /// Confirm on the status line that an action call went through
+    pub fn report_action_success(&mut self, display_name: &str, resource_name: &str) {
+        self.set_status(format!("{} succeeded for '{}'", display_name, resource_name));
+    }
+
+    /// Show `message` in the crumb and keep the full error text for the detail popup
+    pub fn record_error(&mut self, message: String, err: &anyhow::Error) {
+        if self.config.sticky_errors() {
+            self.sticky_error = Some(message.clone());
+        }
+        self.error_message = Some(message);
+        self.error_detail = Some(format!("{:#}", err));
+    }
+
+    /// Hide the sticky error banner
+    pub fn dismiss_sticky_error(&mut self) {
+        self.sticky_error = None;
+    }
+
+    /// Log in again with SSO for the current profile (the profile is re-initialized on success)
+    pub fn start_sso_relogin(&mut self) {
+        match aws::sso::get_sso_config(&self.profile) {
+            Some(config) => {
+                let profile = self.profile.clone();
+                self.error_message = None;
+                self.enter_sso_login_mode(&profile, &config.sso_session);
+            }
+            None => {
+                let message = format!("Profile '{}' doesn't use SSO", self.profile);
+                self.show_warning(&message);
+            }
+        }
+    }
+
+    /// Open the full text of the last error (no-op if nothing has failed)
+    pub fn enter_error_detail_mode(&mut self) {
+        if self.error_detail.is_some() {
+            self.error_scroll = 0;
+            self.mode = Mode::ErrorDetail;
+        }
+    }
     
     /// Enter SSO login mode to prompt for browser authentication
     pub fn enter_sso_login_mode(&mut self, profile: &str, sso_session: &str) {
@@ -847,40 +2015,306 @@ impl App {
         
         let message = config.message.unwrap_or_else(|| action.display_name.clone());
         let default_no = !config.default_yes;
-        
         Some(PendingAction {
             service: self.current_resource()?.service.clone(),
             sdk_method: action.sdk_method.clone(),
             resource_id: resource_id.to_string(),
             message: format!("{} '{}'?", message, resource_name),
+            resource_name,
+            display_name: action.display_name.clone(),
             default_no,
             destructive: config.destructive,
             selected_yes: config.default_yes, // Start with default selection
+            require_typed: config.typed,
+            dry_run: None,
         })
     }
 
-    pub fn enter_profiles_mode(&mut self) {
-        self.profiles_selected = self
-            .available_profiles
-            .iter()
-            .position(|p| p == &self.profile)
-            .unwrap_or(0);
-        self.mode = Mode::Profiles;
+    /// Check with a dry run whether the pending action would be authorized
+    pub async fn dry_run_pending_action(&mut self) {
+        let Some(pending) = &self.pending_action else {
+            return;
+        };
+        if !supports_dry_run(&pending.service, &pending.sdk_method) {
+            return;
+        }
+        let outcome = dry_run_action(
+            &pending.service,
+            &pending.sdk_method,
+            &self.clients,
+            &pending.resource_id,
+        )
+        .await
+        .unwrap_or_else(|e| DryRunOutcome::Refused(format!("Dry run failed: {}", aws::client::format_aws_error(&e, &self.profile))));
+        if let Some(pending) = &mut self.pending_action {
+            pending.dry_run = Some(outcome);
+        }
+    }
+
+    /// Fetch a secret value for a reveal action and show it in a popup
+    pub async fn reveal_value(&mut self, service: &str, sdk_method: &str, resource_id: &str, display_name: &str, resource_name: &str) {
+        match reveal_value(service, sdk_method, &self.clients, resource_id).await {
+            Ok(value) => {
+                self.revealed = Some(RevealedValue {
+                    resource_name: resource_name.to_string(),
+                    display_name: display_name.to_string(),
+                    value,
+                    shown_at: Instant::now(),
+                });
+                self.mode = Mode::Reveal;
+            }
+            Err(e) => self.record_error(format!("{} failed: {}", display_name, aws::client::format_aws_error(&e, &self.profile)), &e),
+        }
+    }
+
+    /// Close the reveal popup once it has been open for `REVEAL_DURATION`
+    pub fn expire_revealed(&mut self) {
+        if self.mode == Mode::Reveal
+            && self.revealed.as_ref().is_none_or(|r| r.shown_at.elapsed() >= REVEAL_DURATION)
+        {
+            self.exit_mode();
+        }
+    }
+
+    /// Prompt for a value before running an input action on the selected resource
+    pub fn enter_input_mode(&mut self, action: &crate::resource::ActionDef, resource_id: &str) {
+        let (Some(input), Some(resource)) = (&action.input, self.current_resource()) else {
+            return;
+        };
+        let name = self.selected_item()
+            .map(|item| extract_json_value(item, &resource.name_field))
+            .filter(|name| name != "-" && !name.is_empty())
+            .unwrap_or_else(|| resource_id.to_string());
+
+        self.action_input = Some(ActionInput {
+            service: resource.service.clone(),
+            sdk_method: action.sdk_method.clone(),
+            resource_id: resource_id.to_string(),
+            prompt: format!("{} '{}'", input.prompt, name),
+            resource_name: name,
+            display_name: action.display_name.clone(),
+            placeholder: input.placeholder.clone(),
+            text: String::new(),
+        });
+        self.mode = Mode::Input;
+    }
+
+    /// Run the pending input action with the typed value
+    pub async fn submit_action_input(&mut self) -> Result<()> {
+        let Some(input) = self.action_input.take() else {
+            return Ok(());
+        };
+        self.mode = Mode::Normal;
+
+        if self.readonly {
+            self.show_warning("This operation is not supported in read-only mode");
+            return Ok(());
+        }
+
+        match crate::resource::execute_action_with_input(
+            &input.service,
+            &input.sdk_method,
+            &self.clients,
+            &input.resource_id,
+            &input.text,
+        ).await {
+            Ok(Some(output)) => {
+                // Show the action's output in the Describe view
+                self.mode = Mode::Describe;
+                self.describe_scroll = 0;
+                self.describe_projected = false;
+                self.describe_data = Some(output);
+                self.clear_describe_search();
+                Ok(())
+            }
+            Ok(None) => {
+                self.report_action_success(&input.display_name, &input.resource_name);
+                self.force_refresh_current().await
+            }
+            Err(e) => {
+                self.record_error(format!("Action failed: {}", aws::client::format_aws_error(&e, &self.profile)), &e);
+                Ok(())
+            }
+        }
+    }
+
+    pub fn enter_profiles_mode(&mut self) {
+        // Re-read ~/.aws so profiles added while running show up without a restart
+        self.available_profiles = crate::aws::profiles::list_profiles();
+        self.picker_filter.clear();
+        self.picker_filter_active = false;
+        self.profiles_selected = self
+            .available_profiles
+            .iter()
+            .position(|p| p.name == self.profile)
+            .unwrap_or(0);
+        self.mode = Mode::Profiles;
+    }
+
+    pub fn enter_regions_mode(&mut self) {
+        self.picker_filter.clear();
+        self.picker_filter_active = false;
+        self.regions_selected = self
+            .available_regions
+            .iter()
+            .position(|r| r == &self.region)
+            .unwrap_or(0);
+        self.mode = Mode::Regions;
+    }
+
+    /// Profiles shown in the profile picker: those whose name contains the picker filter
+    pub fn visible_profiles(&self) -> Vec<&Profile> {
+        let filter = self.picker_filter.to_lowercase();
+        self.available_profiles
+            .iter()
+            .filter(|profile| profile.name.to_lowercase().contains(&filter))
+            .collect()
+    }
+
+    /// Regions shown in the region picker: those whose code or name contains the picker filter
+    pub fn visible_regions(&self) -> Vec<String> {
+        self.available_regions
+            .iter()
+            .filter(|region| region_matches(region, &self.picker_filter))
+            .cloned()
+            .collect()
+    }
+
+    /// Narrow the open picker as the filter changes, starting again from its first row
+    pub fn set_picker_filter(&mut self, filter: String) {
+        self.picker_filter = filter;
+        self.profiles_selected = 0;
+        self.regions_selected = 0;
+    }
+
+    /// Open the column picker on the first column
+    pub fn enter_columns_mode(&mut self) {
+        if self.current_resource().is_some() {
+            self.columns_selected = 0;
+            self.mode = Mode::Columns;
+        }
+    }
+
+    /// Show or hide the column under the picker cursor and remember it for this resource
+    ///
+    /// The last visible column can't be hidden.
+    pub fn toggle_selected_column(&mut self) {
+        let Some(resource) = self.current_resource() else {
+            return;
+        };
+        let Some(header) = resource.columns.get(self.columns_selected).map(|c| c.header.clone()) else {
+            return;
+        };
+        if let Some(pos) = self.hidden_columns.iter().position(|h| *h == header) {
+            self.hidden_columns.remove(pos);
+        } else if self.hidden_columns.len() + 1 < resource.columns.len() {
+            self.hidden_columns.push(header);
+        } else {
+            return;
+        }
+
+        let mut prefs = self.config.view_prefs(&self.current_resource_key);
+        prefs.hidden_columns = self.hidden_columns.clone();
+        if let Err(e) = self.config.set_view_prefs(&self.current_resource_key, prefs) {
+            tracing::warn!("Failed to save view preferences: {}", e);
+        }
+    }
+
+    /// The column rows are grouped by, if any
+    pub fn group_column(&self) -> Option<&ColumnDef> {
+        self.current_resource()?.columns.get(self.group_by?)
+    }
+
+    /// Indexes in `filtered_items` where each group starts (empty when not grouped)
+    pub fn group_starts(&self) -> Vec<usize> {
+        self.group_column()
+            .map(|column| group_starts(&self.filtered_items, column))
+            .unwrap_or_default()
+    }
+
+    /// Group rows by the column under the picker cursor, or stop grouping if it already is
+    pub fn toggle_group_by_selected_column(&mut self) {
+        let Some(header) = self
+            .current_resource()
+            .and_then(|r| r.columns.get(self.columns_selected))
+            .map(|c| c.header.clone())
+        else {
+            return;
+        };
+        let selected_id = self.current_resource().and_then(|r| {
+            self.selected_item().map(|item| (r.id_field.clone(), extract_json_value(item, &r.id_field)))
+        });
+
+        if self.group_by == Some(self.columns_selected) {
+            self.group_by = None;
+            self.set_status("Grouping cleared".to_string());
+        } else {
+            self.group_by = Some(self.columns_selected);
+            self.set_status(format!("Grouped by {}", header));
+        }
+
+        self.apply_filter();
+        let selected_id = selected_id.as_ref().map(|(field, id)| (field.as_str(), id.as_str()));
+        self.selected = follow_selection(&self.filtered_items, selected_id, self.selected);
+    }
+
+    /// Add the current resource to the favorites, or remove it
+    ///
+    /// Only top-level lists can be favorites: a sub-resource list needs its parent.
+    pub fn toggle_favorite(&mut self) {
+        if self.parent_context.is_some() {
+            self.set_status("Only top-level resource lists can be favorites".to_string());
+            return;
+        }
+        let key = self.current_resource_key.clone();
+        let Some(name) = self.current_resource().map(|r| r.display_name.clone()) else {
+            return;
+        };
+        match self.config.toggle_favorite_and_save(&key) {
+            Ok(true) => self.set_status(format!("{} added to favorites (~)", name)),
+            Ok(false) => self.set_status(format!("{} removed from favorites", name)),
+            Err(e) => self.record_error(format!("Failed to save favorites: {}", e), &e),
+        }
     }
 
-    pub fn enter_regions_mode(&mut self) {
-        self.regions_selected = self
-            .available_regions
+    /// Open the favorites list on the current resource, if it is one
+    pub fn enter_favorites_mode(&mut self) {
+        if self.config.favorites.is_empty() {
+            self.set_status("No favorites yet - press F on a resource list to add it".to_string());
+            return;
+        }
+        self.favorites_selected = self
+            .config
+            .favorites
             .iter()
-            .position(|r| r == &self.region)
+            .position(|f| *f == self.current_resource_key)
             .unwrap_or(0);
-        self.mode = Mode::Regions;
+        self.mode = Mode::Favorites;
+    }
+
+    /// Go to the favorite at `index`
+    pub async fn open_favorite(&mut self, index: usize) -> Result<()> {
+        let Some(resource_key) = self.config.favorites.get(index).cloned() else {
+            return Ok(());
+        };
+        self.exit_mode();
+        if get_resource(&resource_key).is_none() {
+            self.error_message = Some(format!("Unknown resource: {}", resource_key));
+            return Ok(());
+        }
+        self.navigate_to_resource(&resource_key).await
     }
 
     pub fn exit_mode(&mut self) {
         self.mode = Mode::Normal;
+        self.picker_filter.clear();
+        self.picker_filter_active = false;
         self.pending_action = None;
+        self.confirm_text.clear();
+        self.action_input = None;
         self.describe_data = None;  // Clear describe data when exiting
+        self.clear_describe_search();
+        self.revealed = None;
     }
 
     // =========================================================================
@@ -895,10 +2329,12 @@ impl App {
         }
         
         // Clear parent context when navigating to top-level resource
+        self.all_regions = false;
         self.parent_context = None;
         self.navigation_stack.clear();
         self.current_resource_key = resource_key.to_string();
-        self.selected = 0;
+        self.apply_view_prefs();
+        self.clear_items();
         self.filter_text.clear();
         self.filter_active = false;
         self.mode = Mode::Normal;
@@ -912,6 +2348,10 @@ impl App {
 
     /// Navigate to sub-resource with parent context
     pub async fn navigate_to_sub_resource(&mut self, sub_resource_key: &str) -> Result<()> {
+        if self.all_regions {
+            self.show_warning("Press Esc to leave the all-regions view before opening sub-resources");
+            return Ok(());
+        }
         let Some(selected_item) = self.selected_item().cloned() else {
             return Ok(());
         };
@@ -962,11 +2402,14 @@ impl App {
             resource_key: self.current_resource_key.clone(),
             item: selected_item,
             display_name: display,
+            selected: self.selected,
+            filter_text: self.filter_text.clone(),
         });
         
         // Navigate
         self.current_resource_key = sub_resource_key.to_string();
-        self.selected = 0;
+        self.apply_view_prefs();
+        self.clear_items();
         self.filter_text.clear();
         self.filter_active = false;
         
@@ -977,16 +2420,22 @@ impl App {
         Ok(())
     }
 
-    /// Navigate back to parent resource
+    /// Navigate back to parent resource (or out of the all-regions view)
     pub async fn navigate_back(&mut self) -> Result<()> {
+        if self.all_regions {
+            return self.leave_all_regions().await;
+        }
         if let Some(parent) = self.parent_context.take() {
             // Pop from navigation stack if available
             self.parent_context = self.navigation_stack.pop();
             
-            // Navigate to parent resource
+            // Navigate to parent resource, restoring where the user was.
+            // The fetch keeps `selected` when the reloaded list is long enough.
             self.current_resource_key = parent.resource_key;
-            self.selected = 0;
-            self.filter_text.clear();
+            self.apply_view_prefs();
+            self.clear_items();
+            self.selected = parent.selected;
+            self.filter_text = parent.filter_text;
             self.filter_active = false;
             
             // Reset pagination for parent resource
@@ -997,6 +2446,77 @@ impl App {
         Ok(())
     }
 
+    /// List the current top-level resource from every region at once (`:allregions`)
+    pub async fn show_all_regions(&mut self) -> Result<()> {
+        let Some(resource) = self.current_resource() else {
+            return Ok(());
+        };
+        if resource.is_global {
+            self.error_message = Some(format!("{} is global; :allregions is for regional resources", resource.display_name));
+            return Ok(());
+        }
+        if self.parent_context.is_some() {
+            self.error_message = Some("Go back to a top-level list to use :allregions".to_string());
+            return Ok(());
+        }
+        if let Err(e) = self.ensure_live() {
+            self.record_error(e.to_string(), &e);
+            return Ok(());
+        }
+
+        self.all_regions = true;
+        self.clear_items();
+        self.reset_pagination();
+        self.fetch_page(None).await
+    }
+
+    /// Return from the all-regions view to the current region's list
+    pub async fn leave_all_regions(&mut self) -> Result<()> {
+        self.all_regions = false;
+        self.clear_items();
+        self.reset_pagination();
+        self.refresh_current().await
+    }
+
+    /// Columns of the current table; the all-regions view adds a REGION column first
+    pub fn table_columns(&self) -> Vec<ColumnDef> {
+        let Some(resource) = self.current_resource() else {
+            return Vec::new();
+        };
+        let mut columns = visible_columns(&resource.columns, &self.hidden_columns);
+        if self.all_regions {
+            // The other columns give up the REGION column's share of the width
+            for column in &mut columns {
+                column.width = column.width * (100 - REGION_COLUMN_WIDTH) / 100;
+            }
+            columns.insert(0, ColumnDef {
+                header: "REGION".to_string(),
+                json_path: REGION_FIELD.to_string(),
+                width: REGION_COLUMN_WIDTH,
+                flex: false,
+                max_width: None,
+                color_map: None,
+                format: None,
+                age_warn_days: None,
+                template: None,
+            });
+        }
+        columns
+    }
+
+    /// Clients for the selected row: its own region in the all-regions view
+    fn clients_for_selected(&self) -> AwsClients {
+        let region = self
+            .selected_item()
+            .filter(|_| self.all_regions)
+            .map(|item| extract_json_value(item, REGION_FIELD))
+            .filter(|region| region != "-");
+        match region {
+            Some(region) => self.clients.for_region(&region),
+            None => self.clients.clone(),
+        }
+    }
+
     /// Get breadcrumb path
     pub fn get_breadcrumb(&self) -> Vec<String> {
         let mut path = Vec::new();
@@ -1019,23 +2539,78 @@ impl App {
     // Profile/Region Switching
     // =========================================================================
 
-    pub async fn switch_region(&mut self, region: &str) -> Result<()> {
-        let actual_region = self.clients.switch_region(&self.profile, region).await?;
+    /// Switch to `region`, first checking the profile can reach it (see `verify_region_switch`).
+    /// On failure the current region is kept, the reason is shown as an error and
+    /// false is returned, so callers shouldn't refresh (that would clear the error).
+    pub async fn switch_region(&mut self, region: &str) -> Result<bool> {
+        let mut clients = self.clients.clone();
+        let checked = async {
+            let actual_region = clients.switch_region(&self.profile, region).await?;
+            if self.config.verify_region_switch() {
+                // Cheap regional call: fails for disabled opt-in regions and bad credentials
+                crate::resource::invoke_sdk("sts", "get_caller_identity", &clients, &Value::Null).await?;
+            }
+            Ok::<_, anyhow::Error>(actual_region)
+        }
+        .await;
+
+        let actual_region = match checked {
+            Ok(actual_region) => actual_region,
+            Err(e) => {
+                self.record_error(
+                    format!(
+                        "Can't use region {} with profile {}: {}",
+                        region,
+                        self.profile,
+                        aws::client::format_aws_error(&e, &self.profile)
+                    ),
+                    &e,
+                );
+                return Ok(false);
+            }
+        };
+        self.clients = clients;
         self.region = actual_region.clone();
+        self.all_regions = false;
         
         // Save to config (log errors but don't fail region switch)
         if let Err(e) = self.config.set_region(&actual_region) {
             tracing::warn!("Failed to save region to config: {}", e);
         }
         
-        Ok(())
+        Ok(true)
     }
 
-    pub async fn switch_profile(&mut self, profile: &str) -> Result<()> {
-        let (new_clients, actual_region) = AwsClients::new(profile, &self.region, self.endpoint_url.clone()).await?;
-        self.clients = new_clients;
+    /// Fetch the account and principal for the current credentials
+    pub async fn refresh_identity(&mut self) {
+        self.identity = CallerIdentity::fetch(&self.clients).await;
+    }
+
+    /// Region to use after switching to `profile`: its configured region, else the current one
+    fn region_for_profile(&self, profile: &str) -> String {
+        self.available_profiles
+            .iter()
+            .find(|p| p.name == profile)
+            .and_then(|p| p.region.clone())
+            .unwrap_or_else(|| self.region.clone())
+    }
+
+    /// Start using the clients of a newly switched (or logged in) profile
+    fn use_clients(&mut self, clients: AwsClients, profile: &str, region: &str) {
+        self.clients = clients;
+        self.clients.apply_config(&self.config);
         self.profile = profile.to_string();
-        self.region = actual_region.clone();
+        self.region = region.to_string();
+        // The banner was about the old credentials (e.g. an expired SSO session)
+        self.sticky_error = None;
+    }
+
+    pub async fn switch_profile(&mut self, profile: &str) -> Result<()> {
+        self.ensure_live()?;
+        let region = self.region_for_profile(profile);
+        let (new_clients, actual_region) = AwsClients::new(profile, &region, self.endpoint_url.clone()).await?;
+        self.use_clients(new_clients, profile, &actual_region);
+        self.refresh_identity().await;
         
         // Save to config (log errors but don't fail profile switch)
         if let Err(e) = self.config.set_profile(profile) {
@@ -1052,11 +2627,12 @@ impl App {
     pub async fn switch_profile_with_sso_check(&mut self, profile: &str) -> Result<ProfileSwitchResult> {
         use crate::aws::client::ClientResult;
         
-        match AwsClients::new_with_sso_check(profile, &self.region, self.endpoint_url.clone()).await? {
+        self.ensure_live()?;
+        let region = self.region_for_profile(profile);
+        match AwsClients::new_with_sso_check(profile, &region, self.endpoint_url.clone()).await? {
             ClientResult::Ok(new_clients, actual_region) => {
-                self.clients = new_clients;
-                self.profile = profile.to_string();
-                self.region = actual_region.clone();
+                self.use_clients(new_clients, profile, &actual_region);
+                self.refresh_identity().await;
                 
                 // Save to config (log errors but don't fail profile switch)
                 if let Err(e) = self.config.set_profile(profile) {
@@ -1076,8 +2652,8 @@ impl App {
 
     /// Select profile - returns true if SSO login is required
     pub async fn select_profile(&mut self) -> Result<bool> {
-        if let Some(profile) = self.available_profiles.get(self.profiles_selected) {
-            let profile = profile.clone();
+        if let Some(profile) = self.visible_profiles().get(self.profiles_selected) {
+            let profile = profile.name.clone();
             match self.switch_profile_with_sso_check(&profile).await? {
                 ProfileSwitchResult::Success => {
                     self.refresh_current().await?;
@@ -1097,10 +2673,11 @@ impl App {
     }
 
     pub async fn select_region(&mut self) -> Result<()> {
-        if let Some(region) = self.available_regions.get(self.regions_selected) {
+        if let Some(region) = self.visible_regions().get(self.regions_selected) {
             let region = region.clone();
-            self.switch_region(&region).await?;
-            self.refresh_current().await?;
+            if self.switch_region(&region).await? {
+                self.refresh_current().await?;
+            }
         }
         self.exit_mode();
         Ok(())
@@ -1131,6 +2708,10 @@ impl App {
             return Ok(false);
         }
 
+        if let Err(e) = self.config.push_command_history(&parts.join(" ")) {
+            tracing::warn!("Failed to save command history: {}", e);
+        }
+
         let cmd = parts[0];
 
         match cmd {
@@ -1145,13 +2726,29 @@ impl App {
                 self.enter_regions_mode();
             }
             "region" if parts.len() > 1 => {
-                self.switch_region(parts[1]).await?;
-                self.refresh_current().await?;
+                if self.switch_region(parts[1]).await? {
+                    self.refresh_current().await?;
+                }
             }
             "profile" if parts.len() > 1 => {
                 self.switch_profile(parts[1]).await?;
                 self.refresh_current().await?;
             }
+            "search" if parts.len() > 1 => {
+                self.run_search(&parts[1..].join(" "));
+            }
+            "dump" if parts.len() > 1 => {
+                self.dump_to_file(parts[1]);
+            }
+            "yank" => {
+                self.yank_table();
+            }
+            "export" if parts.len() > 1 => {
+                self.export_items(parts[1]);
+            }
+            "allregions" => {
+                self.show_all_regions().await?;
+            }
             _ => {
                 // Check if it's a known resource
                 if get_resource(cmd).is_some() {
@@ -1175,6 +2772,253 @@ impl App {
         Ok(false)
     }
 
+    // =========================================================================
+    // Dump
+    // =========================================================================
+
+    /// Write the current list and every cached list for this profile and region
+    /// to `path`, in the format `--replay` reads
+    pub fn dump_to_file(&mut self, path: &str) {
+        let mut dump = Dump {
+            profile: self.profile.clone(),
+            region: self.region.clone(),
+            lists: Vec::new(),
+        };
+        for (key, cached) in &self.resource_cache {
+            if key.profile == self.profile && key.region == self.region {
+                dump.add(&key.resource_key, &key.filters, cached.items.clone());
+            }
+        }
+        // The list on screen wins over an older cached copy
+        dump.add(&self.current_resource_key, &self.build_filters_from_context(), self.items.clone());
+
+        let count = dump.lists.len();
+        match dump.save(std::path::Path::new(path)) {
+            Ok(()) => self.set_status(format!("Wrote {} list(s) to {} (open with --replay)", count, path)),
+            Err(e) => self.record_error(format!("Dump failed: {}", e), &e),
+        }
+    }
+
+    // =========================================================================
+    // Marking and export
+    // =========================================================================
+
+    /// ID of `item` under the current resource's id_field
+    fn item_id(&self, item: &Value) -> Option<String> {
+        let resource = self.current_resource()?;
+        Some(extract_json_value(item, &resource.id_field)).filter(|id| id != "-" && !id.is_empty())
+    }
+
+    /// Whether `item` is marked for export
+    pub fn is_marked(&self, item: &Value) -> bool {
+        !self.marked.is_empty() && self.item_id(item).is_some_and(|id| self.marked.contains(&id))
+    }
+
+    /// Mark or unmark the selected row, then move to the next one
+    pub fn toggle_mark(&mut self) {
+        let Some(id) = self.selected_item().and_then(|item| self.item_id(item)) else {
+            return;
+        };
+        if !self.marked.remove(&id) {
+            self.marked.insert(id);
+        }
+        self.next();
+    }
+
+    /// Write the full JSON of each marked row (or the selected one) to `<dir>/<id>.json`
+    ///
+    /// The untouched API item kept with each row is written, so no further calls are made.
+    pub fn export_items(&mut self, dir: &str) {
+        let items: Vec<&Value> = if self.marked.is_empty() {
+            self.selected_item().into_iter().collect()
+        } else {
+            self.items.iter().filter(|item| self.is_marked(item)).collect()
+        };
+        if items.is_empty() {
+            self.error_message = Some("Nothing to export".to_string());
+            return;
+        }
+
+        let dir = std::path::Path::new(dir);
+        let result = std::fs::create_dir_all(dir).map_err(anyhow::Error::from).and_then(|()| {
+            for item in &items {
+                let id = self.item_id(item).unwrap_or_else(|| "item".to_string());
+                let json = serde_json::to_string_pretty(item.get(RAW_FIELD).unwrap_or(item))?;
+                std::fs::write(dir.join(format!("{}.json", export_file_stem(&id))), json)?;
+            }
+            Ok(())
+        });
+        match result {
+            Ok(()) => self.set_status(format!("Exported {} item(s) to {}", items.len(), dir.display())),
+            Err(e) => self.record_error(format!("Export failed: {}", e), &e),
+        }
+    }
+
+    // =========================================================================
+    // Yank
+    // =========================================================================
+
+    /// The filtered table as tab-separated text: a header line, then one line per row
+    pub fn table_as_tsv(&self) -> Option<String> {
+        self.current_resource()?;
+        let columns = self.table_columns();
+        // Tabs or newlines inside a value would break the grid when pasted
+        let clean = |value: &str| value.replace(['\t', '\n', '\r'], " ");
+
+        let mut lines = Vec::with_capacity(self.filtered_items.len() + 1);
+        lines.push(
+            columns.iter().map(|col| clean(&col.header)).collect::<Vec<_>>().join("\t"),
+        );
+        for item in &self.filtered_items {
+            let cells: Vec<String> = columns
+                .iter()
+                .map(|col| {
+                    let value = col.value(item);
+                    match col.format {
+                        Some(format) => clean(&format_timestamp(&value, format, self.timezone)),
+                        None => clean(&value),
+                    }
+                })
+                .collect();
+            lines.push(cells.join("\t"));
+        }
+        Some(lines.join("\n"))
+    }
+
+    /// Copy the filtered table to the clipboard as TSV, ready to paste into a spreadsheet
+    pub fn yank_table(&mut self) {
+        let Some(tsv) = self.table_as_tsv() else {
+            self.error_message = Some("Nothing to yank".to_string());
+            return;
+        };
+        match crate::clipboard::copy(&tsv) {
+            Ok(()) => self.set_status(format!("Copied {} row(s) to the clipboard", self.filtered_items.len())),
+            Err(e) => self.record_error(format!("Yank failed: {}", e), &e),
+        }
+    }
+
+    /// Hand the selected item's expansion of `command` to the main loop to run
+    ///
+    /// Refused in replay mode: the commands act on real AWS resources.
+    pub fn queue_shell_command(&mut self, command: &ShellCommandDef) {
+        if let Err(e) = self.ensure_live() {
+            self.record_error(e.to_string(), &e);
+            return;
+        }
+        let Some(item) = self.selected_item() else {
+            return;
+        };
+        // All-regions rows carry their own region
+        let region = item
+            .get(REGION_FIELD)
+            .and_then(|v| v.as_str())
+            .unwrap_or(&self.region)
+            .to_string();
+        let args = command.expand(item);
+        if args.is_empty() {
+            self.error_message = Some(format!("{} has no command to run", command.display_name));
+            return;
+        }
+        self.pending_shell = Some(PendingShell { args, region });
+    }
+
+    /// Open the selected resource in the AWS Console, or copy the link when no browser opens
+    pub fn open_in_console(&mut self) {
+        let (Some(resource), Some(item)) = (self.current_resource(), self.selected_item()) else {
+            return;
+        };
+        // All-regions rows carry their own region
+        let region = item
+            .get(REGION_FIELD)
+            .and_then(|v| v.as_str())
+            .unwrap_or(&self.region)
+            .to_string();
+        let Some(url) = console_url(&self.current_resource_key, resource, item, &region) else {
+            self.set_status(format!("No console page for {}", resource.display_name));
+            return;
+        };
+
+        match open::that(&url) {
+            Ok(()) => self.set_status(format!("Opened {}", url)),
+            Err(e) => {
+                tracing::debug!("Failed to open browser: {}", e);
+                match crate::clipboard::copy(&url) {
+                    Ok(()) => self.set_status("No browser available - copied the console link".to_string()),
+                    Err(e) => self.record_error(format!("Couldn't open or copy {}: {}", url, e), &e),
+                }
+            }
+        }
+    }
+
+    // =========================================================================
+    // Search
+    // =========================================================================
+
+    /// List the core resources concurrently in the background and show those
+    /// whose name or ID contains `term` once they arrive (`poll_search_results`)
+    pub fn run_search(&mut self, term: &str) {
+        self.search_generation += 1;
+        let generation = self.search_generation;
+        self.search_state = Some(SearchState {
+            term: term.to_string(),
+            results: Vec::new(),
+            selected: 0,
+            failed: Vec::new(),
+            searching: true,
+        });
+        self.mode = Mode::Search;
+
+        let backend = self.backend(self.clients.clone());
+        let tx = self.search_tx.clone();
+        tokio::spawn(async move {
+            let fetches = SEARCH_RESOURCES
+                .iter()
+                .map(|key| crate::resource::fetch_resources(key, backend.as_ref(), &[]));
+            let responses = SEARCH_RESOURCES.iter().copied().zip(join_all(fetches).await).collect();
+            let _ = tx.send(SearchMessage { generation, responses });
+        });
+    }
+
+    /// Show the results of a finished `:search` (called from the event loop)
+    pub fn poll_search_results(&mut self) {
+        while let Ok(message) = self.search_rx.try_recv() {
+            if message.generation != self.search_generation {
+                continue;
+            }
+            let Some(search) = &mut self.search_state else {
+                continue;
+            };
+            let (results, failed) = search_matches(&search.term, message.responses);
+            search.results = results;
+            search.failed = failed;
+            search.searching = false;
+        }
+    }
+
+    /// Open the selected search result's resource view, filtered to that item
+    pub async fn open_search_result(&mut self) -> Result<()> {
+        let Some(search) = self.search_state.take() else {
+            return Ok(());
+        };
+        let Some(result) = search.results.get(search.selected) else {
+            self.exit_mode();
+            return Ok(());
+        };
+
+        self.navigate_to_resource(&result.resource_key).await?;
+        // The filter is applied when the fetch lands
+        self.filter_text = result.id.clone();
+        Ok(())
+    }
+
+    /// Leave the search results without opening anything
+    pub fn close_search(&mut self) {
+        // A search still in flight has nowhere to show its results
+        self.search_generation += 1;
+        self.search_state = None;
+        self.exit_mode();
+    }
+
     // =========================================================================
     // Log Tail Mode
     // =========================================================================
@@ -1285,6 +3129,12 @@ impl App {
     pub fn toggle_log_tail_pause(&mut self) {
         if let Some(ref mut state) = self.log_tail_state {
             state.paused = !state.paused;
+            // Fetch immediately on resume instead of waiting for the next poll interval
+            if !state.paused {
+                if let Some(past) = std::time::Instant::now().checked_sub(std::time::Duration::from_secs(2)) {
+                    state.last_poll = past;
+                }
+            }
         }
     }
 
@@ -1325,4 +3175,404 @@ impl App {
         self.log_tail_state = None;
         self.mode = Mode::Normal;
     }
+
+    // =========================================================================
+    // Metrics Mode
+    // =========================================================================
+
+    /// Fetch CPU utilization for the selected EC2 instance over the last hour
+    pub async fn enter_metrics_mode(&mut self) -> Result<()> {
+        let Some(item) = self.selected_item().cloned() else {
+            return Ok(());
+        };
+
+        let instance_id = extract_json_value(&item, "InstanceId");
+        if instance_id == "-" {
+            self.error_message = Some("Could not get instance ID".to_string());
+            return Ok(());
+        }
+
+        let params = serde_json::json!({
+            "namespace": "AWS/EC2",
+            "metric_name": "CPUUtilization",
+            "dimension_name": "InstanceId",
+            "dimension_value": instance_id,
+            "period": 300,
+            "minutes": 60,
+        });
+
+        let mut state = MetricsState {
+            resource_id: instance_id,
+            metric_name: "CPUUtilization".to_string(),
+            values: Vec::new(),
+            error: None,
+        };
+
+        match crate::resource::invoke_sdk("cloudwatch", "get_metric_data", &self.clients, &params).await {
+            Ok(response) => {
+                if let Some(points) = response.get("datapoints").and_then(|v| v.as_array()) {
+                    state.values = points
+                        .iter()
+                        .filter_map(|p| p.get("Value").and_then(|v| v.as_f64()))
+                        .collect();
+                }
+            }
+            Err(e) => {
+                state.error = Some(aws::client::format_aws_error(&e, &self.profile));
+            }
+        }
+
+        self.metrics_state = Some(state);
+        self.mode = Mode::Metrics;
+        Ok(())
+    }
+
+    /// Close the metrics popup
+    pub fn exit_metrics_mode(&mut self) {
+        self.metrics_state = None;
+        self.mode = Mode::Normal;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::resource::Dump;
+    use serde_json::json;
+
+    /// An app that serves lists from `dump` instead of AWS
+    fn replay_app(dump: Dump) -> App {
+        let clients = AwsClients::offline(&dump.profile, &dump.region);
+        let (profile, region) = (clients.profile.clone(), clients.region.clone());
+        let mut app = App::from_initialized(clients, profile, region, Vec::new(), Vec::new(), Vec::new(), Config::default(), true, None);
+        app.replay = Some(Arc::new(dump));
+        app
+    }
+
+    #[tokio::test]
+    async fn test_search_runs_in_the_background() {
+        let mut dump = Dump::default();
+        dump.add("ec2-instances", &[], vec![
+            json!({ "InstanceId": "i-1", "Tags": { "Name": "web" } }),
+            json!({ "InstanceId": "i-2", "Tags": { "Name": "db" } }),
+        ]);
+        let mut app = replay_app(dump);
+
+        // The first search is superseded before its results are shown
+        app.run_search("web");
+        app.run_search("DB");
+        assert!(app.search_state.as_ref().unwrap().searching);
+
+        for _ in 0..100 {
+            tokio::time::sleep(std::time::Duration::from_millis(5)).await;
+            app.poll_search_results();
+            if !app.search_state.as_ref().unwrap().searching {
+                break;
+            }
+        }
+        let search = app.search_state.as_ref().unwrap();
+        assert!(!search.searching);
+        let ids: Vec<&str> = search.results.iter().map(|r| r.id.as_str()).collect();
+        assert_eq!(ids, vec!["i-2"]);
+        assert!(search.failed.is_empty());
+    }
+
+    #[test]
+    fn test_export_file_stem_is_a_safe_file_name() {
+        assert_eq!(export_file_stem("i-0abc123"), "i-0abc123");
+        assert_eq!(
+            export_file_stem("arn:aws:iam::123456789012:policy/team/Deploy"),
+            "arn_aws_iam__123456789012_policy_team_Deploy"
+        );
+        assert_eq!(export_file_stem("../etc"), ".._etc");
+    }
+
+    #[test]
+    fn test_hidden_columns_give_their_width_to_the_rest() {
+        let columns = &get_resource("ecs-tasks").unwrap().columns;
+        let total: u16 = columns.iter().map(|c| c.width).sum();
+
+        let hidden = vec!["CPU".to_string(), "MEMORY".to_string()];
+        let visible = visible_columns(columns, &hidden);
+        let headers: Vec<&str> = visible.iter().map(|c| c.header.as_str()).collect();
+        assert_eq!(headers, vec!["TASK ID", "STATUS", "DESIRED STATUS"]);
+
+        let visible_total: u16 = visible.iter().map(|c| c.width).sum();
+        assert!(visible_total <= total && visible_total + 2 >= total);
+        assert_eq!(visible_columns(columns, &[]).len(), columns.len());
+    }
+
+    #[test]
+    fn test_region_filter_matches_code_or_name() {
+        assert!(region_matches("eu-west-2", "eu-"));
+        assert!(region_matches("eu-west-2", "London"));
+        assert!(region_matches("ap-northeast-1", "tok"));
+        assert!(!region_matches("us-east-1", "eu"));
+        assert!(region_matches("us-east-1", ""));
+    }
+
+    #[test]
+    fn test_grouping_keeps_order_within_groups_and_maps_rows() {
+        let resource = get_resource("ec2-instances").unwrap();
+        let state = resource.columns.iter().find(|c| c.header == "STATE").unwrap();
+        let mut items = vec![
+            json!({ "InstanceId": "i-1", "State": "stopped" }),
+            json!({ "InstanceId": "i-2", "State": "running" }),
+            json!({ "InstanceId": "i-3", "State": "stopped" }),
+            json!({ "InstanceId": "i-4", "State": "running" }),
+        ];
+        group_items(&mut items, state);
+        let ids: Vec<String> = items.iter().map(|i| extract_json_value(i, "InstanceId")).collect();
+        assert_eq!(ids, vec!["i-2", "i-4", "i-1", "i-3"]);
+
+        // Rows: [running header, i-2, i-4, stopped header, i-1, i-3]
+        let starts = group_starts(&items, state);
+        assert_eq!(starts, vec![0, 2]);
+        assert_eq!(item_at_grouped_row(&starts, 0), None);
+        assert_eq!(item_at_grouped_row(&starts, 2), Some(1));
+        assert_eq!(item_at_grouped_row(&starts, 3), None);
+        assert_eq!(item_at_grouped_row(&starts, 5), Some(3));
+    }
+
+    #[test]
+    fn test_refresh_keeps_filter_and_selected_resource() {
+        let mut app = replay_app(Dump::default());
+        app.current_resource_key = "ec2-instances".to_string();
+        app.apply_page(vec![
+            json!({ "InstanceId": "i-1", "Tags": { "Name": "web-a" } }),
+            json!({ "InstanceId": "i-2", "Tags": { "Name": "web-b" } }),
+            json!({ "InstanceId": "i-3", "Tags": { "Name": "db" } }),
+        ], None);
+        app.filter_text = "web".to_string();
+        app.apply_filter();
+        assert_eq!(app.filtered_items.len(), 2);
+        app.selected = 1; // web-b
+
+        // The refresh brings a new matching instance that sorts first
+        app.apply_page(vec![
+            json!({ "InstanceId": "i-0", "Tags": { "Name": "web-0" } }),
+            json!({ "InstanceId": "i-1", "Tags": { "Name": "web-a" } }),
+            json!({ "InstanceId": "i-2", "Tags": { "Name": "web-b" } }),
+            json!({ "InstanceId": "i-3", "Tags": { "Name": "db" } }),
+        ], None);
+
+        assert_eq!(app.filter_text, "web");
+        let names: Vec<String> = app.filtered_items.iter().map(|i| extract_json_value(i, "Tags.Name")).collect();
+        assert_eq!(names, vec!["web-0", "web-a", "web-b"]);
+        assert_eq!(app.selected, 2);
+        assert_eq!(extract_json_value(app.selected_item().unwrap(), "InstanceId"), "i-2");
+    }
+
+    #[test]
+    fn test_reloaded_page_keeps_the_selected_instance() {
+        let mut app = replay_app(Dump::default());
+        app.apply_page(vec![
+            json!({ "InstanceId": "i-1" }),
+            json!({ "InstanceId": "i-2" }),
+            json!({ "InstanceId": "i-3" }),
+        ], None);
+        app.selected = 1;
+
+        // i-2 moves to the end and a new instance takes its row
+        app.apply_page(vec![
+            json!({ "InstanceId": "i-0" }),
+            json!({ "InstanceId": "i-1" }),
+            json!({ "InstanceId": "i-3" }),
+            json!({ "InstanceId": "i-2" }),
+        ], Some("next".to_string()));
+        assert_eq!(app.selected, 3);
+        assert!(app.pagination.has_more);
+
+        // Once it is gone the cursor stays on the same row, clamped to the list
+        app.apply_page(vec![json!({ "InstanceId": "i-0" })], None);
+        assert_eq!(app.selected, 0);
+    }
+
+    #[test]
+    fn test_filter_history_recalls_per_resource() {
+        let mut app = replay_app(Dump::default());
+        for filter in ["web", "db", " web "] {
+            app.filter_text = filter.to_string();
+            app.record_filter();
+        }
+        app.filter_text.clear();
+        app.record_filter();
+
+        // Newest first, without duplicates or empty filters
+        app.recall_filter(true);
+        assert_eq!(app.filter_text, "web");
+        app.recall_filter(true);
+        assert_eq!(app.filter_text, "db");
+        app.recall_filter(true);
+        assert_eq!(app.filter_text, "db");
+        app.recall_filter(false);
+        assert_eq!(app.filter_text, "web");
+        // Past the newest entry the filter is cleared
+        app.recall_filter(false);
+        assert_eq!(app.filter_text, "");
+
+        app.current_resource_key = "lambda-functions".to_string();
+        app.recall_filter(true);
+        assert_eq!(app.filter_text, "");
+    }
+
+    #[test]
+    fn test_selection_stays_near_row_when_resource_is_gone() {
+        let items = vec![json!({ "Id": "a" }), json!({ "Id": "b" })];
+        assert_eq!(follow_selection(&items, Some(("Id", "gone")), 5), 1);
+        assert_eq!(follow_selection(&items, None, 0), 0);
+        assert_eq!(follow_selection(&[], Some(("Id", "a")), 3), 0);
+    }
+
+    #[test]
+    fn test_sub_resource_lists_cannot_be_favorites() {
+        let mut app = replay_app(Dump::default());
+        app.current_resource_key = "ec2-volumes".to_string();
+        app.parent_context = Some(ParentContext {
+            resource_key: "ec2-instances".to_string(),
+            item: json!({ "InstanceId": "i-1" }),
+            display_name: "i-1".to_string(),
+            selected: 0,
+            filter_text: String::new(),
+        });
+
+        app.toggle_favorite();
+        assert!(app.config.favorites.is_empty());
+    }
+
+    #[test]
+    fn test_shell_commands_are_refused_in_replay_mode() {
+        let mut dump = Dump::default();
+        dump.add("ec2-instances", &[], vec![json!({ "InstanceId": "i-1" })]);
+        let mut app = replay_app(dump);
+        app.current_resource_key = "ec2-instances".to_string();
+        app.apply_page(vec![json!({ "InstanceId": "i-1" })], None);
+
+        let command: ShellCommandDef = serde_json::from_value(json!({
+            "shortcut": "x", "display_name": "Connect", "command": "aws ssm start-session --target {InstanceId}"
+        }))
+        .unwrap();
+        app.queue_shell_command(&command);
+        assert!(app.pending_shell.is_none());
+        assert_eq!(app.error_message.as_deref(), Some("Not available in replay mode"));
+    }
+
+    #[tokio::test]
+    async fn test_canceled_page_load_stays_on_the_shown_page() {
+        let mut dump = Dump::default();
+        dump.add("ec2-instances", &[], vec![json!({ "InstanceId": "i-1" })]);
+        let mut app = replay_app(dump);
+        app.current_resource_key = "ec2-instances".to_string();
+        app.apply_page(vec![json!({ "InstanceId": "i-1" })], Some("page-2".to_string()));
+
+        app.next_page().await.unwrap();
+        assert!(app.loading);
+        assert_eq!(app.pagination.current_page, 2);
+
+        app.cancel_fetch();
+        tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+        app.poll_fetch_results();
+        assert_eq!(app.pagination.current_page, 1);
+        assert!(app.pagination.token_stack.is_empty());
+        assert_eq!(app.pagination.next_token.as_deref(), Some("page-2"));
+        assert!(app.pagination.has_more);
+
+        // ] works again from the restored page
+        app.next_page().await.unwrap();
+        assert_eq!(app.pagination.current_page, 2);
+        assert_eq!(app.pagination.token_stack, vec![Some("page-2".to_string())]);
+    }
+
+    #[test]
+    fn test_typed_confirmation_comes_from_the_confirm_config() {
+        let mut app = replay_app(Dump::default());
+        app.current_resource_key = "sqs-queues".to_string();
+        app.apply_page(vec![json!({ "QueueUrl": "https://sqs/q1", "QueueName": "q1" })], None);
+        let resource = app.current_resource().unwrap().clone();
+        let pending = |method: &str| {
+            let action = resource.actions.iter().find(|a| a.sdk_method == method).unwrap();
+            app.create_pending_action(action, "https://sqs/q1").unwrap()
+        };
+
+        // Purging can't be undone even though it isn't a delete
+        assert!(pending("purge_queue").require_typed);
+        assert!(pending("delete_queue").require_typed);
+    }
+
+    #[test]
+    fn test_deregistering_an_ami_needs_typed_confirmation() {
+        let mut app = replay_app(Dump::default());
+        app.current_resource_key = "ec2-amis".to_string();
+        app.apply_page(vec![json!({ "ImageId": "ami-1", "Name": "base" })], None);
+        let resource = app.current_resource().unwrap().clone();
+        let action = resource.actions.iter().find(|a| a.sdk_method == "deregister_image").unwrap();
+        assert!(app.create_pending_action(action, "ami-1").unwrap().require_typed);
+    }
+
+    #[test]
+    fn test_sticky_error_until_a_list_loads_or_the_profile_changes() {
+        let err = anyhow::anyhow!("AccessDenied");
+        let mut app = replay_app(Dump::default());
+        app.record_error("Access denied".to_string(), &err);
+        assert_eq!(app.error_message.as_deref(), Some("Access denied"));
+        assert!(app.sticky_error.is_none(), "sticky_errors is off by default");
+
+        app.config.sticky_errors = Some(true);
+        app.record_error("Access denied".to_string(), &err);
+        assert_eq!(app.sticky_error.as_deref(), Some("Access denied"));
+
+        // A successful load clears the banner
+        app.loading = true;
+        let result = Ok(PaginatedResult { items: vec![json!({ "InstanceId": "i-1" })], next_token: None });
+        app.fetch_tx
+            .send(FetchMessage { generation: app.fetch_generation, result, failed_regions: Vec::new() })
+            .unwrap();
+        app.poll_fetch_results();
+        assert!(app.sticky_error.is_none());
+
+        // So does switching to other credentials
+        app.record_error("Access denied".to_string(), &err);
+        app.use_clients(AwsClients::offline("prod", "us-east-1"), "prod", "us-east-1");
+        assert!(app.sticky_error.is_none());
+        assert_eq!((app.profile.as_str(), app.region.as_str()), ("prod", "us-east-1"));
+
+        app.record_error("Access denied".to_string(), &err);
+        app.dismiss_sticky_error();
+        assert!(app.sticky_error.is_none());
+    }
+
+    #[test]
+    fn test_recall_command_walks_the_history() {
+        let mut app = replay_app(Dump::default());
+        for command in ["ec2", "lambda", "s3"] {
+            app.config.remember_command(command);
+        }
+        app.enter_command_mode();
+
+        // Ctrl+p goes back from the newest and stops at the oldest
+        let mut recalled = Vec::new();
+        for _ in 0..4 {
+            app.recall_command(true);
+            recalled.push(app.command_text.clone());
+        }
+        assert_eq!(recalled, vec!["s3", "lambda", "ec2", "ec2"]);
+
+        // Ctrl+n comes forward again, then leaves an empty command line
+        app.recall_command(false);
+        assert_eq!(app.command_text, "lambda");
+        app.recall_command(false);
+        assert_eq!(app.command_text, "s3");
+        app.recall_command(false);
+        assert_eq!(app.command_text, "");
+        app.recall_command(false);
+        assert_eq!(app.command_text, "");
+
+        // Editing a recalled command starts over from the newest entry
+        app.recall_command(true);
+        app.recall_command(true);
+        app.command_text.push('x');
+        app.command_edited();
+        app.recall_command(true);
+        assert_eq!(app.command_text, "s3");
+    }
 }
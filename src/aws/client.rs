@@ -3,9 +3,13 @@
 //! Uses SigV4 signing with direct HTTP calls instead of heavy SDK
 
 use anyhow::Result;
+use regex::Regex;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, OnceLock};
 
 use super::credentials::{load_credentials, load_credentials_with_sso_check, CredentialsError};
-use super::http::AwsHttpClient;
+use super::http::{char_prefix, AwsHttpClient, RetryPolicy, GLOBAL_REGION};
+use crate::config::{Config, DEFAULT_MAX_CONCURRENCY};
 
 /// Result type for client creation that may require SSO login
 pub enum ClientResult {
@@ -16,10 +20,45 @@ pub enum ClientResult {
 }
 
 /// Container for AWS HTTP client
+///
+/// Every service goes through the one signed `http` client; endpoints and
+/// signing scopes are worked out per request, so nothing is built per service.
+#[derive(Clone)]
 pub struct AwsClients {
     pub http: AwsHttpClient,
     pub region: String,
     pub profile: String,
+    /// Limit on concurrent describe calls in N+1 list operations
+    pub max_concurrency: usize,
+    /// Describe calls made so far by an N+1 list, for the loading indicator
+    pub progress: Arc<DetailProgress>,
+}
+
+/// Describe calls started and finished by the N+1 lists of one fetch
+///
+/// Shared between the fetch task and the UI, which shows "Describing 37/120".
+#[derive(Debug, Default)]
+pub struct DetailProgress {
+    done: AtomicUsize,
+    total: AtomicUsize,
+}
+
+impl DetailProgress {
+    /// Announce `count` more describe calls (lists across regions add up)
+    pub fn add_total(&self, count: usize) {
+        self.total.fetch_add(count, Ordering::Relaxed);
+    }
+
+    /// Record `count` finished describe calls
+    pub fn advance(&self, count: usize) {
+        self.done.fetch_add(count, Ordering::Relaxed);
+    }
+
+    /// `(done, total)`, or None before any describe calls were announced
+    pub fn get(&self) -> Option<(usize, usize)> {
+        let total = self.total.load(Ordering::Relaxed);
+        (total > 0).then(|| (self.done.load(Ordering::Relaxed).min(total), total))
+    }
 }
 
 impl AwsClients {
@@ -41,6 +80,8 @@ impl AwsClients {
             http,
             region: region_str.clone(),
             profile: profile_str,
+            max_concurrency: DEFAULT_MAX_CONCURRENCY,
+            progress: Arc::default(),
         };
 
         Ok((client, region_str))
@@ -66,6 +107,8 @@ impl AwsClients {
                     http,
                     region: region.clone(),
                     profile: prof,
+                    max_concurrency: DEFAULT_MAX_CONCURRENCY,
+                            progress: Arc::default(),
                 };
                 Ok(ClientResult::Ok(client, region))
             }
@@ -81,6 +124,17 @@ impl AwsClients {
         }
     }
 
+    /// Clients that never call AWS, for replaying a dump (`--replay`)
+    pub fn offline(profile: &str, region: &str) -> Self {
+        Self {
+            http: AwsHttpClient::offline(region),
+            region: region.to_string(),
+            profile: profile.to_string(),
+            max_concurrency: DEFAULT_MAX_CONCURRENCY,
+            progress: Arc::default(),
+        }
+    }
+
     /// Recreate client for a new region (keeps same profile)
     /// Note: This runs credential loading on a blocking thread to support SSO
     pub async fn switch_region(&mut self, profile: &str, region: &str) -> Result<String> {
@@ -99,24 +153,107 @@ impl AwsClients {
         self.profile = profile_str;
         Ok(region_str)
     }
+
+    /// Apply user tuning (concurrency, retries) from the config file
+    pub fn apply_config(&mut self, config: &Config) {
+        self.max_concurrency = config.max_concurrency();
+        self.http.set_retry_policy(config.retry_policy());
+    }
+
+    /// Copy of these clients that never retries, for write operations
+    pub fn without_retries(&self) -> AwsClients {
+        let mut clients = self.clone();
+        clients.http.set_retry_policy(RetryPolicy::NONE);
+        clients
+    }
+
+    /// Clients pinned to us-east-1 for resources that are global but served by a
+    /// regional endpoint. Returns None when already in us-east-1 or when a custom
+    /// endpoint makes the region irrelevant.
+    pub fn global(&self) -> Option<AwsClients> {
+        if self.region == GLOBAL_REGION || self.http.has_custom_endpoint() {
+            return None;
+        }
+        Some(Self {
+            http: self.http.with_region(GLOBAL_REGION),
+            region: GLOBAL_REGION.to_string(),
+            profile: self.profile.clone(),
+            max_concurrency: self.max_concurrency,
+            progress: self.progress.clone(),
+        })
+    }
+
+    /// Copy of these clients targeting another region with the same credentials
+    pub fn for_region(&self, region: &str) -> AwsClients {
+        Self {
+            http: self.http.with_region(region),
+            region: region.to_string(),
+            profile: self.profile.clone(),
+            max_concurrency: self.max_concurrency,
+            progress: self.progress.clone(),
+        }
+    }
+}
+
+/// Pull the denied IAM action (e.g. `ec2:DescribeInstances`) out of an AccessDenied message
+///
+/// AWS phrases it as "...is not authorized to perform: ec2:DescribeInstances on resource..."
+/// or "...no identity-based policy allows the ec2:DescribeInstances action".
+fn missing_permission(message: &str) -> Option<String> {
+    static PATTERN: OnceLock<Regex> = OnceLock::new();
+    let pattern = PATTERN.get_or_init(|| {
+        Regex::new(r"(?:to perform:?|allows the)\s+([a-z0-9-]+:[A-Za-z0-9*]+)")
+            .expect("valid permission regex")
+    });
+    pattern
+        .captures(message)
+        .map(|caps| caps[1].to_string())
+}
+
+/// Whether an error means the SSO session, or the role credentials it issued, has expired
+///
+/// The SSO portal answers with UnauthorizedException/ForbiddenException once the
+/// access token lapses; service calls fail with ExpiredToken when the role
+/// credentials run out.
+fn is_sso_expiry(err: &anyhow::Error) -> bool {
+    let err_str = err.to_string();
+    err_str.contains("UnauthorizedException")
+        || err_str.contains("ForbiddenException")
+        || err_str.contains("Session token not found or invalid")
+        || err_str.contains("ExpiredToken")
+}
+
+/// Format AWS errors into user-friendly messages for `profile`
+pub fn format_aws_error(err: &anyhow::Error, profile: &str) -> String {
+    let sso_profile = super::sso::get_sso_config(profile).map(|_| profile);
+    describe_aws_error(err, sso_profile)
 }
 
-/// Format AWS errors into user-friendly messages
-pub fn format_aws_error(err: &anyhow::Error) -> String {
+/// User-friendly message for `err`; `sso_profile` is the profile when it logs in with SSO
+fn describe_aws_error(err: &anyhow::Error, sso_profile: Option<&str>) -> String {
     let err_str = err.to_string();
     
     // Check for common AWS error patterns
+    if err_str.contains("Throttling") || err_str.contains("RequestLimitExceeded") || err_str.contains("TooManyRequests") {
+        return "Rate limited - reduce max_concurrency in config".to_string();
+    }
     if err_str.contains("dispatch failure") || err_str.contains("connection") {
         return "Connection failed - check internet/credentials".to_string();
     }
     if err_str.contains("InvalidClientTokenId") || err_str.contains("SignatureDoesNotMatch") {
         return "Invalid credentials - run 'aws configure'".to_string();
     }
+    if let Some(profile) = sso_profile.filter(|_| is_sso_expiry(err)) {
+        return format!("SSO session expired - run 'aws sso login --profile {}' or press L to log in", profile);
+    }
     if err_str.contains("ExpiredToken") {
         return "Credentials expired - refresh or reconfigure".to_string();
     }
-    if err_str.contains("AccessDenied") || err_str.contains("UnauthorizedAccess") {
-        return "Access denied - check IAM permissions".to_string();
+    if err_str.contains("AccessDenied") || err_str.contains("UnauthorizedAccess") || err_str.contains("UnauthorizedOperation") {
+        return match missing_permission(&err_str) {
+            Some(action) => format!("Missing permission: {}", action),
+            None => "Access denied - check IAM permissions".to_string(),
+        };
     }
     if err_str.contains("No credentials") || err_str.contains("no credentials") {
         return "No credentials - run 'aws configure'".to_string();
@@ -129,9 +266,60 @@ pub fn format_aws_error(err: &anyhow::Error) -> String {
     }
     
     // Default: truncate long errors
-    if err_str.len() > 60 {
-        format!("{}...", &err_str[..60])
+    if err_str.chars().count() > 60 {
+        format!("{}...", char_prefix(&err_str, 60))
     } else {
         err_str
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_missing_permission_parses_access_denied_messages() {
+        assert_eq!(
+            missing_permission(
+                "User: arn:aws:iam::123456789012:user/dev is not authorized to perform: \
+                 ec2:DescribeInstances on resource: * because no identity-based policy allows it"
+            )
+            .as_deref(),
+            Some("ec2:DescribeInstances")
+        );
+        assert_eq!(
+            missing_permission(
+                "User: arn:aws:sts::123456789012:assumed-role/ReadOnly/dev is not authorized to \
+                 perform lambda:ListFunctions because no identity-based policy allows the \
+                 lambda:ListFunctions action"
+            )
+            .as_deref(),
+            Some("lambda:ListFunctions")
+        );
+        assert_eq!(
+            missing_permission("no identity-based policy allows the s3:ListAllMyBuckets action").as_deref(),
+            Some("s3:ListAllMyBuckets")
+        );
+        assert_eq!(missing_permission("AccessDenied: Access Denied"), None);
+    }
+
+    #[test]
+    fn test_sso_wording_only_for_sso_profiles() {
+        let unauthorized = anyhow::anyhow!("AWS request failed (401 Unauthorized): UnauthorizedException");
+        let expired = anyhow::anyhow!("AWS request failed (400 Bad Request): ExpiredToken");
+        let hint = "SSO session expired - run 'aws sso login --profile dev' or press L to log in";
+        assert_eq!(describe_aws_error(&unauthorized, Some("dev")), hint);
+        assert_eq!(describe_aws_error(&expired, Some("dev")), hint);
+
+        assert_eq!(describe_aws_error(&expired, None), "Credentials expired - refresh or reconfigure");
+        assert!(!describe_aws_error(&unauthorized, None).contains("SSO"));
+    }
+
+    #[test]
+    fn test_long_errors_are_cut_on_char_boundaries() {
+        let err = anyhow::anyhow!("Überprüfung fehlgeschlagen: {}", "ü".repeat(80));
+        let message = describe_aws_error(&err, None);
+        assert!(message.ends_with("..."));
+        assert_eq!(message.chars().count(), 63);
+    }
+}
@@ -182,8 +182,8 @@ pub fn aws_config_dir() -> Result<PathBuf> {
 }
 
 /// Parse an INI-style file into sections
-/// Returns (profiles, sso_sessions) where sso_sessions contains [sso-session X] sections
-fn parse_ini_file(content: &str) -> HashMap<String, HashMap<String, String>> {
+/// The "profile " prefix is stripped; other prefixes (e.g., "sso-session X") are kept
+pub fn parse_ini_file(content: &str) -> HashMap<String, HashMap<String, String>> {
     let mut sections: HashMap<String, HashMap<String, String>> = HashMap::new();
     let mut current_section = String::new();
 
@@ -335,60 +335,6 @@ fn load_from_sso(profile: &str) -> Result<Credentials> {
     Ok(credentials)
 }
 
-/// Get the default region for a profile
-#[allow(dead_code)]
-pub fn get_profile_region(profile: &str) -> Option<String> {
-    // 1. Check environment variable
-    if let Ok(region) = env::var("AWS_REGION") {
-        return Some(region);
-    }
-    if let Ok(region) = env::var("AWS_DEFAULT_REGION") {
-        return Some(region);
-    }
-
-    // 2. Check config file
-    if let Ok(config_dir) = aws_config_dir() {
-        let config_path = config_dir.join("config");
-        if let Ok(content) = fs::read_to_string(&config_path) {
-            let sections = parse_ini_file(&content);
-            if let Some(section) = sections.get(profile) {
-                if let Some(region) = section.get("region") {
-                    return Some(region.clone());
-                }
-            }
-        }
-    }
-
-    None
-}
-
-/// List available AWS profiles
-#[allow(dead_code)]
-pub fn list_profiles() -> Vec<String> {
-    let mut profiles = Vec::new();
-
-    if let Ok(config_dir) = aws_config_dir() {
-        // Read from credentials file
-        if let Ok(content) = fs::read_to_string(config_dir.join("credentials")) {
-            let sections = parse_ini_file(&content);
-            profiles.extend(sections.keys().cloned());
-        }
-
-        // Read from config file
-        if let Ok(content) = fs::read_to_string(config_dir.join("config")) {
-            let sections = parse_ini_file(&content);
-            for key in sections.keys() {
-                if !profiles.contains(key) {
-                    profiles.push(key.clone());
-                }
-            }
-        }
-    }
-
-    profiles.sort();
-    profiles
-}
-
 // =============================================================================
 // IMDSv2 (EC2 Instance Metadata Service) Support
 // =============================================================================
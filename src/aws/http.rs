@@ -7,7 +7,7 @@ use reqwest::Client;
 use aws_sigv4::http_request::{sign, SigningSettings, SignableRequest, SignableBody};
 use aws_sigv4::sign::v4::SigningParams;
 use aws_smithy_runtime_api::client::identity::Identity;
-use std::time::SystemTime;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 use std::collections::HashMap;
 use tracing::{debug, trace, warn};
 
@@ -46,6 +46,80 @@ fn mask_credential(value: &str) -> String {
     }
 }
 
+/// The first `max_chars` characters of `s`, never splitting a multi-byte character
+pub(crate) fn char_prefix(s: &str, max_chars: usize) -> &str {
+    s.char_indices().nth(max_chars).map_or(s, |(end, _)| &s[..end])
+}
+
+/// Retry policy for transient failures (throttling, 5xx responses, connection errors)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RetryPolicy {
+    /// Total attempts including the first one (1 disables retries)
+    pub max_attempts: u32,
+    /// Delay before the first retry; doubles on every further attempt
+    pub base_delay: Duration,
+}
+
+impl RetryPolicy {
+    /// Single attempt, used for write operations that are not safe to repeat
+    pub const NONE: RetryPolicy = RetryPolicy {
+        max_attempts: 1,
+        base_delay: Duration::ZERO,
+    };
+
+    /// Backoff before retry number `attempt` (1-based): half fixed, half jitter
+    fn delay(&self, attempt: u32) -> Duration {
+        let backoff = self.base_delay.saturating_mul(1 << attempt.saturating_sub(1).min(10));
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.subsec_nanos())
+            .unwrap_or(0);
+        let jitter = backoff.mul_f64((nanos % 1000) as f64 / 1000.0) / 2;
+        backoff / 2 + jitter
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            base_delay: Duration::from_millis(200),
+        }
+    }
+}
+
+/// Whether a failed request is worth retrying
+fn is_transient_error(err: &anyhow::Error) -> bool {
+    if let Some(e) = err.downcast_ref::<reqwest::Error>() {
+        return e.is_connect() || e.is_timeout() || e.is_request();
+    }
+    let msg = err.to_string();
+    if !msg.starts_with("AWS request failed") {
+        return false;
+    }
+    msg.contains("Throttling")
+        || msg.contains("RequestLimitExceeded")
+        || msg.contains("TooManyRequests")
+        || msg.contains("ProvisionedThroughputExceeded")
+        || msg.contains("(429 ")
+        || msg.contains("(500 ")
+        || msg.contains("(502 ")
+        || msg.contains("(503 ")
+        || msg.contains("(504 ")
+}
+
+/// Error for a non-success response, carrying the status and AWS's error body
+fn check_status(status: reqwest::StatusCode, body: &str) -> Result<()> {
+    if status.is_success() {
+        return Ok(());
+    }
+    warn!("AWS request failed: status={}, body={}", status, char_prefix(body, 500));
+    Err(anyhow!("AWS request failed ({}): {}", status, body))
+}
+
+/// Region that global services (IAM, Route 53, CloudFront) are signed for and served from
+pub const GLOBAL_REGION: &str = "us-east-1";
+
 /// AWS Service definition
 #[derive(Debug, Clone)]
 pub struct ServiceDefinition {
@@ -159,6 +233,14 @@ pub fn get_service(name: &str) -> Option<ServiceDefinition> {
             target_prefix: Some("Logs_20140328"),
             is_global: false,
         }),
+        "cloudwatch" | "monitoring" => Some(ServiceDefinition {
+            signing_name: "monitoring",
+            endpoint_prefix: "monitoring",
+            api_version: "2010-08-01",
+            protocol: Protocol::Query,
+            target_prefix: None,
+            is_global: false,
+        }),
         "sqs" => Some(ServiceDefinition {
             signing_name: "sqs",
             endpoint_prefix: "sqs",
@@ -319,16 +401,36 @@ pub fn get_service(name: &str) -> Option<ServiceDefinition> {
             target_prefix: Some("AmazonAthena"),
             is_global: false,
         }),
+        "ce" => Some(ServiceDefinition {
+            signing_name: "ce",
+            endpoint_prefix: "ce",
+            api_version: "2017-10-25",
+            protocol: Protocol::Json,
+            target_prefix: Some("AWSInsightsIndexService"),
+            is_global: true,
+        }),
         _ => None,
     }
 }
 
+/// Response status, headers and body
+#[derive(Debug)]
+pub struct HttpResponse {
+    pub status: u16,
+    pub headers: reqwest::header::HeaderMap,
+    pub body: String,
+}
+
 /// AWS HTTP Client
+#[derive(Clone)]
 pub struct AwsHttpClient {
     http_client: Client,
     credentials: Credentials,
     region: String,
     endpoint_url: Option<String>,
+    retry: RetryPolicy,
+    /// Refuse every request (replaying a dump needs no AWS)
+    offline: bool,
 }
 
 impl AwsHttpClient {
@@ -345,9 +447,54 @@ impl AwsHttpClient {
             credentials,
             region: region.to_string(),
             endpoint_url,
+            retry: RetryPolicy::default(),
+            offline: false,
         }
     }
 
+    /// A client that fails every request instead of calling AWS
+    pub fn offline(region: &str) -> Self {
+        let credentials = Credentials {
+            access_key_id: String::new(),
+            secret_access_key: String::new(),
+            session_token: None,
+        };
+        Self {
+            offline: true,
+            ..Self::new(credentials, region, None)
+        }
+    }
+
+    /// Copy of this client targeting another region (shares credentials and endpoint override)
+    pub fn with_region(&self, region: &str) -> Self {
+        Self {
+            http_client: self.http_client.clone(),
+            credentials: self.credentials.clone(),
+            region: region.to_string(),
+            endpoint_url: self.endpoint_url.clone(),
+            retry: self.retry,
+            offline: self.offline,
+        }
+    }
+
+    /// Update the retry policy for transient failures
+    pub fn set_retry_policy(&mut self, retry: RetryPolicy) {
+        self.retry = retry;
+    }
+
+    /// Fail if this client was created `offline`
+    fn ensure_online(&self) -> Result<()> {
+        if self.offline {
+            return Err(anyhow!("Not available in replay mode"));
+        }
+        Ok(())
+    }
+
+    /// Whether a custom endpoint (LocalStack, etc.) is configured
+    pub fn has_custom_endpoint(&self) -> bool {
+        self.endpoint_url.is_some()
+    }
+
     /// Update region
     pub fn set_region(&mut self, region: &str) {
         debug!("Switching region to: {}", region);
@@ -371,7 +518,7 @@ impl AwsHttpClient {
         }
 
         let region = if service.is_global {
-            "us-east-1"
+            GLOBAL_REGION
         } else {
             &self.region
         };
@@ -437,9 +584,32 @@ impl AwsHttpClient {
         service_name: &str,
         target: &str,
         body: &str,
+    ) -> Result<String> {
+        self.json_request_logged(service_name, target, body, true).await
+    }
+
+    /// JSON protocol request whose request and response bodies are never logged,
+    /// for calls that carry secret values (decrypted parameters, secrets)
+    pub async fn json_request_sensitive(
+        &self,
+        service_name: &str,
+        target: &str,
+        body: &str,
+    ) -> Result<String> {
+        self.json_request_logged(service_name, target, body, false).await
+    }
+
+    async fn json_request_logged(
+        &self,
+        service_name: &str,
+        target: &str,
+        body: &str,
+        log_bodies: bool,
     ) -> Result<String> {
         debug!("JSON request: service={}, target={}", service_name, target);
-        trace!("JSON body: {}", body);
+        if log_bodies {
+            trace!("JSON body: {}", body);
+        }
 
         let service = get_service(service_name)
             .ok_or_else(|| anyhow!("Unknown service: {}", service_name))?;
@@ -458,7 +628,7 @@ impl AwsHttpClient {
         headers.insert("X-Amz-Target".to_string(), target_header);
         headers.insert("Content-Type".to_string(), "application/x-amz-json-1.1".to_string());
 
-        self.signed_request(&service, "POST", &url, body, Some(headers)).await
+        self.signed_request_logged(&service, "POST", &url, body, Some(headers), log_bodies).await
     }
 
     /// Make a REST-JSON request (Lambda, API Gateway, EKS, etc.)
@@ -487,6 +657,34 @@ impl AwsHttpClient {
         self.signed_request(&service, method, &url, body.unwrap_or(""), Some(headers)).await
     }
 
+    /// Make a REST-JSON request that also returns status and response headers
+    /// (e.g., Lambda reports function errors in `X-Amz-Function-Error`).
+    /// Sent once, without retries.
+    pub async fn rest_json_request_with_headers(
+        &self,
+        service_name: &str,
+        method: &str,
+        path: &str,
+        body: &str,
+    ) -> Result<HttpResponse> {
+        debug!("REST-JSON request: service={}, method={}, path={}", service_name, method, path);
+
+        let service = get_service(service_name)
+            .ok_or_else(|| anyhow!("Unknown service: {}", service_name))?;
+        let url = format!("{}{}", self.get_endpoint(&service), path);
+
+        let headers = HashMap::from([("Content-Type".to_string(), "application/json".to_string())]);
+        let response = self.send_signed(&service, method, &url, body, Some(headers)).await?;
+        let status = response.status();
+        let headers = response.headers().clone();
+        let body = response.text().await?;
+
+        debug!("Response status: {}", status);
+        check_status(status, &body)?;
+
+        Ok(HttpResponse { status: status.as_u16(), headers, body })
+    }
+
     /// Make a REST-XML request (S3, Route53, CloudFront)
     pub async fn rest_xml_request(
         &self,
@@ -588,8 +786,69 @@ impl AwsHttpClient {
         body: &str,
         extra_headers: Option<HashMap<String, String>>,
     ) -> Result<String> {
+        self.signed_request_logged(service, method, url, body, extra_headers, true).await
+    }
+
+    /// Make a signed request, tracing the response body only when `log_bodies` is set
+    async fn signed_request_logged(
+        &self,
+        service: &ServiceDefinition,
+        method: &str,
+        url: &str,
+        body: &str,
+        extra_headers: Option<HashMap<String, String>>,
+        log_bodies: bool,
+    ) -> Result<String> {
+        let mut attempt = 1;
+        loop {
+            match self.signed_request_once(service, method, url, body, extra_headers.clone(), log_bodies).await {
+                Err(e) if attempt < self.retry.max_attempts && is_transient_error(&e) => {
+                    let delay = self.retry.delay(attempt);
+                    debug!("Transient error (attempt {}), retrying in {:?}: {}", attempt, delay, e);
+                    tokio::time::sleep(delay).await;
+                    attempt += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+
+    /// Sign and send a single request (no retries)
+    async fn signed_request_once(
+        &self,
+        service: &ServiceDefinition,
+        method: &str,
+        url: &str,
+        body: &str,
+        extra_headers: Option<HashMap<String, String>>,
+        log_bodies: bool,
+    ) -> Result<String> {
+        let response = self.send_signed(service, method, url, body, extra_headers).await?;
+        let status = response.status();
+        let text = response.text().await?;
+
+        debug!("Response status: {}", status);
+        if log_bodies {
+            trace!("Response body (first 2000 chars): {}", char_prefix(&text, 2000));
+        }
+
+        check_status(status, &text)?;
+
+        Ok(text)
+    }
+
+    /// Sign and send a request, returning the raw response
+    async fn send_signed(
+        &self,
+        service: &ServiceDefinition,
+        method: &str,
+        url: &str,
+        body: &str,
+        extra_headers: Option<HashMap<String, String>>,
+    ) -> Result<reqwest::Response> {
+        self.ensure_online()?;
         let region = if service.is_global {
-            "us-east-1"
+            GLOBAL_REGION
         } else {
             &self.region
         };
@@ -694,19 +953,7 @@ impl AwsHttpClient {
 
         // Send request
         trace!("Sending {} request to {}", method, url);
-        let response = request.send().await?;
-        let status = response.status();
-        let text = response.text().await?;
-
-        debug!("Response status: {}", status);
-        trace!("Response body (first 2000 chars): {}", &text[..text.len().min(2000)]);
-
-        if !status.is_success() {
-            warn!("AWS request failed: status={}, body={}", status, &text[..text.len().min(500)]);
-            return Err(anyhow!("AWS request failed ({}): {}", status, text));
-        }
-
-        Ok(text)
+        Ok(request.send().await?)
     }
 
     /// Make a signed request with explicit region override
@@ -720,6 +967,7 @@ impl AwsHttpClient {
         extra_headers: Option<HashMap<String, String>>,
         region: &str,
     ) -> Result<String> {
+        self.ensure_online()?;
         // Parse URL
         let parsed_url = url::Url::parse(url)?;
         let host = parsed_url.host_str().ok_or_else(|| anyhow!("Invalid URL"))?;
@@ -822,12 +1070,9 @@ impl AwsHttpClient {
         let text = response.text().await?;
 
         debug!("Response status: {}", status);
-        trace!("Response body (first 2000 chars): {}", &text[..text.len().min(2000)]);
+        trace!("Response body (first 2000 chars): {}", char_prefix(&text, 2000));
 
-        if !status.is_success() {
-            warn!("AWS request failed: status={}, body={}", status, &text[..text.len().min(500)]);
-            return Err(anyhow!("AWS request failed ({}): {}", status, text));
-        }
+        check_status(status, &text)?;
 
         Ok(text)
     }
@@ -1,51 +1,82 @@
-use anyhow::Result;
-use std::collections::HashSet;
+use super::credentials::parse_ini_file;
+use std::collections::BTreeMap;
+use std::collections::HashMap;
 use std::fs;
 use std::path::PathBuf;
 
-/// List all AWS profiles from ~/.aws/credentials and ~/.aws/config
-pub fn list_profiles() -> Result<Vec<String>> {
-    let mut profiles = HashSet::new();
+/// A profile from ~/.aws/config or ~/.aws/credentials
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Profile {
+    pub name: String,
+    /// Region configured for the profile
+    pub region: Option<String>,
+    /// `sso_session` reference (modern SSO config)
+    pub sso_session: Option<String>,
+    /// Role assumed by the profile
+    pub role_arn: Option<String>,
+    /// `sso_start_url` set on the profile itself (legacy SSO config)
+    pub sso_start_url: Option<String>,
+}
 
-    // Always include default
-    profiles.insert("default".to_string());
-
-    // Read from ~/.aws/credentials
-    if let Some(creds_path) = get_aws_credentials_path() {
-        if let Ok(content) = fs::read_to_string(&creds_path) {
-            for line in content.lines() {
-                let line = line.trim();
-                if line.starts_with('[') && line.ends_with(']') {
-                    let profile = line[1..line.len() - 1].to_string();
-                    profiles.insert(profile);
-                }
-            }
+impl Profile {
+    /// How the profile gets credentials: "SSO", "Role" or "Keys"
+    pub fn auth_kind(&self) -> &'static str {
+        if self.sso_session.is_some() || self.sso_start_url.is_some() {
+            "SSO"
+        } else if self.role_arn.is_some() {
+            "Role"
+        } else {
+            "Keys"
         }
     }
+}
+
+/// List all AWS profiles from ~/.aws/credentials and ~/.aws/config
+pub fn list_profiles() -> Vec<Profile> {
+    let read = |path: Option<PathBuf>| {
+        path.and_then(|p| fs::read_to_string(p).ok())
+            .unwrap_or_default()
+    };
+    parse_profiles(&read(get_aws_credentials_path()), &read(get_aws_config_path()))
+}
 
-    // Read from ~/.aws/config
-    if let Some(config_path) = get_aws_config_path() {
-        if let Ok(content) = fs::read_to_string(&config_path) {
-            for line in content.lines() {
-                let line = line.trim();
-                if line.starts_with('[') && line.ends_with(']') {
-                    let section = &line[1..line.len() - 1];
-                    // Config file uses "profile <name>" format, except for default
-                    let profile = if section.starts_with("profile ") {
-                        section.strip_prefix("profile ").unwrap().to_string()
-                    } else {
-                        section.to_string()
-                    };
-                    profiles.insert(profile);
-                }
+/// Merge the profiles defined in a credentials file and a config file
+///
+/// Non-profile sections such as `[sso-session name]` and `[services name]` are
+/// skipped. Settings from the config file win over the credentials file.
+fn parse_profiles(credentials: &str, config: &str) -> Vec<Profile> {
+    let mut profiles: BTreeMap<String, Profile> = BTreeMap::new();
+
+    // Always include default
+    profiles.insert("default".to_string(), Profile {
+        name: "default".to_string(),
+        ..Default::default()
+    });
+
+    for content in [credentials, config] {
+        for (name, settings) in parse_ini_file(content) {
+            // Profile names can't contain spaces; "sso-session x" and friends can
+            if name.contains(char::is_whitespace) {
+                continue;
             }
+            let profile = profiles.entry(name.clone()).or_insert_with(|| Profile {
+                name,
+                ..Default::default()
+            });
+            merge_setting(&mut profile.region, &settings, "region");
+            merge_setting(&mut profile.sso_session, &settings, "sso_session");
+            merge_setting(&mut profile.role_arn, &settings, "role_arn");
+            merge_setting(&mut profile.sso_start_url, &settings, "sso_start_url");
         }
     }
 
-    let mut profiles: Vec<String> = profiles.into_iter().collect();
-    profiles.sort();
+    profiles.into_values().collect()
+}
 
-    Ok(profiles)
+fn merge_setting(slot: &mut Option<String>, settings: &HashMap<String, String>, key: &str) {
+    if let Some(value) = settings.get(key).filter(|v| !v.is_empty()) {
+        *slot = Some(value.clone());
+    }
 }
 
 /// List common AWS regions
@@ -81,6 +112,41 @@ pub fn list_regions() -> Vec<String> {
     ]
 }
 
+/// Console name of a region, e.g. "US East (N. Virginia)" for us-east-1
+pub fn region_name(region: &str) -> Option<&'static str> {
+    let name = match region {
+        "us-east-1" => "US East (N. Virginia)",
+        "us-east-2" => "US East (Ohio)",
+        "us-west-1" => "US West (N. California)",
+        "us-west-2" => "US West (Oregon)",
+        "af-south-1" => "Africa (Cape Town)",
+        "ap-east-1" => "Asia Pacific (Hong Kong)",
+        "ap-south-1" => "Asia Pacific (Mumbai)",
+        "ap-south-2" => "Asia Pacific (Hyderabad)",
+        "ap-southeast-1" => "Asia Pacific (Singapore)",
+        "ap-southeast-2" => "Asia Pacific (Sydney)",
+        "ap-southeast-3" => "Asia Pacific (Jakarta)",
+        "ap-southeast-4" => "Asia Pacific (Melbourne)",
+        "ap-northeast-1" => "Asia Pacific (Tokyo)",
+        "ap-northeast-2" => "Asia Pacific (Seoul)",
+        "ap-northeast-3" => "Asia Pacific (Osaka)",
+        "ca-central-1" => "Canada (Central)",
+        "eu-central-1" => "Europe (Frankfurt)",
+        "eu-central-2" => "Europe (Zurich)",
+        "eu-west-1" => "Europe (Ireland)",
+        "eu-west-2" => "Europe (London)",
+        "eu-west-3" => "Europe (Paris)",
+        "eu-south-1" => "Europe (Milan)",
+        "eu-south-2" => "Europe (Spain)",
+        "eu-north-1" => "Europe (Stockholm)",
+        "me-south-1" => "Middle East (Bahrain)",
+        "me-central-1" => "Middle East (UAE)",
+        "sa-east-1" => "South America (São Paulo)",
+        _ => return None,
+    };
+    Some(name)
+}
+
 fn get_aws_credentials_path() -> Option<PathBuf> {
     // Check AWS_SHARED_CREDENTIALS_FILE env var first
     if let Ok(path) = std::env::var("AWS_SHARED_CREDENTIALS_FILE") {
@@ -100,3 +166,81 @@ fn get_aws_config_path() -> Option<PathBuf> {
     // Fall back to ~/.aws/config
     dirs::home_dir().map(|h| h.join(".aws").join("config"))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_profiles_reads_settings_and_skips_sessions() {
+        let credentials = r#"
+[default]
+aws_access_key_id = AKIADEFAULT
+
+[ci]
+aws_access_key_id = AKIACI
+region = us-west-1
+"#;
+        let config = r#"
+[default]
+region = us-east-1
+
+[profile ci]
+region = eu-west-1
+
+[profile dev]
+sso_session = corp
+sso_account_id = 123456789012
+
+[profile admin]
+role_arn = arn:aws:iam::123456789012:role/Admin
+source_profile = default
+
+[sso-session corp]
+sso_start_url = https://corp.awsapps.com/start
+"#;
+        let profiles = parse_profiles(credentials, config);
+        let names: Vec<&str> = profiles.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(names, ["admin", "ci", "default", "dev"]);
+
+        let find = |name: &str| profiles.iter().find(|p| p.name == name).unwrap();
+        assert_eq!(find("default").region.as_deref(), Some("us-east-1"));
+        assert_eq!(find("ci").region.as_deref(), Some("eu-west-1"));
+        assert_eq!(find("dev").sso_session.as_deref(), Some("corp"));
+        assert_eq!(
+            find("admin").role_arn.as_deref(),
+            Some("arn:aws:iam::123456789012:role/Admin")
+        );
+    }
+
+    #[test]
+    fn test_auth_kind_and_region_names() {
+        let config = r#"
+[profile dev]
+sso_session = corp
+
+[profile legacy]
+sso_start_url = https://corp.awsapps.com/start
+
+[profile admin]
+role_arn = arn:aws:iam::123456789012:role/Admin
+"#;
+        let profiles = parse_profiles("", config);
+        let kind = |name: &str| profiles.iter().find(|p| p.name == name).unwrap().auth_kind();
+        assert_eq!(kind("dev"), "SSO");
+        assert_eq!(kind("legacy"), "SSO");
+        assert_eq!(kind("admin"), "Role");
+        assert_eq!(kind("default"), "Keys");
+
+        assert!(list_regions().iter().all(|r| region_name(r).is_some()), "every listed region has a name");
+        assert_eq!(region_name("eu-west-1"), Some("Europe (Ireland)"));
+        assert_eq!(region_name("xx-nowhere-1"), None);
+    }
+
+    #[test]
+    fn test_parse_profiles_always_has_default() {
+        let profiles = parse_profiles("", "");
+        assert_eq!(profiles.len(), 1);
+        assert_eq!(profiles[0].name, "default");
+    }
+}
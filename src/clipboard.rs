@@ -0,0 +1,16 @@
+//! System clipboard access through the terminal.
+//!
+//! Uses the OSC 52 escape sequence so copying works over SSH and inside tmux
+//! without a platform clipboard library. Terminals that don't support OSC 52
+//! silently ignore it.
+
+use anyhow::Result;
+use crossterm::clipboard::CopyToClipboard;
+use crossterm::execute;
+use std::io::stdout;
+
+/// Put `text` on the system clipboard
+pub fn copy(text: &str) -> Result<()> {
+    execute!(stdout(), CopyToClipboard::to_clipboard_from(text))?;
+    Ok(())
+}
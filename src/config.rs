@@ -3,12 +3,47 @@
 //! Stores user preferences in ~/.config/taws/config.yaml (XDG compliant)
 //! Falls back to ~/.taws/config.yaml if XDG dirs not available
 
+use crate::aws::http::RetryPolicy;
+use crate::ui::theme::Theme;
 use anyhow::Result;
+use chrono_tz::Tz;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::fs;
 use std::path::PathBuf;
+use std::time::Duration;
 use tracing::{debug, warn};
 
+/// Default limit on concurrent describe calls in N+1 list operations
+pub const DEFAULT_MAX_CONCURRENCY: usize = 10;
+
+/// Default time a fetched list is reused when navigating back to it
+pub const DEFAULT_CACHE_TTL_SECS: u64 = 30;
+
+/// Commands kept in `command_history`
+pub const COMMAND_HISTORY_LEN: usize = 50;
+
+/// Regions bound to Alt+number when no `region_shortcuts` are configured
+pub const DEFAULT_REGION_SHORTCUTS: &[&str] = &[
+    "us-east-1",
+    "us-west-2",
+    "eu-west-1",
+    "eu-central-1",
+    "ap-northeast-1",
+    "ap-southeast-1",
+];
+
+/// Per-resource view settings, remembered across sessions
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ViewPrefs {
+    /// Show the details panel beside the table
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub details_panel: Option<bool>,
+    /// Headers of the columns hidden with the column picker (`C`)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub hidden_columns: Vec<String>,
+}
+
 /// User configuration stored on disk
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct Config {
@@ -23,6 +58,123 @@ pub struct Config {
     /// Last viewed resource type
     #[serde(default)]
     pub last_resource: Option<String>,
+
+    /// Maximum concurrent describe calls (lower this if AWS throttles requests)
+    #[serde(default)]
+    pub max_concurrency: Option<usize>,
+
+    /// Attempts for read requests that fail transiently (1 disables retries)
+    #[serde(default)]
+    pub retry_max_attempts: Option<u32>,
+
+    /// Delay before the first retry in milliseconds (doubles each attempt)
+    #[serde(default)]
+    pub retry_base_delay_ms: Option<u64>,
+
+    /// Seconds a fetched list is reused before navigation refetches it (0 disables)
+    #[serde(default)]
+    pub cache_ttl_secs: Option<u64>,
+
+    /// Regions bound to Alt+number, in order starting at Alt+0
+    #[serde(default)]
+    pub region_shortcuts: Option<Vec<String>>,
+
+    /// Moving past the last row goes to the first, and before the first to the last
+    #[serde(default)]
+    pub wrap_navigation: Option<bool>,
+
+    /// Keep the last error in a banner above the table until a list loads or it is dismissed
+    #[serde(default)]
+    pub sticky_errors: Option<bool>,
+
+    /// Check a region is reachable (sts:GetCallerIdentity) before switching to it
+    #[serde(default)]
+    pub verify_region_switch: Option<bool>,
+
+    /// Send every API call to this URL instead of AWS (LocalStack, moto, ...)
+    #[serde(default)]
+    pub endpoint_url: Option<String>,
+
+    /// Timezone date columns are shown in: `utc` (default), `local` or an IANA name
+    #[serde(default)]
+    pub timezone: Option<String>,
+
+    /// View settings per resource key (e.g. "ec2-instances")
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub view_prefs: HashMap<String, ViewPrefs>,
+
+    /// Resource keys marked as favorites (`F`), in the order they were added
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub favorites: Vec<String>,
+
+    /// Recently executed `:` commands, newest first
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub command_history: Vec<String>,
+
+    /// Color scheme (preset plus per-role overrides)
+    #[serde(default)]
+    pub theme: Option<ThemeConfig>,
+
+    /// Render without colors (set from `--no-color` / `NO_COLOR`, never saved)
+    #[serde(skip)]
+    pub no_color: bool,
+}
+
+/// Theme section of the config file
+///
+/// Colors are names (`cyan`, `darkgray`, ...) or hex RGB (`#rrggbb`).
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+pub struct ThemeConfig {
+    /// Base palette: `default` (dark terminals) or `light`
+    #[serde(default)]
+    pub preset: Option<String>,
+    #[serde(default)]
+    pub title: Option<String>,
+    #[serde(default)]
+    pub header: Option<String>,
+    #[serde(default)]
+    pub text: Option<String>,
+    #[serde(default)]
+    pub muted: Option<String>,
+    #[serde(default)]
+    pub accent: Option<String>,
+    #[serde(default)]
+    pub error: Option<String>,
+    #[serde(default)]
+    pub success: Option<String>,
+    #[serde(default)]
+    pub warning: Option<String>,
+    #[serde(default)]
+    pub selected_fg: Option<String>,
+    #[serde(default)]
+    pub selected_bg: Option<String>,
+    #[serde(default)]
+    pub badge_text: Option<String>,
+}
+
+/// Timezone date columns are shown in (`timezone` in the config file)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DisplayTimezone {
+    #[default]
+    Utc,
+    /// The system timezone of the machine taws runs on
+    Local,
+    /// An IANA timezone such as `Europe/Berlin`
+    Named(Tz),
+}
+
+impl DisplayTimezone {
+    /// Parse `utc`, `local` or an IANA name; `None` for anything else
+    pub fn parse(name: &str) -> Option<Self> {
+        let name = name.trim();
+        if name.eq_ignore_ascii_case("utc") {
+            Some(DisplayTimezone::Utc)
+        } else if name.eq_ignore_ascii_case("local") {
+            Some(DisplayTimezone::Local)
+        } else {
+            name.parse::<Tz>().ok().map(DisplayTimezone::Named)
+        }
+    }
 }
 
 impl Config {
@@ -72,20 +224,25 @@ impl Config {
     }
 
     /// Get the config file path
-    /// Uses XDG config directory if available, otherwise ~/.taws/
     fn config_path() -> PathBuf {
-        // Try XDG config dir first (e.g., ~/.config/taws/config.yaml)
+        Self::config_dir().join("config.yaml")
+    }
+
+    /// Get the taws config directory
+    /// Uses XDG config directory if available, otherwise ~/.taws/
+    pub fn config_dir() -> PathBuf {
+        // Try XDG config dir first (e.g., ~/.config/taws/)
         if let Some(config_dir) = dirs::config_dir() {
-            return config_dir.join("taws").join("config.yaml");
+            return config_dir.join("taws");
         }
 
         // Fallback to home directory
         if let Some(home) = dirs::home_dir() {
-            return home.join(".taws").join("config.yaml");
+            return home.join(".taws");
         }
 
         // Last resort: current directory
-        PathBuf::from(".taws").join("config.yaml")
+        PathBuf::from(".taws")
     }
 
     /// Update profile and save
@@ -102,6 +259,52 @@ impl Config {
         self.save()
     }
 
+    /// View settings saved for a resource (defaults if none)
+    pub fn view_prefs(&self, resource_key: &str) -> ViewPrefs {
+        self.view_prefs.get(resource_key).cloned().unwrap_or_default()
+    }
+
+    /// Update the view settings of a resource and save
+    pub fn set_view_prefs(&mut self, resource_key: &str, prefs: ViewPrefs) -> Result<()> {
+        if prefs == ViewPrefs::default() {
+            self.view_prefs.remove(resource_key);
+        } else {
+            self.view_prefs.insert(resource_key.to_string(), prefs);
+        }
+        self.save()
+    }
+
+    /// Put a command at the front of the history, dropping older duplicates
+    pub fn remember_command(&mut self, command: &str) {
+        self.command_history.retain(|c| c != command);
+        self.command_history.insert(0, command.to_string());
+        self.command_history.truncate(COMMAND_HISTORY_LEN);
+    }
+
+    /// Remember a command and save
+    pub fn push_command_history(&mut self, command: &str) -> Result<()> {
+        self.remember_command(command);
+        self.save()
+    }
+
+    /// Add `resource_key` to the favorites in memory, or remove it if it is one; true when added
+    pub fn toggle_favorite(&mut self, resource_key: &str) -> bool {
+        if let Some(pos) = self.favorites.iter().position(|f| f == resource_key) {
+            self.favorites.remove(pos);
+            false
+        } else {
+            self.favorites.push(resource_key.to_string());
+            true
+        }
+    }
+
+    /// Toggle a favorite and save; true when it was added
+    pub fn toggle_favorite_and_save(&mut self, resource_key: &str) -> Result<bool> {
+        let added = self.toggle_favorite(resource_key);
+        self.save()?;
+        Ok(added)
+    }
+
     /// Update last resource and save
     #[allow(dead_code)]
     pub fn set_last_resource(&mut self, resource: &str) -> Result<()> {
@@ -109,6 +312,79 @@ impl Config {
         self.save()
     }
 
+    /// Get the concurrency limit for detail-fetching operations (at least 1)
+    pub fn max_concurrency(&self) -> usize {
+        self.max_concurrency
+            .unwrap_or(DEFAULT_MAX_CONCURRENCY)
+            .max(1)
+    }
+
+    /// Get the retry policy for read requests
+    pub fn retry_policy(&self) -> RetryPolicy {
+        let default = RetryPolicy::default();
+        RetryPolicy {
+            max_attempts: self.retry_max_attempts.unwrap_or(default.max_attempts).max(1),
+            base_delay: self.retry_base_delay_ms
+                .map(Duration::from_millis)
+                .unwrap_or(default.base_delay),
+        }
+    }
+
+    /// Get how long fetched lists are served from the cache (zero disables caching)
+    pub fn cache_ttl(&self) -> Duration {
+        Duration::from_secs(self.cache_ttl_secs.unwrap_or(DEFAULT_CACHE_TTL_SECS))
+    }
+
+    /// Whether j/k wrap around at the ends of lists (off by default)
+    pub fn wrap_navigation(&self) -> bool {
+        self.wrap_navigation.unwrap_or(false)
+    }
+
+    /// Whether errors stay in a banner until resolved or dismissed (off by default)
+    pub fn sticky_errors(&self) -> bool {
+        self.sticky_errors.unwrap_or(false)
+    }
+
+    /// Whether region switches are verified before they take effect (on by default)
+    pub fn verify_region_switch(&self) -> bool {
+        self.verify_region_switch.unwrap_or(true)
+    }
+
+    /// Get the timezone date columns are shown in (UTC when unset or unknown)
+    pub fn timezone(&self) -> DisplayTimezone {
+        let Some(name) = self.timezone.as_deref() else {
+            return DisplayTimezone::Utc;
+        };
+        DisplayTimezone::parse(name).unwrap_or_else(|| {
+            warn!("Unknown timezone '{}', showing dates in UTC", name);
+            DisplayTimezone::Utc
+        })
+    }
+
+    /// Get the number keys and the regions Alt+key switches to (keys `0`-`9`, in order)
+    ///
+    /// The key handler and the header both read this, so they can't disagree.
+    pub fn region_shortcuts(&self) -> Vec<(char, String)> {
+        let regions: Vec<String> = match &self.region_shortcuts {
+            Some(regions) => regions.clone(),
+            None => DEFAULT_REGION_SHORTCUTS.iter().map(|r| r.to_string()).collect(),
+        };
+        ('0'..='9').zip(regions).collect()
+    }
+
+    /// Get the color theme (default palette when no theme section is set)
+    pub fn theme(&self) -> Theme {
+        let theme = self.theme
+            .as_ref()
+            .map(Theme::from_config)
+            .unwrap_or_default();
+        if self.no_color {
+            theme.monochrome()
+        } else {
+            theme
+        }
+    }
+
     /// Get effective profile (config -> env -> default)
     pub fn effective_profile(&self) -> String {
         // Priority: 1. Environment variable, 2. Config file, 3. Default
@@ -127,6 +403,24 @@ impl Config {
             .or_else(|| self.region.clone())
             .unwrap_or_else(|| "us-east-1".to_string())
     }
+
+    /// Endpoint override from the config file, ignoring a blank value
+    pub fn endpoint_url(&self) -> Option<String> {
+        self.endpoint_url
+            .as_deref()
+            .map(str::trim)
+            .filter(|url| !url.is_empty())
+            .map(str::to_string)
+    }
+
+    /// Endpoint override: `AWS_ENDPOINT_URL`, then `endpoint_url` in the config file.
+    /// None (the default) talks to the real AWS endpoints.
+    pub fn effective_endpoint_url(&self) -> Option<String> {
+        std::env::var("AWS_ENDPOINT_URL")
+            .ok()
+            .filter(|url| !url.trim().is_empty())
+            .or_else(|| self.endpoint_url())
+    }
 }
 
 #[cfg(test)]
@@ -138,6 +432,7 @@ mod tests {
         let config = Config::default();
         assert!(config.profile.is_none());
         assert!(config.region.is_none());
+        assert!(!config.sticky_errors());
     }
 
     #[test]
@@ -146,6 +441,7 @@ mod tests {
             profile: Some("my-profile".to_string()),
             region: Some("eu-west-1".to_string()),
             last_resource: Some("ec2-instances".to_string()),
+            ..Default::default()
         };
 
         let yaml = serde_yaml::to_string(&config).unwrap();
@@ -155,4 +451,169 @@ mod tests {
         assert_eq!(parsed.region, config.region);
         assert_eq!(parsed.last_resource, config.last_resource);
     }
+
+    #[test]
+    fn test_max_concurrency_default_and_override() {
+        let config: Config = serde_yaml::from_str("profile: dev\n").unwrap();
+        assert_eq!(config.max_concurrency(), DEFAULT_MAX_CONCURRENCY);
+
+        let config: Config = serde_yaml::from_str("max_concurrency: 3\n").unwrap();
+        assert_eq!(config.max_concurrency(), 3);
+
+        let config: Config = serde_yaml::from_str("max_concurrency: 0\n").unwrap();
+        assert_eq!(config.max_concurrency(), 1);
+    }
+
+    #[test]
+    fn test_cache_ttl_default_and_override() {
+        let config = Config::default();
+        assert_eq!(config.cache_ttl(), Duration::from_secs(DEFAULT_CACHE_TTL_SECS));
+
+        let config: Config = serde_yaml::from_str("cache_ttl_secs: 0\n").unwrap();
+        assert!(config.cache_ttl().is_zero());
+    }
+
+    #[test]
+    fn test_timezone_defaults_to_utc() {
+        assert_eq!(Config::default().timezone(), DisplayTimezone::Utc);
+
+        let config: Config = serde_yaml::from_str("timezone: Europe/Berlin\n").unwrap();
+        assert!(matches!(config.timezone(), DisplayTimezone::Named(_)));
+
+        let config: Config = serde_yaml::from_str("timezone: Nowhere/Atlantis\n").unwrap();
+        assert_eq!(config.timezone(), DisplayTimezone::Utc);
+
+        assert_eq!(DisplayTimezone::parse("UTC"), Some(DisplayTimezone::Utc));
+        assert_eq!(DisplayTimezone::parse("local"), Some(DisplayTimezone::Local));
+        assert_eq!(DisplayTimezone::parse("Mars/Olympus"), None);
+    }
+
+    #[test]
+    fn test_wrap_navigation_defaults_off() {
+        assert!(!Config::default().wrap_navigation());
+
+        let config: Config = serde_yaml::from_str("wrap_navigation: true\n").unwrap();
+        assert!(config.wrap_navigation());
+    }
+
+    #[test]
+    fn test_verify_region_switch_defaults_on() {
+        assert!(Config::default().verify_region_switch());
+
+        let config: Config = serde_yaml::from_str("verify_region_switch: false\n").unwrap();
+        assert!(!config.verify_region_switch());
+    }
+
+    #[test]
+    fn test_view_prefs_round_trip() {
+        let yaml = "view_prefs:\n  ec2-instances:\n    details_panel: true\n";
+        let config: Config = serde_yaml::from_str(yaml).unwrap();
+        assert_eq!(config.view_prefs("ec2-instances").details_panel, Some(true));
+        assert_eq!(config.view_prefs("s3-buckets"), ViewPrefs::default());
+
+        let saved = serde_yaml::to_string(&config).unwrap();
+        assert!(saved.contains("details_panel: true"));
+        assert!(!saved.contains("hidden_columns"));
+        assert!(!serde_yaml::to_string(&Config::default()).unwrap().contains("view_prefs"));
+
+        let yaml = "view_prefs:\n  rds-instances:\n    hidden_columns: [ENGINE, AZ]\n";
+        let config: Config = serde_yaml::from_str(yaml).unwrap();
+        assert_eq!(config.view_prefs("rds-instances").hidden_columns, vec!["ENGINE", "AZ"]);
+    }
+
+    #[test]
+    fn test_endpoint_url_ignores_blank_values() {
+        let mut config = Config::default();
+        assert_eq!(config.endpoint_url(), None);
+
+        config.endpoint_url = Some("  ".to_string());
+        assert_eq!(config.endpoint_url(), None);
+
+        config.endpoint_url = Some("http://localhost:4566 ".to_string());
+        assert_eq!(config.endpoint_url().as_deref(), Some("http://localhost:4566"));
+    }
+
+    #[test]
+    fn test_favorites_toggle_in_insertion_order() {
+        let mut config = Config::default();
+        assert!(config.toggle_favorite("ec2-instances"));
+        assert!(config.toggle_favorite("lambda-functions"));
+        assert!(!config.toggle_favorite("ec2-instances"));
+        assert!(config.toggle_favorite("ec2-instances"));
+        assert_eq!(config.favorites, vec!["lambda-functions", "ec2-instances"]);
+    }
+
+    #[test]
+    fn test_command_history_is_bounded_and_deduplicated() {
+        let mut config = Config::default();
+        for i in 0..COMMAND_HISTORY_LEN + 5 {
+            config.remember_command(&format!("region r{}", i));
+        }
+        config.remember_command("ec2");
+        config.remember_command("region r60");
+        config.remember_command("ec2");
+        assert_eq!(config.command_history.len(), COMMAND_HISTORY_LEN);
+        assert_eq!(config.command_history[0], "ec2");
+        assert_eq!(config.command_history.iter().filter(|c| *c == "ec2").count(), 1);
+    }
+
+    #[test]
+    fn test_retry_policy_from_config() {
+        let config = Config::default();
+        assert_eq!(config.retry_policy(), RetryPolicy::default());
+
+        let config: Config =
+            serde_yaml::from_str("retry_max_attempts: 5\nretry_base_delay_ms: 50\n").unwrap();
+        let policy = config.retry_policy();
+        assert_eq!(policy.max_attempts, 5);
+        assert_eq!(policy.base_delay, Duration::from_millis(50));
+    }
+
+    #[test]
+    fn test_region_shortcuts_default_and_override() {
+        let defaults = Config::default().region_shortcuts();
+        assert_eq!(defaults.len(), DEFAULT_REGION_SHORTCUTS.len());
+        assert_eq!(defaults[0], ('0', "us-east-1".to_string()));
+        assert_eq!(defaults[5], ('5', "ap-southeast-1".to_string()));
+
+        let config: Config =
+            serde_yaml::from_str("region_shortcuts: [sa-east-1, ap-south-1]\n").unwrap();
+        assert_eq!(
+            config.region_shortcuts(),
+            [('0', "sa-east-1".to_string()), ('1', "ap-south-1".to_string())]
+        );
+
+        let regions: Vec<String> = (0..12).map(|i| format!("region-{}", i)).collect();
+        let config = Config {
+            region_shortcuts: Some(regions),
+            ..Default::default()
+        };
+        let shortcuts = config.region_shortcuts();
+        assert_eq!(shortcuts.len(), 10);
+        assert_eq!(shortcuts.last().unwrap().0, '9');
+    }
+
+    #[test]
+    fn test_theme_preset_and_overrides() {
+        assert_eq!(Config::default().theme(), Theme::default());
+
+        let config: Config = serde_yaml::from_str("theme:\n  preset: light\n").unwrap();
+        assert_eq!(config.theme(), Theme::light());
+
+        let config: Config = serde_yaml::from_str(
+            "theme:\n  title: \"#ff8800\"\n  selected_bg: blue\n  error: not-a-color\n",
+        )
+        .unwrap();
+        let theme = config.theme();
+        assert_eq!(theme.title, ratatui::style::Color::Rgb(0xff, 0x88, 0x00));
+        assert_eq!(theme.selected_bg, ratatui::style::Color::Blue);
+        assert_eq!(theme.error, Theme::default().error);
+
+        let config = Config {
+            no_color: true,
+            ..config
+        };
+        assert!(config.theme().monochrome);
+        assert_eq!(config.theme().selected_bg, ratatui::style::Color::Blue);
+    }
 }
@@ -1,19 +1,97 @@
 use crate::app::{App, Mode, SsoLoginState};
 use crate::aws::sso;
 use anyhow::Result;
-use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use crossterm::event::{
+    self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers, MouseButton, MouseEvent,
+    MouseEventKind,
+};
 use std::time::Duration;
 
+/// Two clicks on the same row within this window count as a double-click
+const DOUBLE_CLICK_WINDOW: Duration = Duration::from_millis(400);
+
+/// Lines scrolled per mouse wheel step in text views
+const WHEEL_SCROLL_LINES: usize = 3;
+
+/// Shown for actions in the all-regions view, whose rows come from other regions
+const ALL_REGIONS_WARNING: &str = "Press Esc to leave the all-regions view before running actions";
+
 pub async fn handle_events(app: &mut App) -> Result<bool> {
     if event::poll(Duration::from_millis(100))? {
-        if let Event::Key(key) = event::read()? {
-            // Only handle key press events, not release or repeat
-            // This fixes double key presses on Windows
-            if key.kind != KeyEventKind::Press {
+        match event::read()? {
+            Event::Key(key) => {
+                // Only handle key press events, not release or repeat
+                // This fixes double key presses on Windows
+                if key.kind != KeyEventKind::Press {
+                    return Ok(false);
+                }
+                return handle_key_event(app, key).await;
+            }
+            Event::Mouse(mouse) => return handle_mouse_event(app, mouse).await,
+            _ => {}
+        }
+    }
+    Ok(false)
+}
+
+/// Switch between app mouse handling and the terminal's own text selection
+fn toggle_mouse_capture(app: &mut App) -> Result<()> {
+    let mut stdout = std::io::stdout();
+    if app.mouse_enabled {
+        crossterm::execute!(stdout, DisableMouseCapture)?;
+        app.mouse_enabled = false;
+        app.show_warning("Mouse capture off: select text with the mouse. Press M to turn it back on.");
+    } else {
+        crossterm::execute!(stdout, EnableMouseCapture)?;
+        app.mouse_enabled = true;
+        app.show_warning("Mouse capture on: click, double-click and scroll work. Press M to select text again.");
+    }
+    Ok(())
+}
+
+async fn handle_mouse_event(app: &mut App, mouse: MouseEvent) -> Result<bool> {
+    match (&app.mode, mouse.kind) {
+        (Mode::Normal | Mode::Profiles | Mode::Regions | Mode::Search, MouseEventKind::ScrollDown) => app.next(),
+        (Mode::Normal | Mode::Profiles | Mode::Regions | Mode::Search, MouseEventKind::ScrollUp) => app.previous(),
+        (Mode::Normal | Mode::Profiles | Mode::Regions | Mode::Search, MouseEventKind::Down(MouseButton::Left)) => {
+            let Some(row) = app.row_at(mouse.column, mouse.row) else {
+                return Ok(false);
+            };
+            app.select_row(row);
+
+            let double_click = matches!(
+                app.last_click,
+                Some((last_row, at)) if last_row == row && at.elapsed() < DOUBLE_CLICK_WINDOW
+            );
+            if !double_click {
+                app.last_click = Some((row, std::time::Instant::now()));
                 return Ok(false);
             }
-            return handle_key_event(app, key).await;
+            app.last_click = None;
+            match app.mode {
+                Mode::Profiles => {
+                    app.select_profile().await?;
+                }
+                Mode::Regions => app.select_region().await?,
+                Mode::Search => app.open_search_result().await?,
+                _ => app.enter_describe_mode().await,
+            }
+        }
+        (Mode::Describe, MouseEventKind::ScrollDown) => {
+            app.describe_scroll = app.describe_scroll.saturating_add(WHEEL_SCROLL_LINES);
+        }
+        (Mode::Describe, MouseEventKind::ScrollUp) => {
+            app.describe_scroll = app.describe_scroll.saturating_sub(WHEEL_SCROLL_LINES);
+        }
+        (Mode::ErrorDetail, MouseEventKind::ScrollDown) => {
+            app.error_scroll = app.error_scroll.saturating_add(WHEEL_SCROLL_LINES);
+        }
+        (Mode::ErrorDetail, MouseEventKind::ScrollUp) => {
+            app.error_scroll = app.error_scroll.saturating_sub(WHEEL_SCROLL_LINES);
         }
+        (Mode::LogTail, MouseEventKind::ScrollDown) => app.log_tail_scroll_down(WHEEL_SCROLL_LINES),
+        (Mode::LogTail, MouseEventKind::ScrollUp) => app.log_tail_scroll_up(WHEEL_SCROLL_LINES),
+        _ => {}
     }
     Ok(false)
 }
@@ -30,72 +108,72 @@ async fn handle_key_event(app: &mut App, key: KeyEvent) -> Result<bool> {
         Mode::Regions => handle_regions_mode(app, key).await,
         Mode::SsoLogin => handle_sso_login_mode(app, key).await,
         Mode::LogTail => handle_log_tail_mode(app, key).await,
+        Mode::Metrics => handle_metrics_mode(app, key).await,
+        Mode::Input => handle_input_mode(app, key).await,
+        Mode::QuickSwitch => handle_quick_switch_mode(app, key).await,
+        Mode::ErrorDetail => handle_error_detail_mode(app, key),
+        Mode::Search => handle_search_mode(app, key).await,
+        Mode::ConfirmQuit => handle_confirm_quit_mode(app, key),
+        Mode::Reveal => {
+            // Any key hides the value (exit_mode drops it)
+            app.exit_mode();
+            Ok(false)
+        }
+        Mode::Columns => handle_columns_mode(app, key),
+        Mode::Favorites => handle_favorites_mode(app, key).await,
     }
 }
 
-// Region shortcuts matching the header display
-const REGION_SHORTCUTS: &[&str] = &[
-    "us-east-1",
-    "us-west-2",
-    "eu-west-1",
-    "eu-central-1",
-    "ap-northeast-1",
-    "ap-southeast-1",
-];
-
 async fn handle_normal_mode(app: &mut App, key: KeyEvent) -> Result<bool> {
     // If filter is active, handle filter input
     if app.filter_active {
         return handle_filter_input(app, key).await;
     }
 
+    // Count prefix for movement: digits, then j/k/G. A leading 0 isn't a count
+    if let KeyCode::Char(c) = key.code {
+        if let Some(digit) = c.to_digit(10).filter(|_| !key.modifiers.contains(KeyModifiers::ALT)) {
+            if digit > 0 || app.pending_count().is_some() {
+                app.push_count_digit(digit);
+            }
+            return Ok(false);
+        }
+    }
+    // The count applies to the next key only
+    let count = app.take_count();
+
     match key.code {
         // Quit with Ctrl+C
-        KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => return Ok(true),
+        KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => return Ok(app.request_quit()),
 
-        // Region shortcuts (0-5)
-        KeyCode::Char('0') => {
-            if let Some(region) = REGION_SHORTCUTS.first() {
-                app.switch_region(region).await?;
-                app.refresh_current().await?;
-            }
-        }
-        KeyCode::Char('1') => {
-            if let Some(region) = REGION_SHORTCUTS.get(1) {
-                app.switch_region(region).await?;
-                app.refresh_current().await?;
-            }
-        }
-        KeyCode::Char('2') => {
-            if let Some(region) = REGION_SHORTCUTS.get(2) {
-                app.switch_region(region).await?;
-                app.refresh_current().await?;
-            }
-        }
-        KeyCode::Char('3') => {
-            if let Some(region) = REGION_SHORTCUTS.get(3) {
-                app.switch_region(region).await?;
-                app.refresh_current().await?;
+        // Region shortcuts (Alt+0-9, from config); bare digits are counts
+        KeyCode::Char(c) if c.is_ascii_digit() && key.modifiers.contains(KeyModifiers::ALT) => {
+            let shortcut = app.config.region_shortcuts().into_iter().find(|(key, _)| *key == c);
+            if let Some((_, region)) = shortcut {
+                if app.switch_region(&region).await? {
+                    app.refresh_current().await?;
+                }
             }
         }
-        KeyCode::Char('4') => {
-            if let Some(region) = REGION_SHORTCUTS.get(4) {
-                app.switch_region(region).await?;
-                app.refresh_current().await?;
+
+        // Navigation - vim style; a count repeats the move, and with G picks the row
+        KeyCode::Char('j') | KeyCode::Down => {
+            for _ in 0..count.unwrap_or(1) {
+                app.next();
             }
         }
-        KeyCode::Char('5') => {
-            if let Some(region) = REGION_SHORTCUTS.get(5) {
-                app.switch_region(region).await?;
-                app.refresh_current().await?;
+        KeyCode::Char('k') | KeyCode::Up => {
+            for _ in 0..count.unwrap_or(1) {
+                app.previous();
             }
         }
-
-        // Navigation - vim style
-        KeyCode::Char('j') | KeyCode::Down => app.next(),
-        KeyCode::Char('k') | KeyCode::Up => app.previous(),
         KeyCode::Home => app.go_to_top(),
-        KeyCode::Char('G') | KeyCode::End => app.go_to_bottom(),
+        KeyCode::Char('G') | KeyCode::End => match count {
+            Some(row) if !app.filtered_items.is_empty() => {
+                app.select_row((row - 1).min(app.filtered_items.len() - 1));
+            }
+            _ => app.go_to_bottom(),
+        },
 
         // Page navigation / Destructive action (ctrl+d)
         KeyCode::Char('d') if key.modifiers.contains(KeyModifiers::CONTROL) => {
@@ -106,11 +184,18 @@ async fn handle_normal_mode(app: &mut App, key: KeyEvent) -> Result<bool> {
                     if action.shortcut.as_deref() == Some("ctrl+d") {
                         if let Some(item) = app.selected_item() {
                             let id = crate::resource::extract_json_value(item, &resource.id_field);
+                            let inapplicable = action_inapplicable_reason(action, item, &id);
                             if id != "-" && !id.is_empty() {
+                                if let Some(reason) = inapplicable {
+                                    app.show_warning(&reason);
+                                    action_triggered = true;
                                 // Block action in readonly mode
-                                if app.readonly {
+                                } else if app.readonly {
                                     app.show_warning("This operation is not supported in read-only mode");
                                     action_triggered = true;
+                                } else if app.all_regions {
+                                    app.show_warning(ALL_REGIONS_WARNING);
+                                    action_triggered = true;
                                 } else if let Some(pending) = app.create_pending_action(action, &id) {
                                     app.enter_confirm_mode(pending);
                                     action_triggered = true;
@@ -123,17 +208,17 @@ async fn handle_normal_mode(app: &mut App, key: KeyEvent) -> Result<bool> {
             }
             // If no action, use as page down
             if !action_triggered {
-                app.page_down(10);
+                app.page_down(app.page_size());
             }
         }
         KeyCode::Char('u') if key.modifiers.contains(KeyModifiers::CONTROL) => {
-            app.page_up(10);
+            app.page_up(app.page_size());
         }
         KeyCode::Char('f') if key.modifiers.contains(KeyModifiers::CONTROL) => {
-            app.page_down(10);
+            app.page_down(app.page_size());
         }
         KeyCode::Char('b') if key.modifiers.contains(KeyModifiers::CONTROL) => {
-            app.page_up(10);
+            app.page_up(app.page_size());
         }
 
         // Describe mode (d or Enter)
@@ -145,6 +230,9 @@ async fn handle_normal_mode(app: &mut App, key: KeyEvent) -> Result<bool> {
             app.toggle_filter();
         }
 
+        // Mark rows for :export
+        KeyCode::Char(' ') => app.toggle_mark(),
+
         // Pagination - next/previous page of results (using ] and [ to avoid conflicts with sub-resource shortcuts)
         KeyCode::Char(']') => {
             if app.pagination.has_more {
@@ -160,25 +248,38 @@ async fn handle_normal_mode(app: &mut App, key: KeyEvent) -> Result<bool> {
         // Manual refresh
         KeyCode::Char('R') => {
             app.reset_pagination();
-            app.refresh_current().await?;
+            app.force_refresh_current().await?;
         }
 
         // Mode switches
         KeyCode::Char(':') => app.enter_command_mode(),
+        KeyCode::Char('p') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+            app.enter_quick_switch_mode()
+        }
         KeyCode::Char('?') => app.enter_help_mode(),
+        KeyCode::Char('e') => app.enter_error_detail_mode(),
+        KeyCode::Char('C') => app.enter_columns_mode(),
+        KeyCode::Char('I') => app.toggle_details_panel(),
+        KeyCode::Char('F') => app.toggle_favorite(),
+        KeyCode::Char('X') if app.sticky_error.is_some() => app.dismiss_sticky_error(),
+        KeyCode::Char('~') => app.enter_favorites_mode(),
+        KeyCode::Char('M') => toggle_mouse_capture(app)?,
+        KeyCode::Char('L') => app.start_sso_relogin(),
 
         // Backspace goes back in navigation
         KeyCode::Backspace => {
-            if app.parent_context.is_some() {
+            if app.parent_context.is_some() || app.all_regions {
                 app.navigate_back().await?;
             }
         }
 
-        // Escape clears filter if present
+        // Escape cancels a slow load, then clears the filter, then goes back
         KeyCode::Esc => {
-            if !app.filter_text.is_empty() {
+            if app.loading {
+                app.cancel_fetch();
+            } else if !app.filter_text.is_empty() {
                 app.clear_filter();
-            } else if app.parent_context.is_some() {
+            } else if app.parent_context.is_some() || app.all_regions {
                 app.navigate_back().await?;
             }
         }
@@ -206,15 +307,40 @@ async fn handle_normal_mode(app: &mut App, key: KeyEvent) -> Result<bool> {
                             if action.shortcut.as_deref() == Some(&c.to_string()) {
                                 if let Some(item) = app.selected_item() {
                                     let id = crate::resource::extract_json_value(item, &resource.id_field);
+                                    let name = Some(crate::resource::extract_json_value(item, &resource.name_field))
+                                        .filter(|name| name != "-" && !name.is_empty())
+                                        .unwrap_or_else(|| id.clone());
+                                    let inapplicable = action_inapplicable_reason(action, item, &name);
                                     if id != "-" && !id.is_empty() {
+                                        if let Some(reason) = inapplicable {
+                                            app.show_warning(&reason);
+                                            handled = true;
+                                        } else if app.all_regions {
+                                            app.show_warning(ALL_REGIONS_WARNING);
+                                            handled = true;
                                         // Special handling for log tailing action
-                                        if action.sdk_method == "tail_logs" {
+                                        } else if action.sdk_method == "tail_logs" {
                                             app.enter_log_tail_mode().await?;
                                             handled = true;
+                                        } else if action.sdk_method == "show_metrics" {
+                                            app.enter_metrics_mode().await?;
+                                            handled = true;
+                                        // Read-only, so allowed in read-only mode
+                                        } else if crate::resource::reveals_value(&resource.service, &action.sdk_method) {
+                                            if let Some(pending) = app.create_pending_action(action, &id) {
+                                                app.enter_confirm_mode(pending);
+                                            } else {
+                                                app.reveal_value(&resource.service, &action.sdk_method, &id, &action.display_name, &name).await;
+                                            }
+                                            handled = true;
                                         // Block action in readonly mode
                                         } else if app.readonly {
                                             app.show_warning("This operation is not supported in read-only mode");
                                             handled = true;
+                                        } else if action.input.is_some() {
+                                            // Ask for a value first (e.g., tags)
+                                            app.enter_input_mode(action, &id);
+                                            handled = true;
                                         } else if action.requires_confirm() {
                                             // Check if action requires confirmation
                                             if let Some(pending) = app.create_pending_action(action, &id) {
@@ -222,16 +348,8 @@ async fn handle_normal_mode(app: &mut App, key: KeyEvent) -> Result<bool> {
                                                 handled = true;
                                             }
                                         } else {
-                                            // Execute directly
-                                            if let Err(e) = crate::resource::execute_action(
-                                                &resource.service,
-                                                &action.sdk_method,
-                                                &app.clients,
-                                                &id
-                                            ).await {
-                                                app.error_message = Some(format!("Action failed: {}", e));
-                                            }
-                                            let _ = app.refresh_current().await;
+                                            // Execute directly (in the background)
+                                            app.start_action(&resource.service, &action.sdk_method, &id, &action.display_name, &name);
                                             handled = true;
                                         }
                                     }
@@ -242,6 +360,24 @@ async fn handle_normal_mode(app: &mut App, key: KeyEvent) -> Result<bool> {
                     }
                 }
 
+                // External commands (run by the main loop with the TUI suspended)
+                if !handled && app.selected_item().is_some() {
+                    let command = app
+                        .current_resource()
+                        .and_then(|r| r.commands.iter().find(|cmd| cmd.shortcut == c.to_string()))
+                        .cloned();
+                    if let Some(command) = command {
+                        app.queue_shell_command(&command);
+                        handled = true;
+                    }
+                }
+
+                // Open in the AWS Console; S3 keeps 'o' for objects
+                if !handled && c == 'o' {
+                    app.open_in_console();
+                    handled = true;
+                }
+
                 // Handle 'gg' for go_to_top
                 if c == 'g' {
                     if let Some((last_key, last_time)) = app.last_key_press {
@@ -269,8 +405,11 @@ async fn handle_filter_input(app: &mut App, key: KeyEvent) -> Result<bool> {
             app.clear_filter();
         }
         KeyCode::Enter => {
+            app.record_filter();
             app.filter_active = false;
         }
+        KeyCode::Up => app.recall_filter(true),
+        KeyCode::Down => app.recall_filter(false),
         KeyCode::Backspace => {
             app.filter_text.pop();
             app.apply_filter();
@@ -292,7 +431,7 @@ async fn handle_command_mode(app: &mut App, key: KeyEvent) -> Result<bool> {
         }
         KeyCode::Enter => {
             let should_quit = app.execute_command().await?;
-            if should_quit {
+            if should_quit && app.request_quit() {
                 return Ok(true);
             }
             if app.mode == Mode::Command {
@@ -302,6 +441,13 @@ async fn handle_command_mode(app: &mut App, key: KeyEvent) -> Result<bool> {
         KeyCode::Tab | KeyCode::Right => {
             app.apply_suggestion();
         }
+        // History recall (Up/Down move through suggestions)
+        KeyCode::Char('p') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+            app.recall_command(true);
+        }
+        KeyCode::Char('n') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+            app.recall_command(false);
+        }
         KeyCode::Down => {
             app.next_suggestion();
         }
@@ -310,11 +456,39 @@ async fn handle_command_mode(app: &mut App, key: KeyEvent) -> Result<bool> {
         }
         KeyCode::Backspace => {
             app.command_text.pop();
-            app.update_command_suggestions();
+            app.command_edited();
         }
         KeyCode::Char(c) => {
             app.command_text.push(c);
-            app.update_command_suggestions();
+            app.command_edited();
+        }
+        _ => {}
+    }
+    Ok(false)
+}
+
+async fn handle_quick_switch_mode(app: &mut App, key: KeyEvent) -> Result<bool> {
+    match key.code {
+        KeyCode::Esc => {
+            app.command_text.clear();
+            app.exit_mode();
+        }
+        KeyCode::Enter => {
+            app.execute_quick_switch().await?;
+        }
+        KeyCode::Down | KeyCode::Tab => {
+            app.next_suggestion();
+        }
+        KeyCode::Up | KeyCode::BackTab => {
+            app.prev_suggestion();
+        }
+        KeyCode::Backspace => {
+            app.command_text.pop();
+            app.update_quick_switch_suggestions();
+        }
+        KeyCode::Char(c) => {
+            app.command_text.push(c);
+            app.update_quick_switch_suggestions();
         }
         _ => {}
     }
@@ -331,11 +505,73 @@ fn handle_help_mode(app: &mut App, key: KeyEvent) -> Result<bool> {
     Ok(false)
 }
 
+fn handle_columns_mode(app: &mut App, key: KeyEvent) -> Result<bool> {
+    let column_count = app.current_resource().map_or(0, |r| r.columns.len());
+    match key.code {
+        KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('C') => app.exit_mode(),
+        KeyCode::Char('j') | KeyCode::Down => {
+            app.columns_selected = (app.columns_selected + 1).min(column_count.saturating_sub(1));
+        }
+        KeyCode::Char('k') | KeyCode::Up => {
+            app.columns_selected = app.columns_selected.saturating_sub(1);
+        }
+        KeyCode::Char(' ') | KeyCode::Enter => app.toggle_selected_column(),
+        KeyCode::Char('g') => app.toggle_group_by_selected_column(),
+        _ => {}
+    }
+    Ok(false)
+}
+
+async fn handle_favorites_mode(app: &mut App, key: KeyEvent) -> Result<bool> {
+    let count = app.config.favorites.len();
+    match key.code {
+        KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('~') => app.exit_mode(),
+        KeyCode::Char('j') | KeyCode::Down => {
+            app.favorites_selected = (app.favorites_selected + 1).min(count.saturating_sub(1));
+        }
+        KeyCode::Char('k') | KeyCode::Up => {
+            app.favorites_selected = app.favorites_selected.saturating_sub(1);
+        }
+        KeyCode::Enter => app.open_favorite(app.favorites_selected).await?,
+        // 1-9 jump straight to a favorite
+        KeyCode::Char(c @ '1'..='9') => {
+            let index = c.to_digit(10).unwrap_or(1) as usize - 1;
+            app.open_favorite(index).await?;
+        }
+        _ => {}
+    }
+    Ok(false)
+}
+
 fn handle_describe_mode(app: &mut App, key: KeyEvent) -> Result<bool> {
+    // If search input is active, handle search input
+    if app.describe_search_active {
+        return handle_describe_search_input(app, key);
+    }
+
     match key.code {
+        KeyCode::Esc if !app.describe_search_text.is_empty() => {
+            app.clear_describe_search();
+        }
         KeyCode::Esc | KeyCode::Char('q') => {
             app.exit_mode();
         }
+        KeyCode::Char('/') => {
+            app.describe_search_text.clear();
+            app.describe_search_active = true;
+        }
+        KeyCode::Char('n') => {
+            app.describe_next_match();
+        }
+        KeyCode::Char('N') => {
+            app.describe_prev_match();
+        }
+        KeyCode::Char('r') => {
+            app.toggle_describe_projection();
+        }
+        KeyCode::Char('f') => {
+            app.toggle_describe_format();
+        }
         KeyCode::Char('d') if key.modifiers.contains(KeyModifiers::CONTROL) => {
             app.describe_scroll = app.describe_scroll.saturating_add(10);
         }
@@ -363,6 +599,40 @@ fn handle_describe_mode(app: &mut App, key: KeyEvent) -> Result<bool> {
     Ok(false)
 }
 
+fn handle_describe_search_input(app: &mut App, key: KeyEvent) -> Result<bool> {
+    match key.code {
+        KeyCode::Esc => {
+            app.clear_describe_search();
+        }
+        KeyCode::Enter => {
+            app.confirm_describe_search();
+        }
+        KeyCode::Backspace => {
+            app.describe_search_text.pop();
+        }
+        KeyCode::Char(c) => {
+            app.describe_search_text.push(c);
+        }
+        _ => {}
+    }
+    Ok(false)
+}
+
+fn handle_error_detail_mode(app: &mut App, key: KeyEvent) -> Result<bool> {
+    match key.code {
+        KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('e') => app.exit_mode(),
+        KeyCode::Char('j') | KeyCode::Down => {
+            app.error_scroll = app.error_scroll.saturating_add(1);
+        }
+        KeyCode::Char('k') | KeyCode::Up => {
+            app.error_scroll = app.error_scroll.saturating_sub(1);
+        }
+        KeyCode::Char('g') | KeyCode::Home => app.error_scroll = 0,
+        _ => {}
+    }
+    Ok(false)
+}
+
 fn handle_warning_mode(app: &mut App, key: KeyEvent) -> Result<bool> {
     match key.code {
         KeyCode::Enter | KeyCode::Esc | KeyCode::Char('o') | KeyCode::Char('O') => {
@@ -375,7 +645,14 @@ fn handle_warning_mode(app: &mut App, key: KeyEvent) -> Result<bool> {
 }
 
 async fn handle_confirm_mode(app: &mut App, key: KeyEvent) -> Result<bool> {
+    if app.pending_action.as_ref().is_some_and(|p| p.require_typed) {
+        return handle_typed_confirm(app, key).await;
+    }
+
     match key.code {
+        KeyCode::Char('r') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+            app.dry_run_pending_action().await;
+        }
         // Toggle selection with arrow keys or tab
         KeyCode::Left | KeyCode::Right | KeyCode::Tab | KeyCode::Char('h') | KeyCode::Char('l') => {
             if let Some(ref mut pending) = app.pending_action {
@@ -384,41 +661,15 @@ async fn handle_confirm_mode(app: &mut App, key: KeyEvent) -> Result<bool> {
         }
         // Confirm with Enter
         KeyCode::Enter => {
-            if let Some(ref pending) = app.pending_action {
-                if pending.selected_yes {
-                    // Execute the action (if not in readonly mode)
-                    if app.readonly {
-                        app.error_message = Some("This operation is not supported in read-only mode".to_string());
-                    } else {
-                        let service = pending.service.clone();
-                        let method = pending.sdk_method.clone();
-                        let resource_id = pending.resource_id.clone();
-                        
-                        if let Err(e) = crate::resource::execute_action(&service, &method, &app.clients, &resource_id).await {
-                            app.error_message = Some(format!("Action failed: {}", e));
-                        }
-                        // Refresh after action
-                        let _ = app.refresh_current().await;
-                    }
-                }
+            if app.pending_action.as_ref().is_some_and(|p| p.selected_yes) {
+                run_pending_action(app).await;
+            } else {
+                app.exit_mode();
             }
-            app.exit_mode();
         }
         // Quick yes/no
         KeyCode::Char('y') | KeyCode::Char('Y') => {
-            if app.readonly {
-                app.error_message = Some("This operation is not supported in read-only mode".to_string());
-            } else if let Some(ref pending) = app.pending_action {
-                let service = pending.service.clone();
-                let method = pending.sdk_method.clone();
-                let resource_id = pending.resource_id.clone();
-                
-                if let Err(e) = crate::resource::execute_action(&service, &method, &app.clients, &resource_id).await {
-                    app.error_message = Some(format!("Action failed: {}", e));
-                }
-                let _ = app.refresh_current().await;
-            }
-            app.exit_mode();
+            run_pending_action(app).await;
         }
         KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc => {
             app.exit_mode();
@@ -428,8 +679,96 @@ async fn handle_confirm_mode(app: &mut App, key: KeyEvent) -> Result<bool> {
     Ok(false)
 }
 
+/// Confirm dialog input for actions that need the resource name (or "yes") typed out
+async fn handle_typed_confirm(app: &mut App, key: KeyEvent) -> Result<bool> {
+    match key.code {
+        KeyCode::Char('r') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+            app.dry_run_pending_action().await;
+        }
+        KeyCode::Esc => {
+            app.exit_mode();
+        }
+        KeyCode::Enter => {
+            let confirmed = app
+                .pending_action
+                .as_ref()
+                .is_some_and(|p| p.matches_typed(&app.confirm_text));
+            if confirmed {
+                run_pending_action(app).await;
+            }
+            // Otherwise stay open; the dialog shows the mismatch
+        }
+        KeyCode::Backspace => {
+            app.confirm_text.pop();
+        }
+        KeyCode::Char(c) => {
+            app.confirm_text.push(c);
+        }
+        _ => {}
+    }
+    Ok(false)
+}
+
+/// Why `action` can't run on `item` right now (its `enabled_when` isn't met), if so
+fn action_inapplicable_reason(action: &crate::resource::ActionDef, item: &serde_json::Value, name: &str) -> Option<String> {
+    if action.is_enabled_for(item) {
+        return None;
+    }
+    let condition = action.enabled_when.as_ref()?;
+    Some(format!(
+        "{} isn't available for {} while it is {}",
+        action.display_name,
+        name,
+        crate::resource::extract_json_value(item, &condition.field)
+    ))
+}
+
+/// Close the dialog and start the confirmed action (if not in readonly mode); the list
+/// refreshes when it finishes. Reveal actions open their popup instead.
+async fn run_pending_action(app: &mut App) {
+    let pending = app.pending_action.take();
+    app.exit_mode();
+    let Some(pending) = pending else {
+        return;
+    };
+
+    // Reveals only read, so they are allowed in read-only mode
+    if crate::resource::reveals_value(&pending.service, &pending.sdk_method) {
+        app.reveal_value(&pending.service, &pending.sdk_method, &pending.resource_id, &pending.display_name, &pending.resource_name).await;
+        return;
+    }
+    if app.readonly {
+        app.error_message = Some("This operation is not supported in read-only mode".to_string());
+        return;
+    }
+
+    app.start_action(&pending.service, &pending.sdk_method, &pending.resource_id, &pending.display_name, &pending.resource_name);
+}
+
+/// Quit anyway (y/Ctrl+c) or keep running (n/Esc) while actions are in flight
+fn handle_confirm_quit_mode(app: &mut App, key: KeyEvent) -> Result<bool> {
+    match key.code {
+        // Enter is not accepted, so a stray keypress can't drop running actions
+        KeyCode::Char('y') | KeyCode::Char('Y') => return Ok(true),
+        KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => return Ok(true),
+        KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc => app.exit_mode(),
+        _ => {}
+    }
+    Ok(false)
+}
+
 async fn handle_profiles_mode(app: &mut App, key: KeyEvent) -> Result<bool> {
+    if app.picker_filter_active {
+        return handle_picker_filter_input(app, key).await;
+    }
+
     match key.code {
+        KeyCode::Esc if !app.picker_filter.is_empty() => {
+            app.set_picker_filter(String::new());
+        }
+        KeyCode::Char('/') => {
+            app.picker_filter_active = true;
+        }
         KeyCode::Esc | KeyCode::Char('q') => {
             app.exit_mode();
         }
@@ -454,7 +793,17 @@ async fn handle_profiles_mode(app: &mut App, key: KeyEvent) -> Result<bool> {
 }
 
 async fn handle_regions_mode(app: &mut App, key: KeyEvent) -> Result<bool> {
+    if app.picker_filter_active {
+        return handle_picker_filter_input(app, key).await;
+    }
+
     match key.code {
+        KeyCode::Esc if !app.picker_filter.is_empty() => {
+            app.set_picker_filter(String::new());
+        }
+        KeyCode::Char('/') => {
+            app.picker_filter_active = true;
+        }
         KeyCode::Esc | KeyCode::Char('q') => {
             app.exit_mode();
         }
@@ -478,6 +827,62 @@ async fn handle_regions_mode(app: &mut App, key: KeyEvent) -> Result<bool> {
     Ok(false)
 }
 
+/// Typing into a picker's filter: the list narrows with each key, Enter picks the highlighted row
+async fn handle_picker_filter_input(app: &mut App, key: KeyEvent) -> Result<bool> {
+    match key.code {
+        KeyCode::Esc => {
+            app.picker_filter_active = false;
+            app.set_picker_filter(String::new());
+        }
+        KeyCode::Enter => {
+            app.picker_filter_active = false;
+            if app.mode == Mode::Profiles {
+                app.select_profile().await?;
+            } else {
+                app.select_region().await?;
+            }
+        }
+        KeyCode::Down => app.next(),
+        KeyCode::Up => app.previous(),
+        KeyCode::Backspace => {
+            let mut filter = app.picker_filter.clone();
+            filter.pop();
+            app.set_picker_filter(filter);
+        }
+        KeyCode::Char(c) => {
+            let filter = format!("{}{}", app.picker_filter, c);
+            app.set_picker_filter(filter);
+        }
+        _ => {}
+    }
+    Ok(false)
+}
+
+async fn handle_search_mode(app: &mut App, key: KeyEvent) -> Result<bool> {
+    match key.code {
+        KeyCode::Esc | KeyCode::Char('q') => {
+            app.close_search();
+        }
+        KeyCode::Char('j') | KeyCode::Down => {
+            app.next();
+        }
+        KeyCode::Char('k') | KeyCode::Up => {
+            app.previous();
+        }
+        KeyCode::Char('g') | KeyCode::Home => {
+            app.go_to_top();
+        }
+        KeyCode::Char('G') | KeyCode::End => {
+            app.go_to_bottom();
+        }
+        KeyCode::Enter => {
+            app.open_search_result().await?;
+        }
+        _ => {}
+    }
+    Ok(false)
+}
+
 async fn handle_sso_login_mode(app: &mut App, key: KeyEvent) -> Result<bool> {
     let sso_state = match &app.sso_state {
         Some(state) => state.clone(),
@@ -587,7 +992,7 @@ async fn handle_sso_login_mode(app: &mut App, key: KeyEvent) -> Result<bool> {
                     app.exit_mode();
                     // Actually switch the profile now that SSO is complete
                     if let Err(e) = app.switch_profile(&profile_to_switch).await {
-                        app.error_message = Some(format!("Failed to switch profile: {}", e));
+                        app.record_error(format!("Failed to switch profile: {}", e), &e);
                     } else {
                         let _ = app.refresh_current().await;
                     }
@@ -684,8 +1089,8 @@ async fn handle_log_tail_mode(app: &mut App, key: KeyEvent) -> Result<bool> {
         KeyCode::Char('G') | KeyCode::End => {
             app.log_tail_scroll_to_bottom();
         }
-        // Toggle pause
-        KeyCode::Char(' ') => {
+        // Stop/start tailing
+        KeyCode::Char(' ') | KeyCode::Char('t') => {
             app.toggle_log_tail_pause();
         }
         _ => {}
@@ -693,6 +1098,43 @@ async fn handle_log_tail_mode(app: &mut App, key: KeyEvent) -> Result<bool> {
     Ok(false)
 }
 
+async fn handle_input_mode(app: &mut App, key: KeyEvent) -> Result<bool> {
+    match key.code {
+        KeyCode::Esc => {
+            app.exit_mode();
+        }
+        KeyCode::Enter => {
+            app.submit_action_input().await?;
+        }
+        KeyCode::Backspace => {
+            if let Some(ref mut input) = app.action_input {
+                input.text.pop();
+            }
+        }
+        KeyCode::Char(c) => {
+            if let Some(ref mut input) = app.action_input {
+                input.text.push(c);
+            }
+        }
+        _ => {}
+    }
+    Ok(false)
+}
+
+async fn handle_metrics_mode(app: &mut App, key: KeyEvent) -> Result<bool> {
+    match key.code {
+        KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('m') => {
+            app.exit_metrics_mode();
+        }
+        // Refetch datapoints
+        KeyCode::Char('R') => {
+            app.enter_metrics_mode().await?;
+        }
+        _ => {}
+    }
+    Ok(false)
+}
+
 /// Poll for new log events if in log tail mode
 pub async fn poll_logs_if_tailing(app: &mut App) {
     if app.mode != Mode::LogTail {
@@ -1,5 +1,6 @@
 mod app;
 mod aws;
+mod clipboard;
 mod config;
 mod event;
 mod resource;
@@ -13,8 +14,9 @@ pub const VERSION: &str = match option_env!("TAWS_VERSION") {
 };
 
 use anyhow::Result;
-use app::{App, Mode, SsoLoginState};
+use app::{App, CallerIdentity, Mode, PendingShell, SsoLoginState};
 use aws::client::ClientResult;
+use aws::profiles::Profile;
 use clap::{Parser, ValueEnum};
 use config::Config;
 use crossterm::{
@@ -50,9 +52,26 @@ struct Args {
     #[arg(long)]
     readonly: bool,
 
-    /// Custom AWS endpoint URL (for LocalStack, etc.). Also reads from AWS_ENDPOINT_URL env var.
+    /// Custom AWS endpoint URL for all services (LocalStack, etc.). Falls back to the
+    /// AWS_ENDPOINT_URL env var, then endpoint_url in the config file.
     #[arg(long)]
     endpoint_url: Option<String>,
+
+    /// Leave the mouse to the terminal so text can be selected (toggle at runtime with M)
+    #[arg(long)]
+    no_mouse: bool,
+
+    /// Disable colors (also enabled by a non-empty NO_COLOR env var)
+    #[arg(long)]
+    no_color: bool,
+
+    /// Check that every resource definition is wired to a dispatch handler, then exit
+    #[arg(long)]
+    check_resources: bool,
+
+    /// Browse lists saved with :dump instead of calling AWS (no credentials needed)
+    #[arg(long, value_name = "FILE")]
+    replay: Option<PathBuf>,
 }
 
 #[derive(Debug, Clone, Copy, ValueEnum)]
@@ -79,9 +98,7 @@ impl LogLevel {
 }
 
 fn setup_logging(level: LogLevel) -> Option<tracing_appender::non_blocking::WorkerGuard> {
-    let Some(tracing_level) = level.to_tracing_level() else {
-        return None;
-    };
+    let tracing_level = level.to_tracing_level()?;
 
     // Get log file path
     let log_path = get_log_path();
@@ -126,6 +143,21 @@ fn get_log_path() -> PathBuf {
     PathBuf::from("taws.log")
 }
 
+/// Print resource definitions that don't resolve to a dispatch handler
+async fn check_resources() -> Result<()> {
+    let gaps = resource::find_wiring_gaps().await;
+    if gaps.is_empty() {
+        println!("All {} resources are wired", resource::get_all_resource_keys().len());
+        return Ok(());
+    }
+
+    for gap in &gaps {
+        println!("{}", gap);
+    }
+    eprintln!("{} wiring gap(s) found", gaps.len());
+    std::process::exit(1);
+}
+
 #[tokio::main]
 async fn main() -> Result<()> {
     // Parse CLI arguments
@@ -134,10 +166,18 @@ async fn main() -> Result<()> {
     // Setup logging (keep guard alive for the duration of the program)
     let _log_guard = setup_logging(args.log_level);
 
+    if args.check_resources {
+        return check_resources().await;
+    }
+
     // Setup terminal
+    install_panic_hook();
     enable_raw_mode()?;
     let mut stdout = io::stdout();
-    execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
+    execute!(stdout, EnterAlternateScreen)?;
+    if !args.no_mouse {
+        execute!(stdout, EnableMouseCapture)?;
+    }
     let backend = CrosstermBackend::new(stdout);
     let mut terminal = Terminal::new(backend)?;
 
@@ -170,11 +210,32 @@ async fn main() -> Result<()> {
     Ok(())
 }
 
+/// Restore the terminal before the default hook prints the panic, so a crash
+/// doesn't leave the shell in raw mode on the alternate screen
+///
+/// The hook runs for panics on any thread, including background tokio tasks,
+/// and the UI can't continue on a restored terminal, so the process exits.
+fn install_panic_hook() {
+    let default_hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        let _ = disable_raw_mode();
+        let _ = execute!(
+            io::stdout(),
+            LeaveAlternateScreen,
+            DisableMouseCapture,
+            crossterm::cursor::Show
+        );
+        default_hook(info);
+        std::process::exit(101);
+    }));
+}
+
 fn cleanup_terminal<B: Backend + std::io::Write>(terminal: &mut Terminal<B>) -> Result<()>
 where
     B::Error: Send + Sync + 'static,
 {
     disable_raw_mode()?;
+    // Capture may have been toggled on at runtime, so always release it
     execute!(
         terminal.backend_mut(),
         LeaveAlternateScreen,
@@ -184,7 +245,57 @@ where
     Ok(())
 }
 
+/// Run an external command from a resource definition with the TUI suspended,
+/// then wait for Enter so its output can be read before the TUI comes back
+async fn run_shell_command<B: Backend>(terminal: &mut Terminal<B>, app: &mut App, shell: &PendingShell) -> Result<()>
+where
+    B::Error: Send + Sync + 'static,
+{
+    disable_raw_mode()?;
+    execute!(io::stdout(), LeaveAlternateScreen, DisableMouseCapture, crossterm::cursor::Show)?;
+    let command_line = shell.args.join(" ");
+    println!("$ {}", command_line);
+
+    // Run directly rather than through a shell, so values reach the program untouched
+    let status = match tokio::process::Command::new(&shell.args[0])
+        .args(&shell.args[1..])
+        .env("AWS_PROFILE", &app.profile)
+        .env("AWS_REGION", &shell.region)
+        .spawn()
+    {
+        Ok(mut child) => loop {
+            // Ctrl+C stops the command, not taws
+            tokio::select! {
+                status = child.wait() => break status,
+                _ = tokio::signal::ctrl_c() => {}
+            }
+        },
+        Err(e) => Err(e),
+    };
+
+    println!("\nPress Enter to return to taws");
+    let _ = io::stdin().read_line(&mut String::new());
+
+    enable_raw_mode()?;
+    execute!(io::stdout(), EnterAlternateScreen)?;
+    if app.mouse_enabled {
+        execute!(io::stdout(), EnableMouseCapture)?;
+    }
+    terminal.clear()?;
+
+    match status {
+        Ok(status) if status.success() => app.set_status(format!("Ran: {}", command_line)),
+        Ok(status) => app.error_message = Some(format!("Command exited with {}: {}", status, command_line)),
+        Err(e) => {
+            let e = anyhow::Error::from(e);
+            app.record_error(format!("Couldn't run {}: {}", command_line, e), &e);
+        }
+    }
+    Ok(())
+}
+
 /// Result of initialization - either an App or SSO login is required
+#[allow(clippy::large_enum_variant)]
 enum InitResult {
     App(App),
     SsoRequired {
@@ -193,7 +304,7 @@ enum InitResult {
         region: String,
         endpoint_url: Option<String>,
         config: Config,
-        available_profiles: Vec<String>,
+        available_profiles: Vec<Profile>,
         available_regions: Vec<String>,
         readonly: bool,
     },
@@ -203,9 +314,9 @@ async fn initialize_with_splash<B: Backend>(terminal: &mut Terminal<B>, args: &A
 where
     B::Error: Send + Sync + 'static,
 {
-    match initialize_inner(terminal, args).await? {
-        None => Ok(None), // User aborted
-        Some(InitResult::App(app)) => Ok(Some(app)),
+    let app = match initialize_inner(terminal, args).await? {
+        None => None, // User aborted
+        Some(InitResult::App(app)) => Some(app),
         Some(InitResult::SsoRequired { 
             profile, 
             sso_session, 
@@ -227,8 +338,16 @@ where
                 available_profiles, 
                 available_regions,
                 readonly,
-            ).await
+            ).await?
+        }
+    };
+
+    match app {
+        Some(mut app) => {
+            app.mouse_enabled = !args.no_mouse;
+            Ok(Some(app))
         }
+        None => Ok(None),
     }
 }
 
@@ -236,7 +355,15 @@ async fn initialize_inner<B: Backend>(terminal: &mut Terminal<B>, args: &Args) -
 where
     B::Error: Send + Sync + 'static,
 {
-    let mut splash = SplashState::new();
+    // Step 1: Load configuration (CLI args > env vars > saved config)
+    let mut config = Config::load();
+    config.no_color = args.no_color || no_color_env();
+
+    if let Some(path) = &args.replay {
+        return Ok(Some(InitResult::App(initialize_replay(path, config).await?)));
+    }
+
+    let mut splash = SplashState::new(config.theme());
 
     // Render initial splash
     terminal.draw(|f| render_splash(f, &splash))?;
@@ -246,16 +373,14 @@ where
         return Ok(None);
     }
 
-    // Step 1: Load configuration (CLI args > env vars > saved config)
-    let config = Config::load();
     let profile = args.profile.clone()
         .unwrap_or_else(|| config.effective_profile());
     let region = args.region.clone()
         .unwrap_or_else(|| config.effective_region());
     
-    // Get endpoint URL from CLI arg or environment variable
+    // Get endpoint URL from CLI arg, environment variable or config file
     let endpoint_url = args.endpoint_url.clone()
-        .or_else(|| std::env::var("AWS_ENDPOINT_URL").ok());
+        .or_else(|| config.effective_endpoint_url());
     
     tracing::info!("Using profile: {}, region: {}, endpoint_url: {:?}", profile, region, endpoint_url);
     
@@ -271,7 +396,7 @@ where
     splash.set_message("Reading ~/.aws/config");
     terminal.draw(|f| render_splash(f, &splash))?;
 
-    let available_profiles = aws::profiles::list_profiles().unwrap_or_else(|_| vec!["default".to_string()]);
+    let available_profiles = aws::profiles::list_profiles();
     let available_regions = aws::profiles::list_regions();
     splash.complete_step();
 
@@ -313,15 +438,14 @@ where
     splash.set_message(&format!("Fetching instances from {}", actual_region));
     terminal.draw(|f| render_splash(f, &splash))?;
 
-    let (instances, initial_error) = {
-        // Use the new JSON-driven resource system
-        match resource::fetch_resources("ec2-instances", &clients, &[]).await {
-            Ok(items) => (items, None),
-            Err(e) => {
-                let error_msg = aws::client::format_aws_error(&e);
-                (Vec::new(), Some(error_msg))
-            }
-        }
+    // Look up the account alongside the first fetch so the header can show where actions will land
+    let (fetched, identity) = tokio::join!(
+        resource::fetch_resources("ec2-instances", &clients, &[]),
+        CallerIdentity::fetch(&clients),
+    );
+    let (instances, initial_error) = match fetched {
+        Ok(items) => (items, None),
+        Err(e) => (Vec::new(), Some(e)),
     };
 
     splash.complete_step();
@@ -343,16 +467,44 @@ where
         args.readonly,
         endpoint_url,
     );
+    app.identity = identity;
 
     // Set initial error if any
     if let Some(err) = initial_error {
-        app.error_message = Some(err);
+        app.record_error(aws::client::format_aws_error(&err, &app.profile), &err);
     }
 
     Ok(Some(InitResult::App(app)))
 }
 
+/// Build an offline app that serves lists from a dump file
+async fn initialize_replay(path: &std::path::Path, config: Config) -> Result<App> {
+    let dump = resource::Dump::load(path)?;
+    tracing::info!("Replaying {} list(s) from {:?}", dump.lists.len(), path);
+
+    let clients = aws::client::AwsClients::offline(&dump.profile, &dump.region);
+    let instances = resource::fetch_resources("ec2-instances", &dump, &[]).await?;
+    let profile = clients.profile.clone();
+    let region = clients.region.clone();
+
+    // Nothing can be changed in a replay, so block actions up front
+    let mut app = App::from_initialized(
+        clients,
+        profile,
+        region,
+        Vec::new(),
+        Vec::new(),
+        instances,
+        config,
+        true,
+        None,
+    );
+    app.replay = Some(std::sync::Arc::new(dump));
+    Ok(app)
+}
+
 /// Handle SSO login flow interactively
+#[allow(clippy::too_many_arguments)]
 async fn handle_sso_login_flow<B: Backend>(
     terminal: &mut Terminal<B>,
     profile: String,
@@ -360,7 +512,7 @@ async fn handle_sso_login_flow<B: Backend>(
     region: String,
     endpoint_url: Option<String>,
     config: Config,
-    available_profiles: Vec<String>,
+    available_profiles: Vec<Profile>,
     available_regions: Vec<String>,
     readonly: bool,
 ) -> Result<Option<App>>
@@ -371,6 +523,8 @@ where
     
     tracing::info!("Entering SSO login flow for profile '{}', session '{}'", profile, sso_session);
     
+    let theme = config.theme();
+
     // Create a minimal app state for the SSO dialog
     let mut sso_state = SsoLoginState::Prompt {
         profile: profile.clone(),
@@ -380,7 +534,7 @@ where
     loop {
         // Render SSO dialog
         terminal.draw(|f| {
-            render_sso_standalone(f, &sso_state);
+            render_sso_standalone(f, &sso_state, &theme);
         })?;
         
         // Handle input
@@ -501,15 +655,14 @@ where
                                 // AwsClients::new handles blocking internally via spawn_blocking
                                 let (clients, actual_region) = aws::client::AwsClients::new(&profile, &region, endpoint_url.clone()).await?;
                                 
-                                // Fetch initial resources
-                                let (instances, initial_error) = {
-                                    match resource::fetch_resources("ec2-instances", &clients, &[]).await {
-                                        Ok(items) => (items, None),
-                                        Err(e) => {
-                                            let error_msg = aws::client::format_aws_error(&e);
-                                            (Vec::new(), Some(error_msg))
-                                        }
-                                    }
+                                // Fetch initial resources and the account in parallel
+                                let (fetched, identity) = tokio::join!(
+                                    resource::fetch_resources("ec2-instances", &clients, &[]),
+                                    CallerIdentity::fetch(&clients),
+                                );
+                                let (instances, initial_error) = match fetched {
+                                    Ok(items) => (items, None),
+                                    Err(e) => (Vec::new(), Some(e)),
                                 };
                                 
                                 let mut app = App::from_initialized(
@@ -523,9 +676,10 @@ where
                                     readonly,
                                     endpoint_url,
                                 );
+                                app.identity = identity;
                                 
                                 if let Some(err) = initial_error {
-                                    app.error_message = Some(err);
+                                    app.record_error(aws::client::format_aws_error(&err, &app.profile), &err);
                                 }
                                 
                                 return Ok(Some(app));
@@ -581,11 +735,16 @@ where
     }
 }
 
+/// Whether NO_COLOR is set to a non-empty value (https://no-color.org)
+fn no_color_env() -> bool {
+    std::env::var_os("NO_COLOR").is_some_and(|v| !v.is_empty())
+}
+
 /// Render SSO dialog standalone (during initialization, before app is created)
-fn render_sso_standalone(f: &mut ratatui::Frame, sso_state: &SsoLoginState) {
+fn render_sso_standalone(f: &mut ratatui::Frame, sso_state: &SsoLoginState, theme: &ui::theme::Theme) {
     use ratatui::{
         layout::{Alignment, Constraint, Direction, Layout, Rect},
-        style::{Color, Modifier, Style},
+        style::{Color, Modifier},
         text::{Line, Span},
         widgets::{Block, Borders, Clear, Paragraph},
     };
@@ -613,7 +772,7 @@ fn render_sso_standalone(f: &mut ratatui::Frame, sso_state: &SsoLoginState) {
     // Clear the screen with a dark background
     let area = f.area();
     f.render_widget(Clear, area);
-    let bg_block = Block::default().style(Style::default().bg(Color::Black));
+    let bg_block = Block::default().style(theme.background(Color::Black));
     f.render_widget(bg_block, area);
     
     match sso_state {
@@ -624,27 +783,27 @@ fn render_sso_standalone(f: &mut ratatui::Frame, sso_state: &SsoLoginState) {
             let text = vec![
                 Line::from(Span::styled(
                     "<SSO Login Required>",
-                    Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD),
+                    theme.title().add_modifier(Modifier::BOLD),
                 )),
                 Line::from(""),
                 Line::from(Span::styled(
                     format!("Profile '{}' requires SSO authentication.", profile),
-                    Style::default().fg(Color::White),
+                    theme.text(),
                 )),
                 Line::from(Span::styled(
                     format!("Session: {}", sso_session),
-                    Style::default().fg(Color::DarkGray),
+                    theme.muted(),
                 )),
                 Line::from(""),
                 Line::from(Span::styled(
                     "Press Enter to open browser for login, Esc to cancel",
-                    Style::default().fg(Color::Yellow),
+                    theme.warning(),
                 )),
             ];
 
             let block = Block::default()
                 .borders(Borders::ALL)
-                .border_style(Style::default().fg(Color::Cyan));
+                .border_style(theme.title());
 
             let paragraph = Paragraph::new(text).block(block).alignment(Alignment::Center);
             f.render_widget(paragraph, dialog_area);
@@ -657,32 +816,32 @@ fn render_sso_standalone(f: &mut ratatui::Frame, sso_state: &SsoLoginState) {
             let text = vec![
                 Line::from(Span::styled(
                     "<Waiting for SSO Authentication>",
-                    Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD),
+                    theme.warning().add_modifier(Modifier::BOLD),
                 )),
                 Line::from(""),
                 Line::from(Span::styled(
                     "Complete authentication in your browser.",
-                    Style::default().fg(Color::White),
+                    theme.text(),
                 )),
                 Line::from(""),
                 Line::from(vec![
-                    Span::styled("Code: ", Style::default().fg(Color::DarkGray)),
-                    Span::styled(user_code, Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
+                    Span::styled("Code: ", theme.muted()),
+                    Span::styled(user_code, theme.title().add_modifier(Modifier::BOLD)),
                 ]),
                 Line::from(vec![
-                    Span::styled("URL: ", Style::default().fg(Color::DarkGray)),
-                    Span::styled(verification_uri, Style::default().fg(Color::Blue)),
+                    Span::styled("URL: ", theme.muted()),
+                    Span::styled(verification_uri, theme.fg(Color::Blue)),
                 ]),
                 Line::from(""),
                 Line::from(Span::styled(
                     "Waiting... (Press Esc to cancel)",
-                    Style::default().fg(Color::DarkGray),
+                    theme.muted(),
                 )),
             ];
 
             let block = Block::default()
                 .borders(Borders::ALL)
-                .border_style(Style::default().fg(Color::Yellow));
+                .border_style(theme.warning());
 
             let paragraph = Paragraph::new(text).block(block).alignment(Alignment::Center);
             f.render_widget(paragraph, dialog_area);
@@ -695,18 +854,18 @@ fn render_sso_standalone(f: &mut ratatui::Frame, sso_state: &SsoLoginState) {
             let text = vec![
                 Line::from(Span::styled(
                     "<SSO Login Successful>",
-                    Style::default().fg(Color::Green).add_modifier(Modifier::BOLD),
+                    theme.success().add_modifier(Modifier::BOLD),
                 )),
                 Line::from(""),
                 Line::from(Span::styled(
                     format!("Authenticated '{}'. Press Enter to continue.", profile),
-                    Style::default().fg(Color::White),
+                    theme.text(),
                 )),
             ];
 
             let block = Block::default()
                 .borders(Borders::ALL)
-                .border_style(Style::default().fg(Color::Green));
+                .border_style(theme.success());
 
             let paragraph = Paragraph::new(text).block(block).alignment(Alignment::Center);
             f.render_widget(paragraph, dialog_area);
@@ -719,20 +878,20 @@ fn render_sso_standalone(f: &mut ratatui::Frame, sso_state: &SsoLoginState) {
             let text = vec![
                 Line::from(Span::styled(
                     "<SSO Login Failed>",
-                    Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+                    theme.error().add_modifier(Modifier::BOLD),
                 )),
                 Line::from(""),
-                Line::from(Span::styled(error.as_str(), Style::default().fg(Color::White))),
+                Line::from(Span::styled(error.as_str(), theme.text())),
                 Line::from(""),
                 Line::from(Span::styled(
                     "Press Enter or Esc to exit",
-                    Style::default().fg(Color::DarkGray),
+                    theme.muted(),
                 )),
             ];
 
             let block = Block::default()
                 .borders(Borders::ALL)
-                .border_style(Style::default().fg(Color::Red));
+                .border_style(theme.error());
 
             let paragraph = Paragraph::new(text).block(block).alignment(Alignment::Center);
             f.render_widget(paragraph, dialog_area);
@@ -762,6 +921,16 @@ where
         if event::handle_events(app).await? {
             return Ok(());
         }
+
+        if let Some(shell) = app.pending_shell.take() {
+            run_shell_command(terminal, app, &shell).await?;
+        }
+        
+        // Apply results from background fetches and actions
+        app.poll_fetch_results();
+        app.poll_search_results();
+        app.poll_action_results().await;
+        app.expire_revealed();
         
         // Poll SSO if in waiting state
         if app.mode == Mode::SsoLogin {
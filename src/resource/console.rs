@@ -0,0 +1,126 @@
+//! AWS Management Console links for listed resources.
+
+use super::fetcher::extract_json_value;
+use super::registry::ResourceDef;
+use serde_json::Value;
+
+/// Console URL for `item`, a row of `resource` listed in `region`
+///
+/// Returns `None` for resources the console has no page for (or that need
+/// context the row doesn't carry, such as an S3 object's bucket).
+pub fn console_url(resource_key: &str, resource: &ResourceDef, item: &Value, region: &str) -> Option<String> {
+    let field = |path: &str| Some(extract_json_value(item, path)).filter(|v| !v.is_empty() && v != "-");
+    let id = field(&resource.id_field)?;
+    let name = field(&resource.name_field).unwrap_or_else(|| id.clone());
+    let r = region;
+    let regional = format!("https://{r}.console.aws.amazon.com");
+    let global = "https://console.aws.amazon.com";
+
+    let url = match resource.service.as_str() {
+        "ec2" => match resource_key {
+            "ec2-instances" => format!("{regional}/ec2/home?region={r}#InstanceDetails:instanceId={id}"),
+            "ec2-volumes" => format!("{regional}/ec2/home?region={r}#VolumeDetails:volumeId={id}"),
+            "ec2-snapshots" => format!("{regional}/ec2/home?region={r}#SnapshotDetails:snapshotId={id}"),
+            "ec2-amis" => format!("{regional}/ec2/home?region={r}#ImageDetails:imageId={id}"),
+            "security-groups" => format!("{regional}/ec2/home?region={r}#SecurityGroup:groupId={id}"),
+            "vpc" => format!("{regional}/vpcconsole/home?region={r}#VpcDetails:VpcId={id}"),
+            "subnets" => format!("{regional}/vpcconsole/home?region={r}#SubnetDetails:subnetId={id}"),
+            _ => return None,
+        },
+        "elbv2" => match resource_key {
+            "elbv2-load-balancers" => format!("{regional}/ec2/home?region={r}#LoadBalancer:loadBalancerArn={id}"),
+            "elbv2-target-groups" => format!("{regional}/ec2/home?region={r}#TargetGroup:targetGroupArn={id}"),
+            _ => return None,
+        },
+        "autoscaling" => format!("{regional}/ec2/home?region={r}#AutoScalingGroupDetails:id={id}"),
+        "lambda" => format!("{regional}/lambda/home?region={r}#/functions/{id}"),
+        "iam" => match resource_key {
+            "iam-users" | "iam-group-users" => format!("{global}/iam/home#/users/{name}"),
+            "iam-roles" => format!("{global}/iam/home#/roles/{name}"),
+            "iam-groups" | "iam-user-groups" => format!("{global}/iam/home#/groups/{name}"),
+            "iam-policies" => format!("{global}/iam/home#/policies/{}", field("Arn")?),
+            "iam-user-policies" | "iam-role-policies" => format!("{global}/iam/home#/policies/{}", field("PolicyArn")?),
+            _ => return None,
+        },
+        "rds" => match resource_key {
+            "rds-instances" => format!("{regional}/rds/home?region={r}#database:id={id}"),
+            "rds-snapshots" => format!("{regional}/rds/home?region={r}#db-snapshot:id={id}"),
+            _ => return None,
+        },
+        "dynamodb" => format!("{regional}/dynamodbv2/home?region={r}#table?name={name}"),
+        "ecs" if resource_key == "ecs-clusters" => format!("{regional}/ecs/v2/clusters/{name}?region={r}"),
+        "eks" => format!("{regional}/eks/home?region={r}#/clusters/{id}"),
+        "cloudformation" if resource_key == "cloudformation-stacks" => {
+            let stack = field("StackId").unwrap_or(id);
+            format!(
+                "{regional}/cloudformation/home?region={r}#/stacks/stackinfo?stackId={}",
+                urlencoding::encode(&stack)
+            )
+        }
+        // The log console double-encodes the group name in its fragment
+        "cloudwatchlogs" if resource_key == "cloudwatch-log-groups" => format!(
+            "{regional}/cloudwatch/home?region={r}#logsV2:log-groups/log-group/{}",
+            urlencoding::encode(&id).replace('%', "$25")
+        ),
+        "sqs" => format!("{regional}/sqs/v3/home?region={r}#/queues/{}", urlencoding::encode(&id)),
+        "sns" => format!("{regional}/sns/v3/home?region={r}#/topic/{id}"),
+        "secretsmanager" => format!(
+            "{regional}/secretsmanager/secret?name={}&region={r}",
+            urlencoding::encode(&name)
+        ),
+        "ssm" => format!(
+            "{regional}/systems-manager/parameters/{}/description?region={r}",
+            urlencoding::encode(&id)
+        ),
+        "kms" => format!("{regional}/kms/home?region={r}#/kms/keys/{id}"),
+        "acm" => format!(
+            "{regional}/acm/home?region={r}#/certificates/{}",
+            id.rsplit('/').next().unwrap_or(&id)
+        ),
+        "apigateway" => format!("{regional}/apigateway/main/apis/{id}/resources?api={id}&region={r}"),
+        "codebuild" => format!("{regional}/codesuite/codebuild/projects/{name}?region={r}"),
+        "codepipeline" => format!("{regional}/codesuite/codepipeline/pipelines/{name}/view?region={r}"),
+        "cloudfront" => format!("{global}/cloudfront/v4/home#/distributions/{id}"),
+        "route53" => format!(
+            "{global}/route53/v2/hostedzones#ListRecordSets/{}",
+            id.trim_start_matches("/hostedzone/")
+        ),
+        _ => return None,
+    };
+    Some(url)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::resource::get_resource;
+    use serde_json::json;
+
+    fn url(resource_key: &str, item: Value) -> Option<String> {
+        console_url(resource_key, get_resource(resource_key).unwrap(), &item, "eu-west-1")
+    }
+
+    #[test]
+    fn test_console_urls_use_region_and_id() {
+        assert_eq!(
+            url("ec2-instances", json!({ "InstanceId": "i-0abc" })).as_deref(),
+            Some("https://eu-west-1.console.aws.amazon.com/ec2/home?region=eu-west-1#InstanceDetails:instanceId=i-0abc")
+        );
+        assert_eq!(
+            url("iam-roles", json!({ "RoleId": "AROA1", "RoleName": "deploy" })).as_deref(),
+            Some("https://console.aws.amazon.com/iam/home#/roles/deploy")
+        );
+        assert_eq!(
+            url("cloudwatch-log-groups", json!({ "logGroupName": "/aws/lambda/api" })).as_deref(),
+            Some("https://eu-west-1.console.aws.amazon.com/cloudwatch/home?region=eu-west-1#logsV2:log-groups/log-group/$252Faws$252Flambda$252Fapi")
+        );
+    }
+
+    #[test]
+    fn test_no_console_url_without_a_page_or_id() {
+        assert_eq!(url("ec2-instance-tags", json!({ "key": "Name" })), None);
+        assert_eq!(url("ec2-instances", json!({})), None);
+        // S3 lists keep 'o' for their objects, so they have no console link
+        assert_eq!(url("s3-buckets", json!({ "Name": "logs" })), None);
+    }
+}
@@ -3,14 +3,16 @@
 //! This module provides a single generic function to fetch any AWS resource.
 //! All the logic is driven by the resources.json configuration.
 
-use super::registry::get_resource;
+use super::registry::{get_resource, ResourceDef};
 use super::sdk_dispatch::invoke_sdk;
 use crate::aws::client::AwsClients;
 use anyhow::{anyhow, Result};
+use futures_util::future::{join_all, BoxFuture};
+use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
 /// Filter for fetching resources (used for sub-resource filtering)
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct ResourceFilter {
     pub name: String,
     pub values: Vec<String>,
@@ -32,64 +34,107 @@ pub struct PaginatedResult {
     pub next_token: Option<String>,
 }
 
+/// Field added to items fetched by `fetch_across_regions`, naming their region
+pub const REGION_FIELD: &str = "_region";
+
+/// Items fetched from several regions, plus the regions that failed
+#[derive(Debug, Default)]
+pub struct RegionsResult {
+    pub items: Vec<Value>,
+    /// (region, error) for each region that couldn't be listed
+    pub failed: Vec<(String, String)>,
+}
+
+/// Source of raw API responses for the fetch layer
+///
+/// `AwsClients` implements this with the live dispatcher (`invoke_sdk`) and
+/// `Dump` with the lists it recorded; tests implement it with canned JSON so
+/// fetching can be exercised without AWS.
+pub trait ResourceBackend: Send + Sync {
+    /// Call an API method by its dispatcher name (e.g. "iam", "list_users")
+    fn invoke<'a>(&'a self, service: &'a str, method: &'a str, params: &'a Value) -> BoxFuture<'a, Result<Value>>;
+
+    /// Backend pinned to us-east-1, used to retry global resources that came back
+    /// empty. None when this backend already is (or can't be) pinned there.
+    fn global(&self) -> Option<Box<dyn ResourceBackend>> {
+        None
+    }
+}
+
+impl ResourceBackend for AwsClients {
+    fn invoke<'a>(&'a self, service: &'a str, method: &'a str, params: &'a Value) -> BoxFuture<'a, Result<Value>> {
+        Box::pin(invoke_sdk(service, method, self, params))
+    }
+
+    fn global(&self) -> Option<Box<dyn ResourceBackend>> {
+        AwsClients::global(self).map(|clients| Box::new(clients) as Box<dyn ResourceBackend>)
+    }
+}
+
 /// Fetch resources using the JSON-driven configuration
 ///
 /// This is the SINGLE entry point for fetching any AWS resource.
-/// It looks up the resource definition from JSON and uses the SDK dispatcher.
+/// It looks up the resource definition from JSON and calls the backend:
+/// AWS through the SDK dispatcher, or a dump file in replay mode.
 ///
 /// # Arguments
 /// * `resource_key` - The resource key (e.g., "iam-users", "iam-roles")
-/// * `clients` - AWS clients container
+/// * `backend` - Where the API responses come from (`AwsClients` or a `Dump`)
 /// * `filters` - Optional filters for sub-resource queries
 ///
 /// # Returns
 /// A vector of JSON values representing the resources
 pub async fn fetch_resources(
     resource_key: &str,
-    clients: &AwsClients,
+    backend: &dyn ResourceBackend,
     filters: &[ResourceFilter],
 ) -> Result<Vec<Value>> {
-    // 1. Look up resource definition from JSON
-    let resource_def = get_resource(resource_key)
-        .ok_or_else(|| anyhow!("Unknown resource: {}", resource_key))?;
+    Ok(fetch_resources_paginated(resource_key, backend, filters, None).await?.items)
+}
 
-    // 2. Build params (merge default params with filters)
-    let mut params = resource_def.sdk_method_params.clone();
-    
-    // Add filters to params if any
-    if !filters.is_empty() {
-        if let Value::Object(ref mut map) = params {
-            for filter in filters {
-                map.insert(filter.name.clone(), Value::Array(
-                    filter.values.iter().map(|v| Value::String(v.clone())).collect()
-                ));
+/// Fetch every page of a resource type from every `(region, backend)` pair
+/// concurrently and merge the lists in the given order. Each item gets a
+/// `REGION_FIELD`; a region that fails is reported in `failed` rather than
+/// failing the whole fetch.
+pub async fn fetch_across_regions<B: ResourceBackend>(
+    backends: &[(String, B)],
+    resource_key: &str,
+    filters: &[ResourceFilter],
+) -> RegionsResult {
+    let fetches = backends
+        .iter()
+        .map(|(_, backend)| fetch_all_pages(resource_key, backend, filters));
+    let responses = join_all(fetches).await;
+
+    let mut result = RegionsResult::default();
+    for ((region, _), response) in backends.iter().zip(responses) {
+        match response {
+            Ok(items) => {
+                result.items.extend(items.into_iter().map(|mut item| {
+                    if let Some(obj) = item.as_object_mut() {
+                        obj.insert(REGION_FIELD.to_string(), Value::String(region.clone()));
+                    }
+                    item
+                }));
             }
+            Err(e) => result.failed.push((region.clone(), format!("{:#}", e))),
         }
     }
+    result
+}
 
-    // 3. Call SDK dispatcher
-    let response = invoke_sdk(
-        &resource_def.service,
-        &resource_def.sdk_method,
-        clients,
-        &params,
-    ).await?;
-
-    // 4. Extract items using response_path
-    let mut items = extract_items(&response, &resource_def.response_path)?;
-    
-    // 5. Sort items by name_field for consistent ordering
-    let sort_field = &resource_def.name_field;
-    items.sort_by(|a, b| {
-        let a_val = a.get(sort_field)
-            .and_then(|v| v.as_str())
-            .unwrap_or("");
-        let b_val = b.get(sort_field)
-            .and_then(|v| v.as_str())
-            .unwrap_or("");
-        a_val.cmp(b_val)
-    });
-
+/// Fetch a resource type page after page until there is no next token
+async fn fetch_all_pages(
+    resource_key: &str,
+    backend: &dyn ResourceBackend,
+    filters: &[ResourceFilter],
+) -> Result<Vec<Value>> {
+    let mut page = fetch_resources_paginated(resource_key, backend, filters, None).await?;
+    let mut items = std::mem::take(&mut page.items);
+    while let Some(token) = page.next_token.take() {
+        page = fetch_resources_paginated(resource_key, backend, filters, Some(&token)).await?;
+        items.append(&mut page.items);
+    }
     Ok(items)
 }
 
@@ -98,15 +143,39 @@ pub async fn fetch_resources(
 /// Returns items for the current page and the next_token for fetching more
 pub async fn fetch_resources_paginated(
     resource_key: &str,
-    clients: &AwsClients,
+    backend: &dyn ResourceBackend,
     filters: &[ResourceFilter],
     page_token: Option<&str>,
 ) -> Result<PaginatedResult> {
     // 1. Look up resource definition from JSON
     let resource_def = get_resource(resource_key)
         .ok_or_else(|| anyhow!("Unknown resource: {}", resource_key))?;
+    if resource_def.sdk_method.is_empty() {
+        return Err(anyhow!("{} can only be opened from its parent list", resource_def.display_name));
+    }
 
     // 2. Build params (merge default params with filters)
+    let params = build_params(resource_def, filters, page_token);
+
+    // 3. Call SDK dispatcher
+    let response = invoke_for_resource(resource_def, backend, &params).await?;
+
+    // 4. Extract items using response_path
+    let mut items = extract_items(&response, &resource_def.response_path)?;
+    
+    // 5. Sort items by name_field for consistent ordering, unless the handler's order matters
+    sort_items(&mut items, resource_def);
+    
+    // 6. Extract next_token from response (if present)
+    let next_token = response.get("_next_token")
+        .and_then(|v| v.as_str())
+        .map(|s| s.to_string());
+
+    Ok(PaginatedResult { items, next_token })
+}
+
+/// Dispatcher params for a resource: its default params, the parent filters and the page token
+pub(super) fn build_params(resource_def: &ResourceDef, filters: &[ResourceFilter], page_token: Option<&str>) -> Value {
     let mut params = resource_def.sdk_method_params.clone();
     
     // Add filters to params if any
@@ -126,19 +195,32 @@ pub async fn fetch_resources_paginated(
             map.insert("_page_token".to_string(), Value::String(token.to_string()));
         }
     }
+    params
+}
 
-    // 3. Call SDK dispatcher
-    let response = invoke_sdk(
-        &resource_def.service,
-        &resource_def.sdk_method,
-        clients,
-        &params,
-    ).await?;
+/// List a sub-resource from the array at `json_path` of its parent item, without calling AWS
+///
+/// A missing path is an empty list and a lone object is a list of one, since
+/// XML responses (such as EC2's raw `tagSet`) drop empty lists and unwrap single items.
+pub fn items_from_parent(resource_key: &str, parent: &Value, json_path: &str) -> Result<Vec<Value>> {
+    let resource_def = get_resource(resource_key)
+        .ok_or_else(|| anyhow!("Unknown resource: {}", resource_key))?;
+    let pointer = format!("/{}", json_path.replace('.', "/"));
+    let mut items = match parent.pointer(&pointer) {
+        None | Some(Value::Null) => Vec::new(),
+        Some(Value::Array(items)) => items.clone(),
+        Some(item @ Value::Object(_)) => vec![item.clone()],
+        Some(other) => return Err(anyhow!("Expected '{}' to be a list, got {}", json_path, other)),
+    };
+    sort_items(&mut items, resource_def);
+    Ok(items)
+}
 
-    // 4. Extract items using response_path
-    let mut items = extract_items(&response, &resource_def.response_path)?;
-    
-    // 5. Sort items by name_field (or id_field) for consistent ordering
+/// Order items by name_field, unless the resource keeps the handler's order
+fn sort_items(items: &mut [Value], resource_def: &ResourceDef) {
+    if resource_def.preserve_order {
+        return;
+    }
     let sort_field = &resource_def.name_field;
     items.sort_by(|a, b| {
         let a_val = a.get(sort_field)
@@ -149,43 +231,144 @@ pub async fn fetch_resources_paginated(
             .unwrap_or("");
         a_val.cmp(b_val)
     });
-    
-    // 6. Extract next_token from response (if present)
-    let next_token = response.get("_next_token")
-        .and_then(|v| v.as_str())
-        .map(|s| s.to_string());
+}
 
-    Ok(PaginatedResult { items, next_token })
+/// Call the SDK dispatcher for a resource, routing global resources through us-east-1
+///
+/// IAM, Route 53 and CloudFront are pinned to us-east-1 by their service definition
+/// (see `http::get_service`), so they always resolve there. Other resources marked
+/// `is_global` in JSON (S3 bucket listing, STS identity) go through the regional
+/// endpoint first; if that returns nothing outside us-east-1, the call is retried
+/// against us-east-1.
+async fn invoke_for_resource(
+    resource_def: &ResourceDef,
+    backend: &dyn ResourceBackend,
+    params: &Value,
+) -> Result<Value> {
+    let response = backend.invoke(
+        &resource_def.service,
+        &resource_def.sdk_method,
+        params,
+    ).await?;
+
+    if !resource_def.is_global {
+        return Ok(response);
+    }
+
+    let is_empty = extract_items(&response, &resource_def.response_path)
+        .map(|items| items.is_empty())
+        .unwrap_or(true);
+    match backend.global() {
+        Some(global) if is_empty => {
+            tracing::debug!(
+                "{} returned nothing in the current region, retrying in us-east-1",
+                resource_def.sdk_method
+            );
+            global.invoke(&resource_def.service, &resource_def.sdk_method, params).await
+        }
+        _ => Ok(response),
+    }
 }
 
-/// Extract items array from response using the response_path
+/// Extract items array from response using the response_path.
+/// An empty path (or ".") means the response itself is the array, and a
+/// `[]` suffix flattens: "reservations[].instances" concatenates the
+/// `instances` arrays of every reservation
 fn extract_items(response: &Value, path: &str) -> Result<Vec<Value>> {
+    let path = path.trim();
+    if path.is_empty() || path == "." {
+        return match response {
+            Value::Array(arr) => Ok(arr.clone()),
+            _ => Err(anyhow!("Expected the response to be an array, got {:?}", response)),
+        };
+    }
+
     // Simple path extraction (e.g., "users", "roles")
     // For nested paths, split by '.' and traverse
     let parts: Vec<&str> = path.split('.').collect();
-    
-    let mut current = response.clone();
-    for part in parts {
-        current = current
-            .get(part)
-            .cloned()
-            .ok_or_else(|| anyhow!("Path '{}' not found in response", path))?;
+
+    let mut current = response;
+    for (i, part) in parts.iter().enumerate() {
+        let (field, flatten) = match part.strip_suffix("[]") {
+            Some(field) => (field, true),
+            None => (*part, false),
+        };
+        if !field.is_empty() {
+            current = current
+                .get(field)
+                .ok_or_else(|| anyhow!("Path '{}' not found in response", path))?;
+        }
+        if flatten {
+            let Value::Array(elements) = current else {
+                return Err(anyhow!("Expected array at '{}' in path '{}', got {:?}", part, path, current));
+            };
+            let rest = parts[i + 1..].join(".");
+            if rest.is_empty() {
+                return Ok(elements.clone());
+            }
+            // An element without the nested array (e.g. an empty reservation) contributes nothing
+            return Ok(elements
+                .iter()
+                .flat_map(|element| extract_items(element, &rest).unwrap_or_default())
+                .collect());
+        }
     }
 
     // Expect an array
     match current {
-        Value::Array(arr) => Ok(arr),
+        Value::Array(arr) => Ok(arr.clone()),
         _ => Err(anyhow!("Expected array at path '{}', got {:?}", path, current)),
     }
 }
 
+/// A response holding `items` where `extract_items` finds them for `path`
+/// (the inverse of `extract_items`, for backends that store lists)
+pub(super) fn response_with_items(path: &str, items: Vec<Value>) -> Value {
+    let mut current = Value::Array(items);
+    let path = path.trim();
+    if path.is_empty() || path == "." {
+        return current;
+    }
+
+    let parts: Vec<&str> = path.split('.').collect();
+    for (i, part) in parts.iter().enumerate().rev() {
+        let (field, flatten) = match part.strip_suffix("[]") {
+            Some(field) => (field, true),
+            None => (*part, false),
+        };
+        // A flattened array in the middle of the path holds objects that carry the rest
+        if flatten && i + 1 < parts.len() {
+            current = Value::Array(vec![current]);
+        }
+        if !field.is_empty() {
+            let mut map = serde_json::Map::new();
+            map.insert(field.to_string(), current);
+            current = Value::Object(map);
+        }
+    }
+    current
+}
+
 /// Extract a value from a JSON object using dot notation path
-/// Supports: "Field", "Field.SubField", "Field.0", "Tags.Name"
+/// Supports: "Field", "Field.SubField", "Field.0", "Tags.Name", "Field.length"
+/// and "Field[].SubField", which maps the rest of the path over an array and
+/// joins the results with ", "
 pub fn extract_json_value(item: &Value, path: &str) -> String {
-    let parts: Vec<&str> = path.split('.').collect();
     let mut current = item.clone();
+    let mut parts = path.split('.');
+
+    while let Some(part) = parts.next() {
+        if let Some(field) = part.strip_suffix("[]") {
+            if !field.is_empty() {
+                current = match current {
+                    Value::Object(mut map) => map.remove(field).unwrap_or(Value::Null),
+                    _ => Value::Null,
+                };
+            }
+            let rest: Vec<&str> = parts.collect();
+            return map_json_array(current, &rest.join("."));
+        }
 
-    for part in parts {
         current = match current {
             Value::Object(map) => {
                 // Special handling for Tags.Name pattern
@@ -213,8 +396,41 @@ pub fn extract_json_value(item: &Value, path: &str) -> String {
         };
     }
 
-    // Convert final value to string
-    match current {
+    json_value_to_string(current)
+}
+
+/// Apply `rest` to every element of an array and join the non-empty results.
+/// A lone object is treated as a one-element array, since XML responses
+/// collapse single-member lists.
+fn map_json_array(value: Value, rest: &str) -> String {
+    let elements = match value {
+        Value::Array(arr) => arr,
+        obj @ Value::Object(_) => vec![obj],
+        _ => return "-".to_string(),
+    };
+
+    let values: Vec<String> = elements
+        .into_iter()
+        .map(|element| {
+            if rest.is_empty() {
+                json_value_to_string(element)
+            } else {
+                extract_json_value(&element, rest)
+            }
+        })
+        .filter(|v| v != "-")
+        .collect();
+
+    if values.is_empty() {
+        "-".to_string()
+    } else {
+        values.join(", ")
+    }
+}
+
+/// Convert a final JSON value to its display string
+fn json_value_to_string(value: Value) -> String {
+    match value {
         Value::String(s) => s,
         Value::Number(n) => n.to_string(),
         Value::Bool(b) => {
@@ -228,3 +444,200 @@ pub fn extract_json_value(item: &Value, path: &str) -> String {
         _ => "-".to_string(),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use std::sync::{Arc, Mutex};
+
+    /// Backend returning canned responses and recording the params it was called with
+    #[derive(Clone, Default)]
+    struct MockBackend {
+        responses: Vec<((&'static str, &'static str), Value)>,
+        calls: Arc<Mutex<Vec<Value>>>,
+        global: Option<Box<MockBackend>>,
+        /// Responses for later pages, by the `_page_token` that asks for them
+        pages: Vec<(&'static str, Value)>,
+    }
+
+    impl MockBackend {
+        fn with(service: &'static str, method: &'static str, response: Value) -> Self {
+            Self {
+                responses: vec![((service, method), response)],
+                ..Default::default()
+            }
+        }
+    }
+
+    impl ResourceBackend for MockBackend {
+        fn invoke<'a>(&'a self, service: &'a str, method: &'a str, params: &'a Value) -> BoxFuture<'a, Result<Value>> {
+            self.calls.lock().unwrap().push(params.clone());
+            let token = params.get("_page_token").and_then(|t| t.as_str());
+            if let Some((_, page)) = self.pages.iter().find(|(t, _)| Some(*t) == token) {
+                let page = page.clone();
+                return Box::pin(async move { Ok(page) });
+            }
+            let response = self
+                .responses
+                .iter()
+                .find(|((s, m), _)| *s == service && *m == method)
+                .map(|(_, response)| response.clone())
+                .ok_or_else(|| anyhow!("Unsupported method: {}.{}", service, method));
+            Box::pin(async move { response })
+        }
+
+        fn global(&self) -> Option<Box<dyn ResourceBackend>> {
+            self.global.clone().map(|g| g as Box<dyn ResourceBackend>)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_fetch_extracts_and_sorts_items() {
+        let backend = MockBackend::with(
+            "iam",
+            "list_users",
+            json!({ "users": [{ "UserName": "bob" }, { "UserName": "alice" }] }),
+        );
+
+        let items = fetch_resources("iam-users", &backend, &[]).await.unwrap();
+        let names: Vec<String> = items.iter().map(|i| extract_json_value(i, "UserName")).collect();
+        assert_eq!(names, vec!["alice", "bob"]);
+    }
+
+    #[tokio::test]
+    async fn test_fetch_across_regions_tags_items_and_collects_failures() {
+        let paged = MockBackend {
+            pages: vec![("p2", json!({ "functions": [{ "FunctionName": "a2" }] }))],
+            ..MockBackend::with(
+                "lambda",
+                "list_functions",
+                json!({ "functions": [{ "FunctionName": "a1" }], "_next_token": "p2" }),
+            )
+        };
+        let backends = vec![
+            ("us-east-1".to_string(), paged),
+            ("eu-west-1".to_string(), MockBackend::default()),
+            (
+                "ap-south-1".to_string(),
+                MockBackend::with("lambda", "list_functions", json!({ "functions": [{ "FunctionName": "b" }] })),
+            ),
+        ];
+
+        let result = fetch_across_regions(&backends, "lambda-functions", &[]).await;
+        let regions: Vec<String> = result.items.iter().map(|i| extract_json_value(i, REGION_FIELD)).collect();
+        assert_eq!(regions, vec!["us-east-1", "us-east-1", "ap-south-1"]);
+        // Every page of a region is listed
+        let names: Vec<String> = result.items.iter().map(|i| extract_json_value(i, "FunctionName")).collect();
+        assert_eq!(names, vec!["a1", "a2", "b"]);
+        assert_eq!(result.failed.len(), 1);
+        assert_eq!(result.failed[0].0, "eu-west-1");
+    }
+
+    #[tokio::test]
+    async fn test_fetch_page_passes_filters_and_token() {
+        let backend = MockBackend::with(
+            "iam",
+            "list_access_keys",
+            json!({ "access_key_metadata": [{ "AccessKeyId": "AKIA1" }], "_next_token": "page-2" }),
+        );
+        let filters = [ResourceFilter::new("user_name", vec!["alice".to_string()])];
+
+        let page = fetch_resources_paginated("iam-access-keys", &backend, &filters, Some("page-1"))
+            .await
+            .unwrap();
+        assert_eq!(page.items.len(), 1);
+        assert_eq!(page.next_token.as_deref(), Some("page-2"));
+
+        let calls = backend.calls.lock().unwrap();
+        assert_eq!(calls[0]["user_name"], json!(["alice"]));
+        assert_eq!(calls[0]["_page_token"], json!("page-1"));
+    }
+
+    #[tokio::test]
+    async fn test_global_resource_retries_when_empty() {
+        let mut backend = MockBackend::with("s3", "list_buckets", json!({ "buckets": [] }));
+        backend.global = Some(Box::new(MockBackend::with(
+            "s3",
+            "list_buckets",
+            json!({ "buckets": [{ "Name": "logs" }] }),
+        )));
+
+        let items = fetch_resources("s3-buckets", &backend, &[]).await.unwrap();
+        assert_eq!(extract_json_value(&items[0], "Name"), "logs");
+    }
+
+    #[test]
+    fn test_empty_response_path_uses_root_array() {
+        let response = json!([{ "Id": "a" }, { "Id": "b" }]);
+        assert_eq!(extract_items(&response, "").unwrap().len(), 2);
+        assert_eq!(extract_items(&response, ".").unwrap().len(), 2);
+        assert!(extract_items(&json!({ "items": [] }), "").is_err());
+    }
+
+    #[test]
+    fn test_response_path_flattens_nested_arrays() {
+        let response = json!({ "reservations": [
+            { "instances": [{ "InstanceId": "i-1" }, { "InstanceId": "i-2" }] },
+            { "instances": [{ "InstanceId": "i-3" }] },
+            {}
+        ] });
+        let items = extract_items(&response, "reservations[].instances").unwrap();
+        let ids: Vec<String> = items.iter().map(|i| extract_json_value(i, "InstanceId")).collect();
+        assert_eq!(ids, vec!["i-1", "i-2", "i-3"]);
+        assert!(extract_items(&response, "missing[].instances").is_err());
+    }
+
+    #[test]
+    fn test_response_with_items_is_found_by_extract_items() {
+        let items = vec![json!({ "Id": "a" }), json!({ "Id": "b" })];
+        for path in ["", "users", "Result.Items", "reservations[].instances", "keys[]"] {
+            let response = response_with_items(path, items.clone());
+            assert_eq!(extract_items(&response, path).unwrap(), items, "path {:?}", path);
+        }
+    }
+
+    #[test]
+    fn test_extract_json_value_maps_over_arrays() {
+        let item = json!({
+            "Listeners": [
+                { "Port": 80, "Actions": [{ "Type": "redirect" }] },
+                { "Port": 443, "Actions": [{ "Type": "forward" }, { "Type": "authenticate" }] },
+                { "Port": 8080 }
+            ],
+            "Empty": [],
+            "Single": { "Name": "only" }
+        });
+        assert_eq!(extract_json_value(&item, "Listeners[].Port"), "80, 443, 8080");
+        assert_eq!(
+            extract_json_value(&item, "Listeners[].Actions[].Type"),
+            "redirect, forward, authenticate"
+        );
+        assert_eq!(extract_json_value(&item, "Empty[].Name"), "-");
+        assert_eq!(extract_json_value(&item, "Missing[].Name"), "-");
+        assert_eq!(extract_json_value(&item, "Single[].Name"), "only");
+        assert_eq!(extract_json_value(&json!({ "Ids": ["a", "b"] }), "Ids[]"), "a, b");
+    }
+
+    #[test]
+    fn test_items_from_parent_reads_parent_json() {
+        let instance = json!({ "_raw": { "tagSet": { "item": [
+            { "key": "team", "value": "web" },
+            { "key": "Name", "value": "api" }
+        ] } } });
+        let tags = items_from_parent("ec2-instance-tags", &instance, "_raw.tagSet.item").unwrap();
+        let keys: Vec<String> = tags.iter().map(|t| extract_json_value(t, "key")).collect();
+        assert_eq!(keys, vec!["Name", "team"]);
+
+        // XML responses unwrap single items and drop empty lists
+        let one_tag = json!({ "_raw": { "tagSet": { "item": { "key": "Name", "value": "api" } } } });
+        assert_eq!(items_from_parent("ec2-instance-tags", &one_tag, "_raw.tagSet.item").unwrap().len(), 1);
+        assert!(items_from_parent("ec2-instance-tags", &json!({ "_raw": {} }), "_raw.tagSet.item").unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_fetch_errors_on_missing_response_path() {
+        let backend = MockBackend::with("iam", "list_users", json!({ "unexpected": [] }));
+        assert!(fetch_resources("iam-users", &backend, &[]).await.is_err());
+    }
+}
@@ -1,7 +1,15 @@
 mod registry;
 mod fetcher;
 pub mod sdk_dispatch;
+mod wiring;
+mod timestamp;
+mod replay;
+mod console;
 
 pub use registry::*;
-pub use fetcher::{fetch_resources, fetch_resources_paginated, extract_json_value, ResourceFilter};
-pub use sdk_dispatch::{execute_action, describe_resource, invoke_sdk, format_log_timestamp};
+pub use fetcher::{fetch_resources, fetch_resources_paginated, fetch_across_regions, items_from_parent, extract_json_value, PaginatedResult, ResourceBackend, ResourceFilter, REGION_FIELD};
+pub use sdk_dispatch::{execute_action, execute_action_with_input, dry_run_action, supports_dry_run, DryRunOutcome, reveal_value, reveals_value, describe_resource, invoke_sdk, format_log_timestamp, RAW_FIELD};
+pub use wiring::find_wiring_gaps;
+pub use replay::Dump;
+pub use console::console_url;
+pub use timestamp::{age_days, format_timestamp};
@@ -3,62 +3,185 @@
 //! This module loads all AWS resource definitions from embedded JSON files
 //! and provides lookup functions for the rest of the application.
 
+use super::fetcher::extract_json_value;
+use regex::Regex;
 use serde::Deserialize;
 use serde_json::Value;
 use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
 use std::sync::OnceLock;
 
 /// Embedded resource JSON files (compiled into the binary)
-const RESOURCE_FILES: &[&str] = &[
-    include_str!("../resources/acm.json"),
-    include_str!("../resources/apigateway.json"),
-    include_str!("../resources/athena.json"),
-    include_str!("../resources/autoscaling.json"),
-    include_str!("../resources/cloudformation.json"),
-    include_str!("../resources/cloudfront.json"),
-    include_str!("../resources/cloudtrail.json"),
-    include_str!("../resources/cloudwatch.json"),
-    include_str!("../resources/codebuild.json"),
-    include_str!("../resources/codepipeline.json"),
-    include_str!("../resources/cognito.json"),
-    include_str!("../resources/common.json"),
-    include_str!("../resources/dynamodb.json"),
-    include_str!("../resources/ec2.json"),
-    include_str!("../resources/ecr.json"),
-    include_str!("../resources/ecs.json"),
-    include_str!("../resources/eks.json"),
-    include_str!("../resources/elasticache.json"),
-    include_str!("../resources/elbv2.json"),
-    include_str!("../resources/eventbridge.json"),
-    include_str!("../resources/iam.json"),
-    include_str!("../resources/kms.json"),
-    include_str!("../resources/lambda.json"),
-    include_str!("../resources/rds.json"),
-    include_str!("../resources/route53.json"),
-    include_str!("../resources/s3.json"),
-    include_str!("../resources/secretsmanager.json"),
-    include_str!("../resources/sns.json"),
-    include_str!("../resources/sqs.json"),
-    include_str!("../resources/ssm.json"),
-    include_str!("../resources/sts.json"),
-    include_str!("../resources/vpc.json"),
+const RESOURCE_FILES: &[(&str, &str)] = &[
+    ("acm.json", include_str!("../resources/acm.json")),
+    ("apigateway.json", include_str!("../resources/apigateway.json")),
+    ("athena.json", include_str!("../resources/athena.json")),
+    ("autoscaling.json", include_str!("../resources/autoscaling.json")),
+    ("ce.json", include_str!("../resources/ce.json")),
+    ("cloudformation.json", include_str!("../resources/cloudformation.json")),
+    ("cloudfront.json", include_str!("../resources/cloudfront.json")),
+    ("cloudtrail.json", include_str!("../resources/cloudtrail.json")),
+    ("cloudwatch.json", include_str!("../resources/cloudwatch.json")),
+    ("codebuild.json", include_str!("../resources/codebuild.json")),
+    ("codepipeline.json", include_str!("../resources/codepipeline.json")),
+    ("cognito.json", include_str!("../resources/cognito.json")),
+    ("common.json", include_str!("../resources/common.json")),
+    ("dynamodb.json", include_str!("../resources/dynamodb.json")),
+    ("ec2.json", include_str!("../resources/ec2.json")),
+    ("ecr.json", include_str!("../resources/ecr.json")),
+    ("ecs.json", include_str!("../resources/ecs.json")),
+    ("eks.json", include_str!("../resources/eks.json")),
+    ("elasticache.json", include_str!("../resources/elasticache.json")),
+    ("elbv2.json", include_str!("../resources/elbv2.json")),
+    ("eventbridge.json", include_str!("../resources/eventbridge.json")),
+    ("iam.json", include_str!("../resources/iam.json")),
+    ("kms.json", include_str!("../resources/kms.json")),
+    ("lambda.json", include_str!("../resources/lambda.json")),
+    ("rds.json", include_str!("../resources/rds.json")),
+    ("route53.json", include_str!("../resources/route53.json")),
+    ("s3.json", include_str!("../resources/s3.json")),
+    ("secretsmanager.json", include_str!("../resources/secretsmanager.json")),
+    ("sns.json", include_str!("../resources/sns.json")),
+    ("sqs.json", include_str!("../resources/sqs.json")),
+    ("ssm.json", include_str!("../resources/ssm.json")),
+    ("sts.json", include_str!("../resources/sts.json")),
+    ("vpc.json", include_str!("../resources/vpc.json")),
 ];
 
+/// How a color definition's `value` is compared against a cell
+#[derive(Debug, Clone, Copy, Default, PartialEq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MatchType {
+    #[default]
+    Exact,
+    Prefix,
+    Contains,
+    Regex,
+    /// Compares a `current/desired` cell: `below` matches when current < desired,
+    /// `met` when current >= desired
+    Ratio,
+}
+
 /// Color definition from JSON
 #[derive(Debug, Clone, Deserialize)]
 pub struct ColorDef {
     pub value: String,
     pub color: [u8; 3],
+    /// Match mode for `value` (defaults to exact)
+    #[serde(default, rename = "match")]
+    pub match_type: MatchType,
+    /// Compiled pattern for regex matching (built on first use)
+    #[serde(skip)]
+    regex: OnceLock<Option<Regex>>,
+}
+
+impl ColorDef {
+    /// Check whether a cell value matches this color definition
+    pub fn matches(&self, value: &str) -> bool {
+        match self.match_type {
+            MatchType::Exact => self.value == value,
+            MatchType::Prefix => value.starts_with(&self.value),
+            MatchType::Contains => value.contains(&self.value),
+            MatchType::Regex => self
+                .regex
+                .get_or_init(|| {
+                    Regex::new(&self.value)
+                        .inspect_err(|e| tracing::warn!("Invalid color map regex '{}': {}", self.value, e))
+                        .ok()
+                })
+                .as_ref()
+                .is_some_and(|re| re.is_match(value)),
+            MatchType::Ratio => {
+                let Some((current, desired)) = value.split_once('/') else {
+                    return false;
+                };
+                let (Ok(current), Ok(desired)) = (current.trim().parse::<f64>(), desired.trim().parse::<f64>()) else {
+                    return false;
+                };
+                match self.value.as_str() {
+                    "below" => current < desired,
+                    "met" => current >= desired,
+                    _ => false,
+                }
+            }
+        }
+    }
 }
 
 /// Column definition from JSON
 #[derive(Debug, Clone, Deserialize)]
 pub struct ColumnDef {
     pub header: String,
+    /// Path of the value to show (see `extract_json_value`); may be omitted when `template` is set
+    #[serde(default)]
     pub json_path: String,
+    /// Share of the table width, in percent (ignored for `flex` columns)
     pub width: u16,
+    /// Size the column to its longest value instead of `width`
+    #[serde(default)]
+    pub flex: bool,
+    /// Widest a `flex` column grows, in terminal columns
+    #[serde(default)]
+    pub max_width: Option<u16>,
     #[serde(default)]
     pub color_map: Option<String>,
+    /// How to display timestamp values (defaults to the raw value)
+    #[serde(default)]
+    pub format: Option<ColumnFormat>,
+    /// Highlight timestamps at least this many days old
+    #[serde(default)]
+    pub age_warn_days: Option<u32>,
+    /// Cell text built from several fields, e.g. `{runningCount}/{desiredCount}`
+    /// or `{Name} ({Id})`. Each `{json.path}` is replaced with
+    /// `extract_json_value(item, "json.path")`; text outside braces is kept
+    /// as-is and an unclosed `{` is shown literally. Takes precedence over
+    /// `json_path`.
+    #[serde(default)]
+    pub template: Option<String>,
+}
+
+impl ColumnDef {
+    /// The raw cell value for `item`: the expanded template, or the value at `json_path`
+    pub fn value(&self, item: &Value) -> String {
+        match &self.template {
+            Some(template) => expand_template(item, template),
+            None => extract_json_value(item, &self.json_path),
+        }
+    }
+}
+
+/// Replace each `{json.path}` placeholder in `template` with the item's value
+fn expand_template(item: &Value, template: &str) -> String {
+    expand_placeholders(item, template, |value| value)
+}
+
+/// `expand_template`, passing each substituted value through `transform`
+fn expand_placeholders(item: &Value, template: &str, transform: impl Fn(String) -> String) -> String {
+    let mut out = String::new();
+    let mut rest = template;
+    while let Some(start) = rest.find('{') {
+        let Some(len) = rest[start..].find('}') else {
+            break;
+        };
+        out.push_str(&rest[..start]);
+        out.push_str(&transform(extract_json_value(item, &rest[start + 1..start + len])));
+        rest = &rest[start + len + 1..];
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Display format for timestamp columns
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ColumnFormat {
+    /// Age relative to now, e.g. `3d ago`
+    Relative,
+    /// `YYYY-MM-DD HH:MM:SS`
+    Datetime,
+    /// `YYYY-MM-DD`
+    Date,
 }
 
 /// Sub-resource definition from JSON
@@ -67,8 +190,28 @@ pub struct SubResourceDef {
     pub resource_key: String,
     pub display_name: String,
     pub shortcut: String,
+    /// Parent field passed to the child's sdk_method as `filter_param` (unused for `"source": "parent"`)
+    #[serde(default)]
     pub parent_id_field: String,
+    #[serde(default)]
     pub filter_param: String,
+    /// Where the child items come from
+    #[serde(default)]
+    pub source: SubResourceSource,
+    /// Path of the child array in the parent item (for `"source": "parent"`)
+    #[serde(default)]
+    pub json_path: Option<String>,
+}
+
+/// Where a sub-resource's items come from
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SubResourceSource {
+    /// Call the child resource's `sdk_method`, filtered by the parent ID
+    #[default]
+    Sdk,
+    /// Slice `json_path` out of the parent item the list already fetched
+    Parent,
 }
 
 /// Confirmation config for actions
@@ -83,6 +226,37 @@ pub struct ConfirmConfig {
     /// If true, action is destructive (shown in red)
     #[serde(default)]
     pub destructive: bool,
+    /// If true, the resource name (or `yes`) must be typed to confirm, for actions that can't be undone
+    #[serde(default)]
+    pub typed: bool,
+}
+
+/// Text input config for actions that need a value from the user
+#[derive(Debug, Clone, Deserialize)]
+pub struct InputConfig {
+    /// Prompt shown above the input field
+    pub prompt: String,
+    /// Hint shown while the input is empty (e.g., "key=value")
+    #[serde(default)]
+    pub placeholder: Option<String>,
+}
+
+/// Precondition on the selected item for an action to apply
+/// (e.g. only offer Start when `DBInstanceStatus` is `stopped`)
+#[derive(Debug, Clone, Deserialize)]
+pub struct ActionCondition {
+    /// Path of the value to check (see `extract_json_value`)
+    pub field: String,
+    /// The action applies when the value is one of these (case-insensitive)
+    pub values: Vec<String>,
+}
+
+impl ActionCondition {
+    /// Whether `item` meets the condition
+    pub fn matches(&self, item: &Value) -> bool {
+        let value = extract_json_value(item, &self.field);
+        self.values.iter().any(|v| v.eq_ignore_ascii_case(&value))
+    }
 }
 
 /// Action definition from JSON
@@ -105,9 +279,20 @@ pub struct ActionDef {
     /// Confirmation configuration
     #[serde(default)]
     pub confirm: Option<ConfirmConfig>,
+    /// Prompt for a value before running the action
+    #[serde(default)]
+    pub input: Option<InputConfig>,
+    /// Only run the action when the selected item meets this condition
+    #[serde(default)]
+    pub enabled_when: Option<ActionCondition>,
 }
 
 impl ActionDef {
+    /// Whether the action applies to `item` (always true without `enabled_when`)
+    pub fn is_enabled_for(&self, item: &Value) -> bool {
+        self.enabled_when.as_ref().is_none_or(|condition| condition.matches(item))
+    }
+
     /// Check if this action requires confirmation
     pub fn requires_confirm(&self) -> bool {
         self.confirm.is_some() || self.needs_confirm
@@ -122,6 +307,7 @@ impl ActionDef {
                 message: Some(self.display_name.clone()),
                 default_yes: false,
                 destructive: false,
+                typed: false,
             })
         } else {
             None
@@ -129,24 +315,53 @@ impl ActionDef {
     }
 }
 
+/// External command run for the selected item, with the TUI suspended
+#[derive(Debug, Clone, Deserialize)]
+pub struct ShellCommandDef {
+    pub shortcut: String,
+    pub display_name: String,
+    /// Command line with `{json.path}` placeholders, split on whitespace into
+    /// the program and its arguments (no shell is involved)
+    pub command: String,
+}
+
+impl ShellCommandDef {
+    /// The program and arguments for `item`; a substituted value stays inside its word's argument
+    pub fn expand(&self, item: &Value) -> Vec<String> {
+        self.command
+            .split_whitespace()
+            .map(|word| expand_placeholders(item, word, |value| value))
+            .collect()
+    }
+}
+
 /// Resource definition from JSON
 #[derive(Debug, Clone, Deserialize)]
 pub struct ResourceDef {
     pub display_name: String,
     pub service: String,
+    /// Empty for resources only listed from their parent item (`"source": "parent"`)
+    #[serde(default)]
     pub sdk_method: String,
     #[serde(default)]
     pub sdk_method_params: Value,
+    #[serde(default)]
     pub response_path: String,
     pub id_field: String,
     pub name_field: String,
     #[serde(default)]
     pub is_global: bool,
+    /// Keep the order the dispatch handler returned instead of sorting by name_field
+    #[serde(default)]
+    pub preserve_order: bool,
     pub columns: Vec<ColumnDef>,
     #[serde(default)]
     pub sub_resources: Vec<SubResourceDef>,
     #[serde(default)]
     pub actions: Vec<ActionDef>,
+    /// External commands bound to keys (e.g. `aws logs tail`)
+    #[serde(default)]
+    pub commands: Vec<ShellCommandDef>,
     /// SDK method to call when fetching details for a single resource
     #[serde(default)]
     pub detail_sdk_method: Option<String>,
@@ -168,22 +383,71 @@ pub struct ResourceConfig {
 static REGISTRY: OnceLock<ResourceConfig> = OnceLock::new();
 
 /// Get the resource registry (loads from embedded JSON on first access)
+///
+/// User definitions from `<config dir>/resources/*.json` are merged on top,
+/// overriding embedded resources and color maps with the same key.
 pub fn get_registry() -> &'static ResourceConfig {
     REGISTRY.get_or_init(|| {
-        let mut final_config = ResourceConfig {
-            color_maps: HashMap::new(),
-            resources: HashMap::new(),
-        };
+        let user_dir = crate::config::Config::config_dir().join("resources");
+        let user_files = read_user_resource_files(&user_dir);
+
+        let files = RESOURCE_FILES.iter().copied().chain(
+            user_files
+                .iter()
+                .map(|(name, content)| (name.as_str(), content.as_str())),
+        );
+        merge_resource_files(files)
+    })
+}
 
-        for content in RESOURCE_FILES {
-            let partial: ResourceConfig = serde_json::from_str(content)
-                .unwrap_or_else(|e| panic!("Failed to parse embedded resource JSON: {}", e));
-            final_config.color_maps.extend(partial.color_maps);
-            final_config.resources.extend(partial.resources);
+/// Merge resource files in order, later files overriding earlier keys
+///
+/// A file that fails to parse is logged and skipped so one bad definition
+/// doesn't take the rest of the registry down with it.
+fn merge_resource_files<'a>(
+    files: impl IntoIterator<Item = (&'a str, &'a str)>,
+) -> ResourceConfig {
+    let mut final_config = ResourceConfig {
+        color_maps: HashMap::new(),
+        resources: HashMap::new(),
+    };
+
+    for (name, content) in files {
+        match serde_json::from_str::<ResourceConfig>(content) {
+            Ok(partial) => {
+                final_config.color_maps.extend(partial.color_maps);
+                final_config.resources.extend(partial.resources);
+            }
+            Err(e) => tracing::warn!("Skipping invalid resource file {}: {}", name, e),
         }
+    }
 
-        final_config
-    })
+    final_config
+}
+
+/// Read user resource files from a directory, in file name order
+///
+/// Returns `(path, content)` pairs. Unreadable files are logged and skipped.
+fn read_user_resource_files(dir: &Path) -> Vec<(String, String)> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return Vec::new();
+    };
+
+    let mut paths: Vec<PathBuf> = entries
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+        .collect();
+    paths.sort();
+
+    paths
+        .into_iter()
+        .filter_map(|path| {
+            let content = fs::read_to_string(&path)
+                .inspect_err(|e| tracing::warn!("Failed to read resource file {:?}: {}", path, e))
+                .ok()?;
+            Some((path.display().to_string(), content))
+        })
+        .collect()
 }
 
 /// Get a resource definition by key
@@ -209,7 +473,7 @@ pub fn get_color_map(name: &str) -> Option<&'static Vec<ColorDef>> {
 pub fn get_color_for_value(color_map_name: &str, value: &str) -> Option<[u8; 3]> {
     get_color_map(color_map_name)?
         .iter()
-        .find(|c| c.value == value)
+        .find(|c| c.matches(value))
         .map(|c| c.color)
 }
 
@@ -303,6 +567,18 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_ec2_create_tags_prompts_for_input() {
+        let resource = get_resource("ec2-instances").unwrap();
+        let tag_action = resource
+            .actions
+            .iter()
+            .find(|a| a.sdk_method == "create_tags")
+            .expect("EC2 should have create_tags action");
+        assert!(tag_action.input.is_some(), "create_tags should prompt for input");
+        assert!(!tag_action.requires_confirm());
+    }
+
     #[test]
     fn test_get_all_resource_keys() {
         let keys = get_all_resource_keys();
@@ -335,6 +611,96 @@ mod tests {
         assert_eq!(color.unwrap(), [0, 255, 0]);
     }
 
+    #[test]
+    fn test_color_map_pattern_matching() {
+        assert_eq!(get_color_for_value("state", "CREATE_COMPLETE"), Some([0, 255, 0]));
+        assert_eq!(get_color_for_value("state", "UPDATE_COMPLETE"), Some([0, 255, 0]));
+        assert_eq!(get_color_for_value("state", "UPDATE_ROLLBACK_COMPLETE"), Some([255, 165, 0]));
+        assert_eq!(get_color_for_value("state", "CREATE_FAILED"), Some([255, 0, 0]));
+        assert_eq!(get_color_for_value("state", "COMPLETE"), None);
+    }
+
+    #[test]
+    fn test_color_def_match_types() {
+        let def = |value: &str, match_type: &str| -> ColorDef {
+            serde_json::from_str(&format!(
+                r#"{{ "value": "{}", "match": "{}", "color": [1, 2, 3] }}"#,
+                value, match_type
+            ))
+            .unwrap()
+        };
+        assert!(def("run", "prefix").matches("running"));
+        assert!(!def("run", "exact").matches("running"));
+        assert!(def("nn", "contains").matches("running"));
+        assert!(def("^r.*g$", "regex").matches("running"));
+        assert!(!def("(", "regex").matches("("), "invalid regex should never match");
+        assert!(def("below", "ratio").matches("1/3"));
+        assert!(!def("below", "ratio").matches("3/3"));
+        assert!(def("met", "ratio").matches("3/3"));
+        assert!(!def("met", "ratio").matches("-"), "non-ratio values should never match");
+    }
+
+    #[test]
+    fn test_column_template_substitution() {
+        let column = |json: &str| -> ColumnDef { serde_json::from_str(json).unwrap() };
+        let item = serde_json::json!({
+            "Name": "web",
+            "Id": "i-123",
+            "Counts": { "running": 2, "desired": 3 },
+            "Tags": { "Env": "prod" }
+        });
+
+        let combined = column(r#"{ "header": "NAME", "template": "{Name} ({Id})", "width": 20 }"#);
+        assert_eq!(combined.value(&item), "web (i-123)");
+
+        let nested = column(r#"{ "header": "TASKS", "template": "{Counts.running}/{Counts.desired}", "width": 10 }"#);
+        assert_eq!(nested.value(&item), "2/3");
+
+        let tag = column(r#"{ "header": "ENV", "template": "env={Tags.Env}", "width": 10 }"#);
+        assert_eq!(tag.value(&item), "env=prod");
+
+        let unclosed = column(r#"{ "header": "X", "template": "{Name} {oops", "width": 10 }"#);
+        assert_eq!(unclosed.value(&item), "web {oops");
+
+        // Template wins over json_path; plain columns are unchanged
+        let both = column(r#"{ "header": "X", "json_path": "Id", "template": "[{Name}]", "width": 10 }"#);
+        assert_eq!(both.value(&item), "[web]");
+        let plain = column(r#"{ "header": "ID", "json_path": "Id", "width": 10 }"#);
+        assert_eq!(plain.value(&item), "i-123");
+    }
+
+    #[test]
+    fn test_shell_command_passes_values_as_single_arguments() {
+        let command: ShellCommandDef = serde_json::from_str(
+            r#"{ "shortcut": "t", "display_name": "Tail", "command": "aws logs tail {logGroupName} --follow" }"#,
+        )
+        .unwrap();
+        let item = serde_json::json!({ "logGroupName": "/aws/lambda/it's; rm -rf ~ & echo %PATH% | more ^" });
+        assert_eq!(
+            command.expand(&item),
+            vec!["aws", "logs", "tail", "/aws/lambda/it's; rm -rf ~ & echo %PATH% | more ^", "--follow"]
+        );
+    }
+
+    #[test]
+    fn test_iam_access_keys_warn_on_age() {
+        let resource = get_resource("iam-access-keys").unwrap();
+        let created = resource
+            .columns
+            .iter()
+            .find(|c| c.json_path == "CreateDate")
+            .expect("Access keys should have a CreateDate column");
+        assert_eq!(created.age_warn_days, Some(90));
+    }
+
+    #[test]
+    fn test_cost_by_service_keeps_handler_order() {
+        let resource = get_resource("cost-by-service").unwrap();
+        assert_eq!(resource.service, "ce");
+        assert!(resource.preserve_order, "Costs are sorted by amount, not by service name");
+        assert!(!get_resource("ec2-instances").unwrap().preserve_order);
+    }
+
     #[test]
     fn test_rds_has_sub_resources() {
         let resource = get_resource("rds-instances").unwrap();
@@ -395,6 +761,13 @@ mod tests {
     #[test]
     fn test_all_resources_have_required_fields() {
         let registry = get_registry();
+        let parent_sourced: Vec<&str> = registry
+            .resources
+            .values()
+            .flat_map(|r| &r.sub_resources)
+            .filter(|sub| sub.source == SubResourceSource::Parent)
+            .map(|sub| sub.resource_key.as_str())
+            .collect();
         for (key, resource) in &registry.resources {
             assert!(
                 !resource.display_name.is_empty(),
@@ -407,8 +780,8 @@ mod tests {
                 key
             );
             assert!(
-                !resource.sdk_method.is_empty(),
-                "Resource {} should have sdk_method",
+                !resource.sdk_method.is_empty() || parent_sourced.contains(&key.as_str()),
+                "Resource {} should have sdk_method or be read from its parent",
                 key
             );
             assert!(
@@ -493,6 +866,83 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_elbv2_sub_resource_filter_params() {
+        // These must match the params read by the elbv2 describe_* dispatch arms
+        let expected = [
+            ("elbv2-load-balancers", "elbv2-listeners", "load_balancer_arn"),
+            ("elbv2-load-balancers", "elbv2-target-groups", "load_balancer_arn"),
+            ("elbv2-listeners", "elbv2-rules", "listener_arn"),
+            ("elbv2-target-groups", "elbv2-targets", "target_group_arn"),
+        ];
+
+        for (parent, child, param) in expected {
+            let sub = get_resource(parent)
+                .unwrap()
+                .sub_resources
+                .iter()
+                .find(|s| s.resource_key == child)
+                .unwrap_or_else(|| panic!("{} should have {} sub-resource", parent, child));
+            assert_eq!(sub.filter_param, param, "{} -> {} filter param", parent, child);
+        }
+    }
+
+    #[test]
+    fn test_rds_start_stop_follow_instance_status() {
+        let rds = get_resource("rds-instances").unwrap();
+        let action = |method: &str| rds.actions.iter().find(|a| a.sdk_method == method).unwrap();
+        let stopped = serde_json::json!({ "DBInstanceStatus": "stopped" });
+        let available = serde_json::json!({ "DBInstanceStatus": "available" });
+
+        assert!(action("start_db_instance").is_enabled_for(&stopped));
+        assert!(!action("start_db_instance").is_enabled_for(&available));
+        assert!(action("stop_db_instance").is_enabled_for(&available));
+        assert!(!action("stop_db_instance").is_enabled_for(&stopped));
+        // Actions without a condition always apply
+        assert!(action("reboot_db_instance").is_enabled_for(&stopped));
+    }
+
+    #[test]
+    fn test_ec2_actions_follow_instance_state() {
+        let ec2 = get_resource("ec2-instances").unwrap();
+        let enabled = |state: &str| -> Vec<&str> {
+            let item = serde_json::json!({ "State": state });
+            ec2.actions
+                .iter()
+                .filter(|a| a.is_enabled_for(&item))
+                .map(|a| a.sdk_method.as_str())
+                .collect()
+        };
+
+        assert!(!enabled("running").contains(&"start_instance"));
+        assert!(enabled("running").contains(&"stop_instance"));
+        assert!(enabled("stopped").contains(&"start_instance"));
+        assert!(!enabled("stopped").contains(&"reboot_instance"));
+        assert_eq!(enabled("terminated"), vec!["show_metrics", "create_tags"]);
+    }
+
+    #[test]
+    fn test_parent_sourced_sub_resources() {
+        let tasks = get_resource("ecs-tasks").unwrap();
+        let containers = &tasks.sub_resources[0];
+        assert_eq!(containers.source, SubResourceSource::Parent);
+        assert_eq!(containers.json_path.as_deref(), Some("containers"));
+        assert!(get_resource("ecs-task-containers").unwrap().sdk_method.is_empty());
+
+        let volumes = &get_resource("ec2-instances").unwrap().sub_resources[0];
+        assert_eq!(volumes.source, SubResourceSource::Sdk);
+        assert!(get_resource("ec2-instance-tags").unwrap().sdk_method.is_empty());
+    }
+
+    #[test]
+    fn test_cfn_status_colors_failures_red() {
+        let red = Some([255, 0, 0]);
+        assert_eq!(get_color_for_value("cfn_status", "CREATE_FAILED"), red);
+        assert_eq!(get_color_for_value("cfn_status", "UPDATE_ROLLBACK_FAILED"), red);
+        assert_eq!(get_color_for_value("cfn_status", "ROLLBACK_COMPLETE"), Some([255, 165, 0]));
+        assert_eq!(get_color_for_value("cfn_status", "UPDATE_COMPLETE"), Some([0, 255, 0]));
+    }
+
     #[test]
     fn test_elbv2_health_color_map_exists() {
         let health_map = get_color_map("health");
@@ -502,4 +952,62 @@ mod tests {
         assert!(color.is_some(), "Should have color for 'healthy' state");
         assert_eq!(color.unwrap(), [0, 255, 0]); // Green color
     }
+
+    #[test]
+    fn test_read_user_resource_files_only_reads_json() {
+        let dir = std::env::temp_dir().join(format!("taws-user-resources-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("b.json"), "{}").unwrap();
+        fs::write(dir.join("a.json"), "{}").unwrap();
+        fs::write(dir.join("notes.txt"), "ignored").unwrap();
+
+        let files = read_user_resource_files(&dir);
+        fs::remove_dir_all(&dir).unwrap();
+
+        let names: Vec<&str> = files
+            .iter()
+            .map(|(name, _)| name.rsplit(std::path::MAIN_SEPARATOR).next().unwrap())
+            .collect();
+        assert_eq!(names, vec!["a.json", "b.json"]);
+    }
+
+    #[test]
+    fn test_invalid_resource_file_does_not_abort_loading() {
+        let valid = r#"{ "resources": { "my-things": {
+            "display_name": "My Things", "service": "ec2", "sdk_method": "describe_vpcs",
+            "response_path": "vpcs", "id_field": "VpcId", "name_field": "VpcId",
+            "columns": [ { "header": "ID", "json_path": "VpcId", "width": 100 } ]
+        } } }"#;
+
+        let config = merge_resource_files([
+            ("broken.json", "{ not json"),
+            ("valid.json", valid),
+            ("wrong-shape.json", r#"{ "resources": { "x": { "display_name": 1 } } }"#),
+        ]);
+
+        assert_eq!(config.resources.len(), 1);
+        assert!(config.resources.contains_key("my-things"));
+    }
+
+    #[test]
+    fn test_later_resource_file_overrides_earlier() {
+        let file = |name: &str| {
+            format!(
+                r#"{{ "resources": {{ "things": {{
+                    "display_name": "{}", "service": "ec2", "sdk_method": "describe_vpcs",
+                    "response_path": "vpcs", "id_field": "VpcId", "name_field": "VpcId",
+                    "columns": []
+                }} }} }}"#,
+                name
+            )
+        };
+        let (embedded, user) = (file("Embedded"), file("User"));
+
+        let config = merge_resource_files([
+            ("embedded.json", embedded.as_str()),
+            ("user.json", user.as_str()),
+        ]);
+
+        assert_eq!(config.resources["things"].display_name, "User");
+    }
 }
@@ -0,0 +1,142 @@
+//! Offline replay of resource lists
+//!
+//! `:dump <file>` writes the lists taws has in memory to a JSON file, and
+//! `taws --replay <file>` serves them back instead of calling AWS, so the UI
+//! can be explored (or a bug reproduced) without credentials.
+
+use super::fetcher::{build_params, response_with_items, ResourceBackend, ResourceFilter};
+use super::registry::{get_all_resource_keys, get_resource};
+use anyhow::{anyhow, Context, Result};
+use futures_util::future::BoxFuture;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::path::Path;
+
+/// Contents of a dump file
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Dump {
+    /// Profile the lists were fetched with
+    #[serde(default)]
+    pub profile: String,
+    /// Region the lists were fetched from
+    #[serde(default)]
+    pub region: String,
+    #[serde(default)]
+    pub lists: Vec<DumpedList>,
+}
+
+/// One resource list, as fetched with the given parent filters
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DumpedList {
+    pub resource_key: String,
+    /// Sub-resource filters (empty for top-level lists)
+    #[serde(default)]
+    pub filters: Vec<ResourceFilter>,
+    pub items: Vec<Value>,
+}
+
+impl Dump {
+    /// Read a dump file written by `:dump`
+    pub fn load(path: &Path) -> Result<Self> {
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        serde_json::from_str(&content)
+            .with_context(|| format!("Failed to parse {}", path.display()))
+    }
+
+    /// Write the dump as pretty-printed JSON
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let content = serde_json::to_string_pretty(self)?;
+        std::fs::write(path, content)
+            .with_context(|| format!("Failed to write {}", path.display()))
+    }
+
+    /// Add a list, replacing any earlier one for the same resource and filters
+    pub fn add(&mut self, resource_key: &str, filters: &[ResourceFilter], items: Vec<Value>) {
+        self.lists
+            .retain(|list| !(list.resource_key == resource_key && list.filters == filters));
+        self.lists.push(DumpedList {
+            resource_key: resource_key.to_string(),
+            filters: filters.to_vec(),
+            items,
+        });
+    }
+
+    /// The recorded list a fetch of `service`/`method` with `params` asks for,
+    /// shaped like the dispatcher's response for that resource
+    fn response(&self, service: &str, method: &str, params: &Value) -> Result<Value> {
+        // Dumps hold a single page of each list
+        let mut params = params.clone();
+        if let Value::Object(map) = &mut params {
+            map.remove("_page_token");
+        }
+
+        let mut resources: Vec<(&str, _)> = get_all_resource_keys()
+            .into_iter()
+            .filter_map(|key| get_resource(key).map(|def| (key, def)))
+            .filter(|(_, def)| def.service == service && def.sdk_method == method)
+            .collect();
+        resources.sort_by_key(|(key, _)| *key);
+
+        let recorded = resources.iter().find_map(|(key, def)| {
+            self.lists
+                .iter()
+                .find(|list| list.resource_key == *key && build_params(def, &list.filters, None) == params)
+                .map(|list| (def, list.items.clone()))
+        });
+        // A list that was never dumped is empty
+        let (def, items) = match recorded {
+            Some(found) => found,
+            None => resources
+                .first()
+                .map(|(_, def)| (def, Vec::new()))
+                .ok_or_else(|| anyhow!("Not available in replay mode"))?,
+        };
+        Ok(response_with_items(&def.response_path, items))
+    }
+}
+
+impl ResourceBackend for Dump {
+    fn invoke<'a>(&'a self, service: &'a str, method: &'a str, params: &'a Value) -> BoxFuture<'a, Result<Value>> {
+        Box::pin(async move { self.response(service, method, params) })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::resource::fetch_resources;
+    use serde_json::json;
+
+    #[tokio::test]
+    async fn test_dump_round_trip_and_lookup() {
+        let filters = vec![ResourceFilter::new("user_name", vec!["alice".to_string()])];
+        let mut dump = Dump {
+            profile: "dev".to_string(),
+            region: "eu-west-1".to_string(),
+            lists: Vec::new(),
+        };
+        dump.add("iam-users", &[], vec![json!({"UserName": "alice"})]);
+        dump.add("iam-access-keys", &filters, vec![json!({"AccessKeyId": "AKIA1"})]);
+        dump.add("iam-access-keys", &filters, vec![json!({"AccessKeyId": "AKIA2"})]);
+
+        let path = std::env::temp_dir().join(format!("taws-dump-test-{}.json", std::process::id()));
+        dump.save(&path).unwrap();
+        let loaded = Dump::load(&path).unwrap();
+        let _ = std::fs::remove_file(&path);
+
+        assert_eq!(loaded.region, "eu-west-1");
+        assert_eq!(loaded.lists.len(), 2);
+
+        // Served back through the fetch layer like any backend
+        let fetch = |key: &'static str, filters: Vec<ResourceFilter>| {
+            let loaded = &loaded;
+            async move { fetch_resources(key, loaded, &filters).await }
+        };
+        assert_eq!(fetch("iam-users", vec![]).await.unwrap(), vec![json!({"UserName": "alice"})]);
+        assert_eq!(fetch("iam-access-keys", filters.clone()).await.unwrap(), vec![json!({"AccessKeyId": "AKIA2"})]);
+        assert!(fetch("iam-access-keys", vec![]).await.unwrap().is_empty());
+        assert!(fetch("ec2-instances", vec![]).await.unwrap().is_empty());
+        assert!(loaded.invoke("iam", "delete_user", &json!({})).await.is_err());
+    }
+}
@@ -6,7 +6,9 @@
 use crate::aws::client::AwsClients;
 use crate::aws::http::xml_to_json;
 use anyhow::{anyhow, Result};
+use futures_util::stream::{self, StreamExt};
 use serde_json::{json, Value};
+use std::collections::HashMap;
 use tracing::debug;
 
 // =============================================================================
@@ -23,6 +25,17 @@ fn extract_param(params: &Value, key: &str) -> String {
         .unwrap_or_default()
 }
 
+/// Field under which list operations keep the untouched API item next to the projected row
+pub const RAW_FIELD: &str = "_raw";
+
+/// Attach the untouched API item to a projected row so Describe can show fields the table drops
+fn with_raw(mut item: Value, raw: &Value) -> Value {
+    if let Value::Object(ref mut map) = item {
+        map.insert(RAW_FIELD.to_string(), raw.clone());
+    }
+    item
+}
+
 /// Format bytes into human-readable format
 fn format_bytes(bytes: u64) -> String {
     const KB: u64 = 1024;
@@ -90,6 +103,116 @@ fn parse_query_list(xml: &str, list_key: &str, item_key: &str) -> Result<Vec<Val
 // Action Functions (write operations)
 // =============================================================================
 
+/// Shown instead of a SecureString parameter's value until it is revealed
+const SECURE_STRING_MASK: &str = "******** (SecureString, press v in the list to reveal)";
+
+/// Whether an action shows a secret value (see `reveal_value`) instead of changing anything
+pub fn reveals_value(service: &str, action: &str) -> bool {
+    matches!(
+        (service, action),
+        ("ssm", "reveal_parameter") | ("secretsmanager", "get_secret_value")
+    )
+}
+
+/// Fetch the plain-text value behind a reveal action. The request and response
+/// bodies are kept out of the trace log.
+pub async fn reveal_value(
+    service: &str,
+    action: &str,
+    clients: &AwsClients,
+    resource_id: &str,
+) -> Result<String> {
+    match (service, action) {
+        ("ssm", "reveal_parameter") => {
+            let request_body = json!({ "Name": resource_id, "WithDecryption": true }).to_string();
+            let response = clients.http.json_request_sensitive("ssm", "GetParameter", &request_body).await?;
+            let json: Value = serde_json::from_str(&response)?;
+            json.pointer("/Parameter/Value")
+                .and_then(|v| v.as_str())
+                .map(str::to_string)
+                .ok_or_else(|| anyhow!("GetParameter returned no value for {}", resource_id))
+        }
+        ("secretsmanager", "get_secret_value") => {
+            let request_body = json!({ "SecretId": resource_id }).to_string();
+            let response = clients.http.json_request_sensitive("secretsmanager", "GetSecretValue", &request_body).await?;
+            let json: Value = serde_json::from_str(&response)?;
+            if let Some(value) = json.get("SecretString").and_then(|v| v.as_str()) {
+                return Ok(value.to_string());
+            }
+            json.get("SecretBinary")
+                .and_then(|v| v.as_str())
+                .map(|binary| format!("(binary secret, base64) {}", binary))
+                .ok_or_else(|| anyhow!("GetSecretValue returned no value for {}", resource_id))
+        }
+        _ => Err(anyhow!("Unknown reveal action: {}.{}", service, action)),
+    }
+}
+
+/// What AWS said about an action run with `DryRun=true`
+#[derive(Debug, Clone, PartialEq)]
+pub enum DryRunOutcome {
+    /// The call would have succeeded (EC2 answered `DryRunOperation`)
+    Allowed,
+    /// The call would fail; holds the reason, usually the missing permission
+    Refused(String),
+}
+
+/// EC2 operation and ID parameter for actions that accept `DryRun`
+fn dry_run_request(service: &str, action: &str) -> Option<(&'static str, &'static str)> {
+    match (service, action) {
+        ("ec2", "start_instance") => Some(("StartInstances", "InstanceId.1")),
+        ("ec2", "stop_instance") => Some(("StopInstances", "InstanceId.1")),
+        ("ec2", "reboot_instance") => Some(("RebootInstances", "InstanceId.1")),
+        ("ec2", "terminate_instance") => Some(("TerminateInstances", "InstanceId.1")),
+        ("ec2", "delete_snapshot") => Some(("DeleteSnapshot", "SnapshotId")),
+        ("ec2", "deregister_image") => Some(("DeregisterImage", "ImageId")),
+        _ => None,
+    }
+}
+
+/// Whether `dry_run_action` can preview this action
+pub fn supports_dry_run(service: &str, action: &str) -> bool {
+    dry_run_request(service, action).is_some()
+}
+
+/// Ask EC2 whether an action would be authorized, without performing it
+///
+/// Refused with a custom endpoint: an emulator that ignores `DryRun` would
+/// carry out the action for real.
+pub async fn dry_run_action(
+    service: &str,
+    action: &str,
+    clients: &AwsClients,
+    resource_id: &str,
+) -> Result<DryRunOutcome> {
+    if clients.http.has_custom_endpoint() {
+        return Err(anyhow!("Dry run is not available with a custom endpoint"));
+    }
+    let (operation, id_param) = dry_run_request(service, action)
+        .ok_or_else(|| anyhow!("{}.{} has no dry run", service, action))?;
+
+    let clients = clients.without_retries();
+    let response = clients.http.query_request(service, operation, &[
+        (id_param, resource_id),
+        ("DryRun", "true"),
+    ]).await;
+
+    dry_run_outcome(operation, response, &clients.profile)
+}
+
+/// Read the outcome of a dry run from EC2's response to it
+fn dry_run_outcome(operation: &str, response: Result<String>, profile: &str) -> Result<DryRunOutcome> {
+    // EC2 always answers a dry run with an error; DryRunOperation means "would succeed"
+    match response {
+        Ok(_) => Err(anyhow!("{} did not report a dry run result", operation)),
+        Err(e) if e.to_string().contains("DryRunOperation") => Ok(DryRunOutcome::Allowed),
+        Err(e) if e.to_string().contains("UnauthorizedOperation") => {
+            Ok(DryRunOutcome::Refused(crate::aws::client::format_aws_error(&e, profile)))
+        }
+        Err(e) => Err(e),
+    }
+}
+
 /// Execute an action on a resource (start, stop, terminate, etc.)
 pub async fn execute_action(
     service: &str,
@@ -97,6 +220,9 @@ pub async fn execute_action(
     clients: &AwsClients,
     resource_id: &str,
 ) -> Result<()> {
+
+    // Write operations are not idempotent, so never retry them
+    let clients = &clients.without_retries();
     match (service, action) {
         // EC2 Instance Actions
         ("ec2", "start_instance") => {
@@ -124,16 +250,21 @@ pub async fn execute_action(
             Ok(())
         }
 
-        // Lambda Actions
-        ("lambda", "invoke_function") => {
-            clients.http.rest_json_request(
-                "lambda",
-                "POST",
-                &format!("/2015-03-31/functions/{}/invocations", resource_id),
-                Some("{}")
-            ).await?;
+        ("ec2", "delete_snapshot") => {
+            clients.http.query_request("ec2", "DeleteSnapshot", &[
+                ("SnapshotId", resource_id)
+            ]).await?;
+            Ok(())
+        }
+
+        ("ec2", "deregister_image") => {
+            clients.http.query_request("ec2", "DeregisterImage", &[
+                ("ImageId", resource_id)
+            ]).await?;
             Ok(())
         }
+
+        // Lambda Actions
         ("lambda", "delete_function") => {
             clients.http.rest_json_request(
                 "lambda",
@@ -170,6 +301,12 @@ pub async fn execute_action(
             ]).await?;
             Ok(())
         }
+        ("rds", "delete_db_snapshot") => {
+            clients.http.query_request("rds", "DeleteDBSnapshot", &[
+                ("DBSnapshotIdentifier", resource_id)
+            ]).await?;
+            Ok(())
+        }
 
         // ECS Actions
         ("ecs", "delete_cluster") => {
@@ -326,6 +463,104 @@ pub async fn execute_action(
     }
 }
 
+/// Execute an action that takes a user-supplied value (e.g., tags to set)
+///
+/// Returns the action's result when there is one worth showing (e.g., a
+/// Lambda invocation's response payload).
+pub async fn execute_action_with_input(
+    service: &str,
+    action: &str,
+    clients: &AwsClients,
+    resource_id: &str,
+    input: &str,
+) -> Result<Option<Value>> {
+
+    // Write operations are not idempotent, so never retry them
+    let clients = &clients.without_retries();
+    match (service, action) {
+        ("ec2", "create_tags") => {
+            let tags = parse_tag_pairs(input)?;
+            let mut params: Vec<(String, String)> = vec![("ResourceId.1".to_string(), resource_id.to_string())];
+            for (i, (key, value)) in tags.iter().enumerate() {
+                params.push((format!("Tag.{}.Key", i + 1), key.clone()));
+                params.push((format!("Tag.{}.Value", i + 1), value.clone()));
+            }
+            let params: Vec<(&str, &str)> = params.iter().map(|(k, v)| (k.as_str(), v.as_str())).collect();
+            clients.http.query_request("ec2", "CreateTags", &params).await?;
+            Ok(None)
+        }
+        ("lambda", "invoke_function") => {
+            let payload = match input.trim() {
+                "" => "{}",
+                payload => payload,
+            };
+            serde_json::from_str::<Value>(payload)
+                .map_err(|e| anyhow!("Payload is not valid JSON: {}", e))?;
+
+            let response = clients.http.rest_json_request_with_headers(
+                "lambda",
+                "POST",
+                &format!("/2015-03-31/functions/{}/invocations", resource_id),
+                payload,
+            ).await?;
+
+            // Errors raised by the function still return 200; the header says what happened
+            let header = |name: &str| response.headers.get(name).and_then(|v| v.to_str().ok());
+            let function_error = header("x-amz-function-error");
+            let outcome = match function_error {
+                None => "Success",
+                Some("Handled") => "Handled error",
+                Some(_) => "Unhandled error",
+            };
+            let returned = serde_json::from_str::<Value>(&response.body)
+                .unwrap_or_else(|_| Value::String(response.body.clone()));
+
+            Ok(Some(json!({
+                "FunctionName": resource_id,
+                "StatusCode": response.status,
+                "Result": outcome,
+                "FunctionError": function_error,
+                "ExecutedVersion": header("x-amz-executed-version"),
+                "Payload": returned,
+            })))
+        }
+        ("ssm", "put_parameter") => {
+            // Overwrite keeps the parameter's type, so SecureStrings stay encrypted
+            let request_body = json!({
+                "Name": resource_id,
+                "Value": input,
+                "Overwrite": true,
+            }).to_string();
+            clients.http.json_request_sensitive("ssm", "PutParameter", &request_body).await?;
+            Ok(None)
+        }
+        _ => Err(anyhow!("Unknown action: {}.{}", service, action)),
+    }
+}
+
+/// Parse "key=value" pairs separated by commas
+fn parse_tag_pairs(input: &str) -> Result<Vec<(String, String)>> {
+    let tags: Vec<(String, String)> = input
+        .split(',')
+        .filter(|pair| !pair.trim().is_empty())
+        .map(|pair| {
+            let (key, value) = pair
+                .split_once('=')
+                .ok_or_else(|| anyhow!("Expected key=value, got '{}'", pair.trim()))?;
+            let key = key.trim();
+            if key.is_empty() {
+                return Err(anyhow!("Tag key cannot be empty"));
+            }
+            Ok((key.to_string(), value.trim().to_string()))
+        })
+        .collect::<Result<_>>()?;
+
+    if tags.is_empty() {
+        return Err(anyhow!("Expected key=value"));
+    }
+    Ok(tags)
+}
+
 // =============================================================================
 // Describe Functions (single resource details)
 // =============================================================================
@@ -336,6 +571,7 @@ pub async fn describe_resource(
     clients: &AwsClients,
     resource_id: &str,
 ) -> Result<Value> {
+
     tracing::debug!("Describing resource: {} with id: {}", resource_key, resource_id);
     
     match resource_key {
@@ -639,6 +875,88 @@ pub async fn invoke_sdk(
             Ok(json!({ "policies": result }))
         }
 
+        ("iam", "get_policy_version") => {
+            // The policy names its default version; the document belongs to the version
+            let policy_arn = extract_param(params, "policy_arn");
+            let xml = clients.http.query_request("iam", "GetPolicy", &[
+                ("PolicyArn", &policy_arn)
+            ]).await?;
+            let json = xml_to_json(&xml)?;
+            let policy = json.pointer("/GetPolicyResponse/GetPolicyResult/Policy")
+                .ok_or_else(|| anyhow!("IAM policy not found"))?;
+            let version_id = policy.get("DefaultVersionId").and_then(|v| v.as_str())
+                .ok_or_else(|| anyhow!("IAM policy {} has no default version", policy_arn))?;
+
+            let xml = clients.http.query_request("iam", "GetPolicyVersion", &[
+                ("PolicyArn", &policy_arn),
+                ("VersionId", version_id),
+            ]).await?;
+            let json = xml_to_json(&xml)?;
+            let document = json.pointer("/GetPolicyVersionResponse/GetPolicyVersionResult/PolicyVersion/Document")
+                .and_then(|v| v.as_str())
+                .unwrap_or_default();
+
+            Ok(json!({
+                "PolicyName": policy.get("PolicyName").and_then(|v| v.as_str()).unwrap_or("-"),
+                "Arn": policy_arn,
+                "Description": policy.get("Description").and_then(|v| v.as_str()).unwrap_or("-"),
+                "DefaultVersionId": version_id,
+                "AttachmentCount": policy.get("AttachmentCount").and_then(|v| v.as_str()).unwrap_or("0"),
+                "UpdateDate": policy.get("UpdateDate").and_then(|v| v.as_str()).unwrap_or("-"),
+                "PolicyDocument": decode_policy_document(document),
+            }))
+        }
+
+        ("iam", "list_user_policies") | ("iam", "list_role_policies") => {
+            // Inline policies are listed by name only; the document comes from get_*_policy
+            let (operation, param, owner_field) = match method {
+                "list_user_policies" => ("ListUserPolicies", "user_name", "UserName"),
+                _ => ("ListRolePolicies", "role_name", "RoleName"),
+            };
+            let owner = extract_param(params, param);
+            let xml = clients.http.query_request("iam", operation, &[
+                (owner_field, &owner)
+            ]).await?;
+            let json = xml_to_json(&xml)?;
+
+            let names: Vec<String> = match json.pointer(&format!("/{0}Response/{0}Result/PolicyNames/member", operation)) {
+                Some(Value::Array(arr)) => arr.iter().filter_map(|n| n.as_str().map(String::from)).collect(),
+                Some(Value::String(name)) => vec![name.clone()],
+                _ => vec![],
+            };
+            let result: Vec<Value> = names.iter().map(|name| {
+                json!({
+                    "PolicyName": name,
+                    owner_field: owner,
+                })
+            }).collect();
+
+            Ok(json!({ "inline_policies": result }))
+        }
+
+        ("iam", "get_user_policy") | ("iam", "get_role_policy") => {
+            let (operation, param, owner_field) = match method {
+                "get_user_policy" => ("GetUserPolicy", "user_name", "UserName"),
+                _ => ("GetRolePolicy", "role_name", "RoleName"),
+            };
+            let owner = extract_param(params, param);
+            let policy_name = extract_param(params, "policy_name");
+            let xml = clients.http.query_request("iam", operation, &[
+                (owner_field, &owner),
+                ("PolicyName", &policy_name),
+            ]).await?;
+            let json = xml_to_json(&xml)?;
+            let document = json.pointer(&format!("/{0}Response/{0}Result/PolicyDocument", operation))
+                .and_then(|v| v.as_str())
+                .unwrap_or_default();
+
+            Ok(json!({
+                "PolicyName": policy_name,
+                owner_field: owner,
+                "PolicyDocument": decode_policy_document(document),
+            }))
+        }
+
         ("iam", "list_groups") => {
             let xml = clients.http.query_request("iam", "ListGroups", &[]).await?;
             let json = xml_to_json(&xml)?;
@@ -757,42 +1075,114 @@ pub async fn invoke_sdk(
             let xml = clients.http.query_request("ec2", "DescribeInstances", &[]).await?;
             let json = xml_to_json(&xml)?;
             
-            let mut instances: Vec<Value> = Vec::new();
-            
-            // Navigate: DescribeInstancesResponse > reservationSet > item > instancesSet > item
-            if let Some(reservations) = json.pointer("/DescribeInstancesResponse/reservationSet/item") {
-                let reservation_list = match reservations {
-                    Value::Array(arr) => arr.clone(),
-                    obj @ Value::Object(_) => vec![obj.clone()],
+            // DescribeInstancesResponse > reservationSet > item > instancesSet > item.
+            // Reservations are kept as-is; response_path "reservations[].instances" flattens them
+            let reservations: Vec<Value> = extract_ec2_list(&json, "reservationSet").iter().map(|reservation| {
+                let instance_list = match reservation.pointer("/instancesSet/item") {
+                    Some(Value::Array(arr)) => arr.clone(),
+                    Some(obj @ Value::Object(_)) => vec![obj.clone()],
                     _ => vec![],
                 };
-                
-                for reservation in reservation_list {
-                    if let Some(instance_set) = reservation.pointer("/instancesSet/item") {
-                        let instance_list = match instance_set {
-                            Value::Array(arr) => arr.clone(),
-                            obj @ Value::Object(_) => vec![obj.clone()],
-                            _ => vec![],
-                        };
-                        
-                        for instance in instance_list {
-                            let tags = extract_tags(&instance);
-                            instances.push(json!({
-                                "InstanceId": instance.pointer("/instanceId").and_then(|v| v.as_str()).unwrap_or("-"),
-                                "InstanceType": instance.pointer("/instanceType").and_then(|v| v.as_str()).unwrap_or("-"),
-                                "State": instance.pointer("/instanceState/name").and_then(|v| v.as_str()).unwrap_or("-"),
-                                "AvailabilityZone": instance.pointer("/placement/availabilityZone").and_then(|v| v.as_str()).unwrap_or("-"),
-                                "PublicIpAddress": instance.pointer("/ipAddress").and_then(|v| v.as_str()).unwrap_or("-"),
-                                "PrivateIpAddress": instance.pointer("/privateIpAddress").and_then(|v| v.as_str()).unwrap_or("-"),
-                                "LaunchTime": instance.pointer("/launchTime").and_then(|v| v.as_str()).unwrap_or("-"),
-                                "Tags": tags,
-                            }));
-                        }
-                    }
-                }
-            }
+                let instances: Vec<Value> = instance_list.iter().map(|instance| {
+                    let tags = extract_tags(instance);
+                    with_raw(json!({
+                        "InstanceId": instance.pointer("/instanceId").and_then(|v| v.as_str()).unwrap_or("-"),
+                        "InstanceType": instance.pointer("/instanceType").and_then(|v| v.as_str()).unwrap_or("-"),
+                        "State": instance.pointer("/instanceState/name").and_then(|v| v.as_str()).unwrap_or("-"),
+                        "AvailabilityZone": instance.pointer("/placement/availabilityZone").and_then(|v| v.as_str()).unwrap_or("-"),
+                        "PublicIpAddress": instance.pointer("/ipAddress").and_then(|v| v.as_str()).unwrap_or("-"),
+                        "PrivateIpAddress": instance.pointer("/privateIpAddress").and_then(|v| v.as_str()).unwrap_or("-"),
+                        "LaunchTime": instance.pointer("/launchTime").and_then(|v| v.as_str()).unwrap_or("-"),
+                        "Tags": tags,
+                    }), instance)
+                }).collect();
+                json!({
+                    "ReservationId": reservation.pointer("/reservationId").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "instances": instances,
+                })
+            }).collect();
             
-            Ok(json!({ "reservations": instances }))
+            Ok(json!({ "reservations": reservations }))
+        }
+
+        ("ec2", "describe_volumes") => {
+            let mut query_params: Vec<(&str, &str)> = vec![];
+            let instance_id = extract_param(params, "instance_ids");
+            if !instance_id.is_empty() {
+                query_params.push(("Filter.1.Name", "attachment.instance-id"));
+                query_params.push(("Filter.1.Value.1", &instance_id));
+            }
+
+            let xml = clients.http.query_request("ec2", "DescribeVolumes", &query_params).await?;
+            let json = xml_to_json(&xml)?;
+
+            let volumes = extract_ec2_list(&json, "volumeSet");
+            let result: Vec<Value> = volumes.iter().map(|volume| {
+                let tags = extract_tags(volume);
+                let size = volume.pointer("/size")
+                    .and_then(|v| v.as_str())
+                    .map_or_else(|| "-".to_string(), |size| format!("{} GiB", size));
+                with_raw(json!({
+                    "VolumeId": volume.pointer("/volumeId").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "Size": size,
+                    "State": volume.pointer("/status").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "VolumeType": volume.pointer("/volumeType").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "Iops": volume.pointer("/iops").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "AvailabilityZone": volume.pointer("/availabilityZone").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "Tags": tags,
+                }), volume)
+            }).collect();
+
+            Ok(json!({ "volumes": result }))
+        }
+
+        ("ec2", "describe_snapshots") => {
+            let xml = clients.http.query_request("ec2", "DescribeSnapshots", &[
+                ("Owner.1", "self")
+            ]).await?;
+            let json = xml_to_json(&xml)?;
+
+            let snapshots = extract_ec2_list(&json, "snapshotSet");
+            let result: Vec<Value> = snapshots.iter().map(|snapshot| {
+                let tags = extract_tags(snapshot);
+                let size = snapshot.pointer("/volumeSize")
+                    .and_then(|v| v.as_str())
+                    .map_or_else(|| "-".to_string(), |size| format!("{} GiB", size));
+                with_raw(json!({
+                    "SnapshotId": snapshot.pointer("/snapshotId").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "VolumeId": snapshot.pointer("/volumeId").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "State": snapshot.pointer("/status").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "Size": size,
+                    "Description": snapshot.pointer("/description").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "StartTime": snapshot.pointer("/startTime").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "Tags": tags,
+                }), snapshot)
+            }).collect();
+
+            Ok(json!({ "snapshots": result }))
+        }
+
+        ("ec2", "describe_images") => {
+            let xml = clients.http.query_request("ec2", "DescribeImages", &[
+                ("Owner.1", "self")
+            ]).await?;
+            let json = xml_to_json(&xml)?;
+
+            let images = extract_ec2_list(&json, "imagesSet");
+            let result: Vec<Value> = images.iter().map(|image| {
+                let tags = extract_tags(image);
+                with_raw(json!({
+                    "ImageId": image.pointer("/imageId").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "Name": image.pointer("/name").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "State": image.pointer("/imageState").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "Architecture": image.pointer("/architecture").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "Platform": image.pointer("/platformDetails").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "CreationDate": image.pointer("/creationDate").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "Tags": tags,
+                }), image)
+            }).collect();
+
+            Ok(json!({ "images": result }))
         }
 
         ("ec2", "describe_vpcs") => {
@@ -802,14 +1192,14 @@ pub async fn invoke_sdk(
             let vpcs = extract_ec2_list(&json, "vpcSet");
             let result: Vec<Value> = vpcs.iter().map(|vpc| {
                 let tags = extract_tags(vpc);
-                json!({
+                with_raw(json!({
                     "VpcId": vpc.pointer("/vpcId").and_then(|v| v.as_str()).unwrap_or("-"),
                     "State": vpc.pointer("/state").and_then(|v| v.as_str()).unwrap_or("-"),
                     "CidrBlock": vpc.pointer("/cidrBlock").and_then(|v| v.as_str()).unwrap_or("-"),
                     "IsDefault": if vpc.pointer("/isDefault").and_then(|v| v.as_str()) == Some("true") { "Yes" } else { "No" },
                     "InstanceTenancy": vpc.pointer("/instanceTenancy").and_then(|v| v.as_str()).unwrap_or("-"),
                     "Tags": tags,
-                })
+                }), vpc)
             }).collect();
             
             Ok(json!({ "vpcs": result }))
@@ -833,7 +1223,7 @@ pub async fn invoke_sdk(
             let subnets = extract_ec2_list(&json, "subnetSet");
             let result: Vec<Value> = subnets.iter().map(|subnet| {
                 let tags = extract_tags(subnet);
-                json!({
+                with_raw(json!({
                     "SubnetId": subnet.pointer("/subnetId").and_then(|v| v.as_str()).unwrap_or("-"),
                     "VpcId": subnet.pointer("/vpcId").and_then(|v| v.as_str()).unwrap_or("-"),
                     "State": subnet.pointer("/state").and_then(|v| v.as_str()).unwrap_or("-"),
@@ -841,7 +1231,7 @@ pub async fn invoke_sdk(
                     "AvailabilityZone": subnet.pointer("/availabilityZone").and_then(|v| v.as_str()).unwrap_or("-"),
                     "AvailableIpAddressCount": subnet.pointer("/availableIpAddressCount").and_then(|v| v.as_str()).unwrap_or("0"),
                     "Tags": tags,
-                })
+                }), subnet)
             }).collect();
             
             Ok(json!({ "subnets": result }))
@@ -864,13 +1254,15 @@ pub async fn invoke_sdk(
             
             let groups = extract_ec2_list(&json, "securityGroupInfo");
             let result: Vec<Value> = groups.iter().map(|sg| {
-                json!({
+                with_raw(json!({
                     "GroupId": sg.pointer("/groupId").and_then(|v| v.as_str()).unwrap_or("-"),
                     "GroupName": sg.pointer("/groupName").and_then(|v| v.as_str()).unwrap_or("-"),
                     "VpcId": sg.pointer("/vpcId").and_then(|v| v.as_str()).unwrap_or("-"),
                     "Description": sg.pointer("/groupDescription").and_then(|v| v.as_str()).unwrap_or("-"),
                     "OwnerId": sg.pointer("/ownerId").and_then(|v| v.as_str()).unwrap_or("-"),
-                })
+                    // Listed by the security-group-rules sub-resource
+                    "Rules": security_group_rules(sg),
+                }), sg)
             }).collect();
             
             Ok(json!({ "security_groups": result }))
@@ -1023,14 +1415,14 @@ pub async fn invoke_sdk(
             
             let instances = extract_rds_list(&json, "DBInstances", "DBInstance");
             let result: Vec<Value> = instances.iter().map(|db| {
-                json!({
+                with_raw(json!({
                     "DBInstanceIdentifier": db.pointer("/DBInstanceIdentifier").and_then(|v| v.as_str()).unwrap_or("-"),
                     "DBInstanceStatus": db.pointer("/DBInstanceStatus").and_then(|v| v.as_str()).unwrap_or("-"),
                     "Engine": db.pointer("/Engine").and_then(|v| v.as_str()).unwrap_or("-"),
                     "DBInstanceClass": db.pointer("/DBInstanceClass").and_then(|v| v.as_str()).unwrap_or("-"),
                     "AvailabilityZone": db.pointer("/AvailabilityZone").and_then(|v| v.as_str()).unwrap_or("-"),
                     "Endpoint": db.pointer("/Endpoint/Address").and_then(|v| v.as_str()).unwrap_or("-"),
-                })
+                }), db)
             }).collect();
             
             Ok(json!({ "db_instances": result }))
@@ -1048,7 +1440,7 @@ pub async fn invoke_sdk(
             
             let snapshots = extract_rds_list(&json, "DBSnapshots", "DBSnapshot");
             let result: Vec<Value> = snapshots.iter().map(|snap| {
-                json!({
+                with_raw(json!({
                     "DBSnapshotIdentifier": snap.pointer("/DBSnapshotIdentifier").and_then(|v| v.as_str()).unwrap_or("-"),
                     "DBInstanceIdentifier": snap.pointer("/DBInstanceIdentifier").and_then(|v| v.as_str()).unwrap_or("-"),
                     "Status": snap.pointer("/Status").and_then(|v| v.as_str()).unwrap_or("-"),
@@ -1056,7 +1448,7 @@ pub async fn invoke_sdk(
                     "Engine": snap.pointer("/Engine").and_then(|v| v.as_str()).unwrap_or("-"),
                     "AllocatedStorage": snap.pointer("/AllocatedStorage").and_then(|v| v.as_str()).unwrap_or("0"),
                     "SnapshotCreateTime": snap.pointer("/SnapshotCreateTime").and_then(|v| v.as_str()).unwrap_or("-"),
-                })
+                }), snap)
             }).collect();
             
             Ok(json!({ "db_snapshots": result }))
@@ -1065,18 +1457,108 @@ pub async fn invoke_sdk(
         // =====================================================================
         // DynamoDB Operations (JSON protocol)
         // =====================================================================
-        ("dynamodb", "list_tables") => {
-            let response = clients.http.json_request("dynamodb", "ListTables", "{}").await?;
+        ("dynamodb", "list_tables_with_details") => {
+            let page_token = params.get("_page_token").and_then(|v| v.as_str());
+            let request_body = if let Some(token) = page_token {
+                json!({ "ExclusiveStartTableName": token, "Limit": 100 }).to_string()
+            } else {
+                json!({ "Limit": 100 }).to_string()
+            };
+
+            let response = clients.http.json_request("dynamodb", "ListTables", &request_body).await?;
             let json: Value = serde_json::from_str(&response)?;
-            
-            let tables = json.get("TableNames").and_then(|v| v.as_array()).cloned().unwrap_or_default();
-            let result: Vec<Value> = tables.iter().map(|name| {
-                json!({
-                    "TableName": name.as_str().unwrap_or("-"),
+            let names: Vec<String> = json.get("TableNames")
+                .and_then(|v| v.as_array())
+                .map(|arr| arr.iter().filter_map(|n| n.as_str().map(String::from)).collect())
+                .unwrap_or_default();
+
+            // Describe tables concurrently; `buffered` keeps the list order
+            clients.progress.add_total(names.len());
+            let tables: Vec<Value> = stream::iter(names)
+                .map(|name| async move {
+                    let desc = clients.http.json_request(
+                        "dynamodb",
+                        "DescribeTable",
+                        &json!({ "TableName": name }).to_string()
+                    ).await;
+                    let table = desc.ok()
+                        .and_then(|r| serde_json::from_str::<Value>(&r).ok())
+                        .and_then(|j| j.get("Table").cloned());
+                    match table {
+                        Some(t) => with_raw(json!({
+                            "TableName": name,
+                            "TableStatus": t.get("TableStatus").and_then(|v| v.as_str()).unwrap_or("-"),
+                            "ItemCount": t.get("ItemCount").and_then(|v| v.as_u64()).unwrap_or(0),
+                            "TableSize": format_bytes(t.get("TableSizeBytes").and_then(|v| v.as_u64()).unwrap_or(0)),
+                            "BillingMode": t.pointer("/BillingModeSummary/BillingMode").and_then(|v| v.as_str()).unwrap_or("PROVISIONED"),
+                            "IndexCount": t.get("GlobalSecondaryIndexes").and_then(|v| v.as_array()).map(|a| a.len()).unwrap_or(0),
+                        }), &t),
+                        // Keep the row even if describe fails (e.g., no DescribeTable permission)
+                        None => json!({ "TableName": name }),
+                    }
                 })
-            }).collect();
-            
-            Ok(json!({ "table_names": result }))
+                .buffered(clients.max_concurrency)
+                .inspect(|_| clients.progress.advance(1))
+                .collect()
+                .await;
+
+            let mut response = json!({ "tables": tables });
+            if let Some(token) = json.get("LastEvaluatedTableName").and_then(|v| v.as_str()) {
+                response["_next_token"] = json!(token);
+            }
+            Ok(response)
+        }
+
+        ("dynamodb", "describe_table") => {
+            let table_name = extract_param(params, "table_name");
+            if table_name.is_empty() {
+                return Ok(json!({ "keys": [] }));
+            }
+
+            let response = clients.http.json_request(
+                "dynamodb",
+                "DescribeTable",
+                &json!({ "TableName": table_name }).to_string()
+            ).await?;
+            let json: Value = serde_json::from_str(&response)?;
+            let table = json.get("Table").cloned().unwrap_or(Value::Null);
+
+            // Key schema entries name their attributes by role
+            let key_attr = |schema: Option<&Value>, key_type: &str| -> String {
+                schema.and_then(|v| v.as_array())
+                    .and_then(|arr| arr.iter().find(|k| k.get("KeyType").and_then(|v| v.as_str()) == Some(key_type)))
+                    .and_then(|k| k.get("AttributeName").and_then(|v| v.as_str()))
+                    .unwrap_or("-")
+                    .to_string()
+            };
+
+            let mut rows = vec![json!({
+                "Name": table_name,
+                "Type": "TABLE",
+                "PartitionKey": key_attr(table.get("KeySchema"), "HASH"),
+                "SortKey": key_attr(table.get("KeySchema"), "RANGE"),
+                "Status": table.get("TableStatus").and_then(|v| v.as_str()).unwrap_or("-"),
+                "ItemCount": table.get("ItemCount").and_then(|v| v.as_u64()).unwrap_or(0),
+                "Projection": "-",
+            })];
+
+            for (field, index_type) in [("GlobalSecondaryIndexes", "GSI"), ("LocalSecondaryIndexes", "LSI")] {
+                let indexes = table.get(field).and_then(|v| v.as_array()).cloned().unwrap_or_default();
+                rows.extend(indexes.iter().map(|idx| json!({
+                    "Name": idx.get("IndexName").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "Type": index_type,
+                    "PartitionKey": key_attr(idx.get("KeySchema"), "HASH"),
+                    "SortKey": key_attr(idx.get("KeySchema"), "RANGE"),
+                    // LSIs share the table's status
+                    "Status": idx.get("IndexStatus").and_then(|v| v.as_str())
+                        .or_else(|| table.get("TableStatus").and_then(|v| v.as_str()))
+                        .unwrap_or("-"),
+                    "ItemCount": idx.get("ItemCount").and_then(|v| v.as_u64()).unwrap_or(0),
+                    "Projection": idx.pointer("/Projection/ProjectionType").and_then(|v| v.as_str()).unwrap_or("-"),
+                })));
+            }
+
+            Ok(json!({ "keys": rows }))
         }
 
         // =====================================================================
@@ -1145,16 +1627,49 @@ pub async fn invoke_sdk(
             let list_json: Value = serde_json::from_str(&list_response)?;
             let service_arns = list_json.get("serviceArns").and_then(|v| v.as_array()).cloned().unwrap_or_default();
             
-            // Parse service name from ARN: arn:aws:ecs:region:account:service/cluster/service-name
-            let result: Vec<Value> = service_arns.iter().filter_map(|arn| {
-                let arn_str = arn.as_str()?;
-                let service_name = arn_str.split('/').last().unwrap_or("-");
-                Some(json!({
-                    "serviceArn": arn_str,
-                    "serviceName": service_name,
-                    "clusterArn": cluster,
-                }))
-            }).collect();
+            // DescribeServices takes up to 10 services per call; `buffered` keeps the list order
+            let arns: Vec<String> = service_arns.iter().filter_map(|a| a.as_str().map(String::from)).collect();
+            let cluster_ref = &cluster;
+            let arn_batches: Vec<Vec<String>> = arns.chunks(10).map(<[String]>::to_vec).collect();
+            clients.progress.add_total(arns.len());
+            let batches: Vec<Vec<Value>> = stream::iter(arn_batches)
+                .map(|batch| async move {
+                    let described = clients.http.json_request("ecs", "DescribeServices", &json!({
+                        "cluster": cluster_ref,
+                        "services": batch,
+                    }).to_string()).await.ok()
+                        .and_then(|r| serde_json::from_str::<Value>(&r).ok())
+                        .and_then(|j| j.get("services").and_then(|v| v.as_array()).cloned())
+                        .unwrap_or_default();
+
+                    batch.iter().map(|arn| {
+                        // Parse service name from ARN: arn:aws:ecs:region:account:service/cluster/service-name
+                        let service_name = arn.split('/').next_back().unwrap_or("-");
+                        let row = json!({
+                            "serviceArn": arn,
+                            "serviceName": service_name,
+                            "clusterArn": cluster_ref,
+                        });
+                        match described.iter().find(|s| s.get("serviceArn").and_then(|v| v.as_str()) == Some(arn.as_str())) {
+                            Some(s) => {
+                                let mut row = row;
+                                row["status"] = json!(s.get("status").and_then(|v| v.as_str()).unwrap_or("-"));
+                                row["runningCount"] = json!(s.get("runningCount").and_then(|v| v.as_u64()).unwrap_or(0));
+                                row["desiredCount"] = json!(s.get("desiredCount").and_then(|v| v.as_u64()).unwrap_or(0));
+                                row["pendingCount"] = json!(s.get("pendingCount").and_then(|v| v.as_u64()).unwrap_or(0));
+                                row["launchType"] = json!(s.get("launchType").and_then(|v| v.as_str()).unwrap_or("-"));
+                                with_raw(row, s)
+                            }
+                            // Keep the row even if describe fails (e.g., no DescribeServices permission)
+                            None => row,
+                        }
+                    }).collect()
+                })
+                .buffered(clients.max_concurrency)
+                .inspect(|batch: &Vec<Value>| clients.progress.advance(batch.len()))
+                .collect()
+                .await;
+            let result: Vec<Value> = batches.into_iter().flatten().collect();
             
             // Include next_token in response for pagination
             let next_token = list_json.get("nextToken").and_then(|v| v.as_str());
@@ -1233,6 +1748,8 @@ pub async fn invoke_sdk(
                     "cpu": t.get("cpu").and_then(|v| v.as_str()).unwrap_or("-"),
                     "memory": t.get("memory").and_then(|v| v.as_str()).unwrap_or("-"),
                     "clusterArn": t.get("clusterArn").and_then(|v| v.as_str()).unwrap_or("-"),
+                    // Listed by the ecs-task-containers sub-resource without another call
+                    "containers": t.get("containers").cloned().unwrap_or_else(|| json!([])),
                 })
             }).collect();
             
@@ -1249,24 +1766,54 @@ pub async fn invoke_sdk(
         // =====================================================================
         // SQS Operations (Query protocol)
         // =====================================================================
-        ("sqs", "list_queues") => {
+        ("sqs", "list_queues_with_details") => {
             let xml = clients.http.query_request("sqs", "ListQueues", &[]).await?;
             let json = xml_to_json(&xml)?;
-            
-            let queue_urls = json.pointer("/ListQueuesResponse/ListQueuesResult/QueueUrl");
-            let queue_list = match queue_urls {
-                Some(Value::Array(arr)) => arr.clone(),
-                Some(Value::String(s)) => vec![Value::String(s.clone())],
+
+            let urls: Vec<String> = match json.pointer("/ListQueuesResponse/ListQueuesResult/QueueUrl") {
+                Some(Value::Array(arr)) => arr.iter().filter_map(|u| u.as_str().map(String::from)).collect(),
+                Some(Value::String(s)) => vec![s.clone()],
                 _ => vec![],
             };
-            
-            let result: Vec<Value> = queue_list.iter().map(|url| {
-                json!({
-                    "QueueUrl": url.as_str().unwrap_or("-"),
+
+            // Fetch attributes concurrently; `buffered` keeps the list order
+            clients.progress.add_total(urls.len());
+            let queues: Vec<Value> = stream::iter(urls)
+                .map(|url| async move {
+                    let name = url.rsplit('/').next().unwrap_or(&url).to_string();
+                    let attrs = clients.http.query_request("sqs", "GetQueueAttributes", &[
+                        ("QueueUrl", url.as_str()),
+                        ("AttributeName.1", "All"),
+                    ]).await.ok().and_then(|xml| xml_to_json(&xml).ok());
+
+                    let attr_list = match attrs.as_ref().and_then(|j| j.pointer("/GetQueueAttributesResponse/GetQueueAttributesResult/Attribute")) {
+                        Some(Value::Array(arr)) => arr.clone(),
+                        Some(obj @ Value::Object(_)) => vec![obj.clone()],
+                        _ => vec![],
+                    };
+                    let attr = |key: &str| -> String {
+                        attr_list.iter()
+                            .find(|a| a.get("Name").and_then(|v| v.as_str()) == Some(key))
+                            .and_then(|a| a.get("Value").and_then(|v| v.as_str()))
+                            .unwrap_or("-")
+                            .to_string()
+                    };
+
+                    json!({
+                        "QueueUrl": url,
+                        "QueueName": name,
+                        "Type": if name.ends_with(".fifo") { "FIFO" } else { "Standard" },
+                        "Messages": attr("ApproximateNumberOfMessages"),
+                        "InFlight": attr("ApproximateNumberOfMessagesNotVisible"),
+                        "Delayed": attr("ApproximateNumberOfMessagesDelayed"),
+                    })
                 })
-            }).collect();
-            
-            Ok(json!({ "queue_urls": result }))
+                .buffered(clients.max_concurrency)
+                .inspect(|_| clients.progress.advance(1))
+                .collect()
+                .await;
+
+            Ok(json!({ "queues": queues }))
         }
 
         // =====================================================================
@@ -1320,6 +1867,86 @@ pub async fn invoke_sdk(
             Ok(json!({ "stacks": result }))
         }
 
+        ("cloudformation", "list_stack_resources") => {
+            let stack = extract_param(params, "stack_name");
+            if stack.is_empty() {
+                return Ok(json!({ "resources": [] }));
+            }
+
+            // DescribeStackResources stops at 100 resources; ListStackResources pages through all of them
+            let mut query_params: Vec<(&str, &str)> = vec![("StackName", &stack)];
+            let page_token = params.get("_page_token").and_then(|v| v.as_str());
+            if let Some(token) = page_token {
+                query_params.push(("NextToken", token));
+            }
+            let xml = clients.http.query_request("cloudformation", "ListStackResources", &query_params).await?;
+            let json = xml_to_json(&xml)?;
+
+            let result_json = json.pointer("/ListStackResourcesResponse/ListStackResourcesResult");
+            let resource_list = match result_json.and_then(|r| r.pointer("/StackResourceSummaries/member")) {
+                Some(Value::Array(arr)) => arr.clone(),
+                Some(obj @ Value::Object(_)) => vec![obj.clone()],
+                _ => vec![],
+            };
+
+            let result: Vec<Value> = resource_list.iter().map(|r| {
+                with_raw(json!({
+                    "LogicalResourceId": r.pointer("/LogicalResourceId").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "PhysicalResourceId": r.pointer("/PhysicalResourceId").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "ResourceType": r.pointer("/ResourceType").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "ResourceStatus": r.pointer("/ResourceStatus").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "ResourceStatusReason": r.pointer("/ResourceStatusReason").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "Timestamp": r.pointer("/LastUpdatedTimestamp").and_then(|v| v.as_str()).unwrap_or("-"),
+                }), r)
+            }).collect();
+
+            let mut response = json!({ "resources": result });
+            if let Some(token) = result_json.and_then(|r| r.pointer("/NextToken")).and_then(|v| v.as_str()) {
+                response["_next_token"] = json!(token);
+            }
+            Ok(response)
+        }
+
+        ("cloudformation", "describe_stack_events") => {
+            let stack = extract_param(params, "stack_name");
+            if stack.is_empty() {
+                return Ok(json!({ "events": [] }));
+            }
+
+            let mut query_params: Vec<(&str, &str)> = vec![("StackName", &stack)];
+            let page_token = params.get("_page_token").and_then(|v| v.as_str());
+            if let Some(token) = page_token {
+                query_params.push(("NextToken", token));
+            }
+            let xml = clients.http.query_request("cloudformation", "DescribeStackEvents", &query_params).await?;
+            let json = xml_to_json(&xml)?;
+
+            let result_json = json.pointer("/DescribeStackEventsResponse/DescribeStackEventsResult");
+            let event_list = match result_json.and_then(|r| r.pointer("/StackEvents/member")) {
+                Some(Value::Array(arr)) => arr.clone(),
+                Some(obj @ Value::Object(_)) => vec![obj.clone()],
+                _ => vec![],
+            };
+
+            // Newest first, as CloudFormation returns them
+            let result: Vec<Value> = event_list.iter().map(|e| {
+                json!({
+                    "EventId": e.pointer("/EventId").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "Timestamp": e.pointer("/Timestamp").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "LogicalResourceId": e.pointer("/LogicalResourceId").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "ResourceType": e.pointer("/ResourceType").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "ResourceStatus": e.pointer("/ResourceStatus").and_then(|v| v.as_str()).unwrap_or("-"),
+                    "ResourceStatusReason": e.pointer("/ResourceStatusReason").and_then(|v| v.as_str()).unwrap_or("-"),
+                })
+            }).collect();
+
+            let mut response = json!({ "events": result });
+            if let Some(token) = result_json.and_then(|r| r.pointer("/NextToken")).and_then(|v| v.as_str()) {
+                response["_next_token"] = json!(token);
+            }
+            Ok(response)
+        }
+
         // =====================================================================
         // CloudWatch Logs Operations (JSON protocol)
         // =====================================================================
@@ -1371,11 +1998,11 @@ pub async fn invoke_sdk(
                 // Format timestamps as human-readable dates
                 let last_event = ls.get("lastEventTimestamp")
                     .and_then(|v| v.as_i64())
-                    .map(|ts| format_epoch_millis(ts))
+                    .map(format_epoch_millis)
                     .unwrap_or("-".to_string());
                 let first_event = ls.get("firstEventTimestamp")
                     .and_then(|v| v.as_i64())
-                    .map(|ts| format_epoch_millis(ts))
+                    .map(format_epoch_millis)
                     .unwrap_or("-".to_string());
                     
                 json!({
@@ -1437,6 +2064,67 @@ pub async fn invoke_sdk(
             }))
         }
 
+        // =====================================================================
+        // CloudWatch Metrics Operations (Query protocol)
+        // =====================================================================
+        ("cloudwatch", "get_metric_data") => {
+            let namespace = extract_param(params, "namespace");
+            let metric_name = extract_param(params, "metric_name");
+            let dimension_name = extract_param(params, "dimension_name");
+            let dimension_value = extract_param(params, "dimension_value");
+            let stat = match extract_param(params, "stat") {
+                s if s.is_empty() => "Average".to_string(),
+                s => s,
+            };
+            let period = params.get("period").and_then(|v| v.as_i64()).unwrap_or(300).to_string();
+            let minutes = params.get("minutes").and_then(|v| v.as_i64()).unwrap_or(60);
+
+            let end = chrono::Utc::now();
+            let start = end - chrono::Duration::minutes(minutes);
+            let start_time = start.format("%Y-%m-%dT%H:%M:%SZ").to_string();
+            let end_time = end.format("%Y-%m-%dT%H:%M:%SZ").to_string();
+
+            let xml = clients.http.query_request("cloudwatch", "GetMetricData", &[
+                ("MetricDataQueries.member.1.Id", "m1"),
+                ("MetricDataQueries.member.1.MetricStat.Metric.Namespace", &namespace),
+                ("MetricDataQueries.member.1.MetricStat.Metric.MetricName", &metric_name),
+                ("MetricDataQueries.member.1.MetricStat.Metric.Dimensions.member.1.Name", &dimension_name),
+                ("MetricDataQueries.member.1.MetricStat.Metric.Dimensions.member.1.Value", &dimension_value),
+                ("MetricDataQueries.member.1.MetricStat.Period", &period),
+                ("MetricDataQueries.member.1.MetricStat.Stat", &stat),
+                ("StartTime", &start_time),
+                ("EndTime", &end_time),
+                ("ScanBy", "TimestampAscending"),
+            ]).await?;
+            let json = xml_to_json(&xml)?;
+
+            let result = match json.pointer("/GetMetricDataResponse/GetMetricDataResult/MetricDataResults/member") {
+                Some(Value::Array(arr)) => arr.first().cloned(),
+                Some(obj @ Value::Object(_)) => Some(obj.clone()),
+                _ => None,
+            };
+            let as_list = |path: &str| -> Vec<Value> {
+                match result.as_ref().and_then(|r| r.pointer(path)) {
+                    Some(Value::Array(arr)) => arr.clone(),
+                    Some(Value::String(s)) => vec![Value::String(s.clone())],
+                    _ => vec![],
+                }
+            };
+
+            let datapoints: Vec<Value> = as_list("/Timestamps/member")
+                .iter()
+                .zip(as_list("/Values/member").iter())
+                .map(|(ts, value)| {
+                    json!({
+                        "Timestamp": ts.as_str().unwrap_or("-"),
+                        "Value": value.as_str().and_then(|v| v.parse::<f64>().ok()).unwrap_or(0.0),
+                    })
+                })
+                .collect();
+
+            Ok(json!({ "datapoints": datapoints }))
+        }
+
         // =====================================================================
         // Secrets Manager Operations (JSON protocol)
         // =====================================================================
@@ -1508,6 +2196,20 @@ pub async fn invoke_sdk(
             
             Ok(response)
         }
+        ("ssm", "get_parameter") => {
+            // Never decrypted here: SecureString values stay masked until revealed
+            let name = params.get("name").and_then(|v| v.as_str())
+                .ok_or_else(|| anyhow!("name is required for get_parameter"))?;
+            let request_body = json!({ "Name": name, "WithDecryption": false }).to_string();
+            let response = clients.http.json_request("ssm", "GetParameter", &request_body).await?;
+            let json: Value = serde_json::from_str(&response)?;
+
+            let mut parameter = json.get("Parameter").cloned().unwrap_or_else(|| json!({}));
+            if parameter.get("Type").and_then(|v| v.as_str()) == Some("SecureString") {
+                parameter["Value"] = json!(SECURE_STRING_MASK);
+            }
+            Ok(parameter)
+        }
 
         // =====================================================================
         // EKS Operations (REST-JSON)
@@ -1521,29 +2223,32 @@ pub async fn invoke_sdk(
                 return Ok(json!({ "clusters": [] }));
             }
             
-            let mut clusters: Vec<Value> = Vec::new();
-            for name in cluster_names {
-                if let Some(name_str) = name.as_str() {
-                    if let Ok(desc_response) = clients.http.rest_json_request(
+            // Describe clusters concurrently; `buffered` keeps the list order
+            let names: Vec<String> = cluster_names.iter().filter_map(|n| n.as_str().map(String::from)).collect();
+            clients.progress.add_total(names.len());
+            let clusters: Vec<Value> = stream::iter(names)
+                .map(|name| async move {
+                    let desc_response = clients.http.rest_json_request(
                         "eks",
                         "GET",
-                        &format!("/clusters/{}", name_str),
+                        &format!("/clusters/{}", name),
                         None
-                    ).await {
-                        if let Ok(desc_json) = serde_json::from_str::<Value>(&desc_response) {
-                            if let Some(cluster) = desc_json.get("cluster") {
-                                clusters.push(json!({
-                                    "name": cluster.get("name").and_then(|v| v.as_str()).unwrap_or("-"),
-                                    "arn": cluster.get("arn").and_then(|v| v.as_str()).unwrap_or("-"),
-                                    "status": cluster.get("status").and_then(|v| v.as_str()).unwrap_or("-"),
-                                    "version": cluster.get("version").and_then(|v| v.as_str()).unwrap_or("-"),
-                                    "endpoint": cluster.get("endpoint").and_then(|v| v.as_str()).unwrap_or("-"),
-                                }));
-                            }
-                        }
-                    }
-                }
-            }
+                    ).await.ok()?;
+                    let desc_json = serde_json::from_str::<Value>(&desc_response).ok()?;
+                    let cluster = desc_json.get("cluster")?;
+                    Some(json!({
+                        "name": cluster.get("name").and_then(|v| v.as_str()).unwrap_or("-"),
+                        "arn": cluster.get("arn").and_then(|v| v.as_str()).unwrap_or("-"),
+                        "status": cluster.get("status").and_then(|v| v.as_str()).unwrap_or("-"),
+                        "version": cluster.get("version").and_then(|v| v.as_str()).unwrap_or("-"),
+                        "endpoint": cluster.get("endpoint").and_then(|v| v.as_str()).unwrap_or("-"),
+                    }))
+                })
+                .buffered(clients.max_concurrency)
+                .inspect(|_| clients.progress.advance(1))
+                .filter_map(|cluster| async move { cluster })
+                .collect()
+                .await;
             
             Ok(json!({ "clusters": clusters }))
         }
@@ -1667,27 +2372,44 @@ pub async fn invoke_sdk(
             let json: Value = serde_json::from_str(&response)?;
             
             let keys_list = json.get("Keys").and_then(|v| v.as_array()).cloned().unwrap_or_default();
-            let mut keys: Vec<Value> = Vec::new();
-            
-            for key in keys_list {
-                if let Some(key_id) = key.get("KeyId").and_then(|v| v.as_str()) {
-                    if let Ok(desc_response) = clients.http.json_request("kms", "DescribeKey", &json!({
-                        "KeyId": key_id
-                    }).to_string()).await {
-                        if let Ok(desc_json) = serde_json::from_str::<Value>(&desc_response) {
-                            if let Some(metadata) = desc_json.get("KeyMetadata") {
-                                keys.push(json!({
-                                    "KeyId": metadata.get("KeyId").and_then(|v| v.as_str()).unwrap_or("-"),
-                                    "KeyArn": metadata.get("Arn").and_then(|v| v.as_str()).unwrap_or("-"),
-                                    "KeyState": metadata.get("KeyState").and_then(|v| v.as_str()).unwrap_or("-"),
-                                    "KeyUsage": metadata.get("KeyUsage").and_then(|v| v.as_str()).unwrap_or("-"),
-                                    "KeySpec": metadata.get("KeySpec").and_then(|v| v.as_str()).unwrap_or("-"),
-                                }));
-                            }
-                        }
-                    }
+
+            // One ListAliases pass for all keys; without permission the column just stays empty
+            let aliases = match kms_aliases(clients).await {
+                Ok(aliases) => aliases,
+                Err(e) => {
+                    debug!("ListAliases failed, showing keys without aliases: {}", e);
+                    HashMap::new()
                 }
-            }
+            };
+            let aliases = &aliases;
+            
+            // Describe keys concurrently; `buffered` keeps the list order
+            let key_ids: Vec<String> = keys_list.iter()
+                .filter_map(|k| k.get("KeyId").and_then(|v| v.as_str()).map(String::from))
+                .collect();
+            clients.progress.add_total(key_ids.len());
+            let keys: Vec<Value> = stream::iter(key_ids)
+                .map(|key_id| async move {
+                    let desc_response = clients.http.json_request("kms", "DescribeKey", &json!({
+                        "KeyId": key_id
+                    }).to_string()).await.ok()?;
+                    let desc_json = serde_json::from_str::<Value>(&desc_response).ok()?;
+                    let metadata = desc_json.get("KeyMetadata")?;
+                    let alias = aliases.get(&key_id).map(|names| names.join(", "));
+                    Some(json!({
+                        "KeyId": metadata.get("KeyId").and_then(|v| v.as_str()).unwrap_or("-"),
+                        "Alias": alias.as_deref().unwrap_or("-"),
+                        "KeyArn": metadata.get("Arn").and_then(|v| v.as_str()).unwrap_or("-"),
+                        "KeyState": metadata.get("KeyState").and_then(|v| v.as_str()).unwrap_or("-"),
+                        "KeyUsage": metadata.get("KeyUsage").and_then(|v| v.as_str()).unwrap_or("-"),
+                        "KeySpec": metadata.get("KeySpec").and_then(|v| v.as_str()).unwrap_or("-"),
+                    }))
+                })
+                .buffered(clients.max_concurrency)
+                .inspect(|_| clients.progress.advance(1))
+                .filter_map(|key| async move { key })
+                .collect()
+                .await;
             
             Ok(json!({ "keys": keys }))
         }
@@ -1914,6 +2636,50 @@ pub async fn invoke_sdk(
             Ok(json!({ "work_groups": result }))
         }
 
+        // =====================================================================
+        // Cost Explorer Operations (JSON protocol)
+        // =====================================================================
+        ("ce", "get_cost_and_usage") => {
+            // Month to date; End is exclusive, so include today by ending tomorrow
+            let today = chrono::Utc::now().date_naive();
+            let start = today.format("%Y-%m-01").to_string();
+            let end = (today + chrono::Duration::days(1)).format("%Y-%m-%d").to_string();
+            let body = json!({
+                "TimePeriod": { "Start": start, "End": end },
+                "Granularity": "MONTHLY",
+                "Metrics": ["UnblendedCost"],
+                "GroupBy": [{ "Type": "DIMENSION", "Key": "SERVICE" }],
+            });
+            let response = clients.http.json_request("ce", "GetCostAndUsage", &body.to_string()).await?;
+            let json: Value = serde_json::from_str(&response)?;
+
+            let mut totals: Vec<(String, f64, String)> = Vec::new();
+            let periods = json.get("ResultsByTime").and_then(|v| v.as_array()).cloned().unwrap_or_default();
+            for group in periods.iter().filter_map(|p| p.get("Groups").and_then(|v| v.as_array())).flatten() {
+                let service = group.pointer("/Keys/0").and_then(|v| v.as_str()).unwrap_or("-");
+                let amount = group.pointer("/Metrics/UnblendedCost/Amount")
+                    .and_then(|v| v.as_str())
+                    .and_then(|s| s.parse::<f64>().ok())
+                    .unwrap_or(0.0);
+                let unit = group.pointer("/Metrics/UnblendedCost/Unit").and_then(|v| v.as_str()).unwrap_or("USD");
+                match totals.iter_mut().find(|(s, _, _)| s == service) {
+                    Some(total) => total.1 += amount,
+                    None => totals.push((service.to_string(), amount, unit.to_string())),
+                }
+            }
+            totals.sort_by(|a, b| b.1.total_cmp(&a.1));
+
+            let result: Vec<Value> = totals.iter().map(|(service, amount, unit)| {
+                json!({
+                    "Service": service,
+                    "Cost": format!("{:.2}", amount),
+                    "Unit": unit,
+                })
+            }).collect();
+
+            Ok(json!({ "costs": result }))
+        }
+
         // =====================================================================
         // ELBv2 Operations (Query protocol)
         // =====================================================================
@@ -2204,3 +2970,223 @@ fn extract_tags(resource: &Value) -> Value {
     
     Value::Object(tags)
 }
+
+/// Items of an EC2 XML set (`{ "item": ... }`), which holds a lone object when it has one entry
+fn ec2_set_items(set: Option<&Value>) -> Vec<Value> {
+    match set.and_then(|s| s.get("item")) {
+        Some(Value::Array(arr)) => arr.clone(),
+        Some(obj @ Value::Object(_)) => vec![obj.clone()],
+        _ => vec![],
+    }
+}
+
+/// One row per (permission, source) of a security group, inbound rules first
+fn security_group_rules(sg: &Value) -> Vec<Value> {
+    let mut rules = Vec::new();
+    for (direction, set_key) in [("inbound", "ipPermissions"), ("outbound", "ipPermissionsEgress")] {
+        for permission in ec2_set_items(sg.get(set_key)) {
+            let text = |key: &str| permission.get(key).and_then(|v| v.as_str()).unwrap_or("-");
+            let protocol = match text("ipProtocol") {
+                "-1" => "all",
+                other => other,
+            };
+            let ports = match (text("fromPort"), text("toPort")) {
+                ("-", _) | ("-1", _) => "all".to_string(),
+                (from, to) if from == to || to == "-" => from.to_string(),
+                (from, to) => format!("{}-{}", from, to),
+            };
+
+            // Each kind of source is its own set on the permission
+            let sources = [("ipRanges", "cidrIp"), ("ipv6Ranges", "cidrIpv6"), ("groups", "groupId"), ("prefixListIds", "prefixListId")]
+                .into_iter()
+                .flat_map(|(set, field)| {
+                    ec2_set_items(permission.get(set))
+                        .into_iter()
+                        .filter_map(move |entry| {
+                            let source = entry.get(field)?.as_str()?.to_string();
+                            let description = entry.get("description").and_then(|v| v.as_str()).unwrap_or("-").to_string();
+                            Some((source, description))
+                        })
+                });
+            for (source, description) in sources {
+                rules.push(json!({
+                    "RuleId": format!("{} {} {} {}", direction, protocol, ports, source),
+                    "Direction": direction,
+                    "Protocol": protocol,
+                    "PortRange": ports,
+                    "Source": source,
+                    "Description": description,
+                }));
+            }
+        }
+    }
+    rules
+}
+
+/// Alias names per KMS key ID, from every page of ListAliases
+async fn kms_aliases(clients: &AwsClients) -> Result<HashMap<String, Vec<String>>> {
+    let mut aliases: HashMap<String, Vec<String>> = HashMap::new();
+    let mut marker: Option<String> = None;
+    loop {
+        let request = match &marker {
+            Some(marker) => json!({ "Limit": 100, "Marker": marker }),
+            None => json!({ "Limit": 100 }),
+        };
+        let response = clients.http.json_request("kms", "ListAliases", &request.to_string()).await?;
+        let json: Value = serde_json::from_str(&response)?;
+
+        marker = add_kms_aliases(&mut aliases, &json);
+        if marker.is_none() {
+            break;
+        }
+    }
+    Ok(aliases)
+}
+
+/// Add one ListAliases page to `aliases`; the marker of the next page, if there is one
+fn add_kms_aliases(aliases: &mut HashMap<String, Vec<String>>, page: &Value) -> Option<String> {
+    for alias in page.get("Aliases").and_then(|v| v.as_array()).into_iter().flatten() {
+        // Aliases not pointing at a key yet have no TargetKeyId
+        let (Some(name), Some(key_id)) = (
+            alias.get("AliasName").and_then(|v| v.as_str()),
+            alias.get("TargetKeyId").and_then(|v| v.as_str()),
+        ) else {
+            continue;
+        };
+        aliases.entry(key_id.to_string()).or_default().push(name.to_string());
+    }
+
+    let truncated = page.get("Truncated").and_then(|v| v.as_bool()).unwrap_or(false);
+    page.get("NextMarker")
+        .and_then(|v| v.as_str())
+        .filter(|_| truncated)
+        .map(String::from)
+}
+
+/// IAM returns policy documents URL-encoded; show them as JSON (or as text if they don't parse)
+fn decode_policy_document(document: &str) -> Value {
+    let decoded = urlencoding::decode(document)
+        .map(|d| d.into_owned())
+        .unwrap_or_else(|_| document.to_string());
+    serde_json::from_str(&decoded).unwrap_or(Value::String(decoded))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_security_group_rules_flatten_each_source() {
+        // xml_to_json gives a lone <item> as an object and repeated ones as an array
+        let sg = json!({
+            "groupId": "sg-1",
+            "ipPermissions": {
+                "item": {
+                    "ipProtocol": "tcp", "fromPort": "443", "toPort": "443",
+                    "ipRanges": { "item": { "cidrIp": "0.0.0.0/0", "description": "HTTPS" } },
+                    "ipv6Ranges": { "item": { "cidrIpv6": "::/0" } }
+                }
+            },
+            "ipPermissionsEgress": {
+                "item": [
+                    {
+                        "ipProtocol": "-1",
+                        "ipRanges": { "item": [{ "cidrIp": "10.0.0.0/8" }, { "cidrIp": "172.16.0.0/12" }] }
+                    },
+                    {
+                        "ipProtocol": "udp", "fromPort": "1000", "toPort": "2000",
+                        "groups": { "item": { "groupId": "sg-2", "userId": "123456789012" } },
+                        "prefixListIds": { "item": { "prefixListId": "pl-1", "description": "S3" } }
+                    }
+                ]
+            }
+        });
+
+        let rules = security_group_rules(&sg);
+        let rows: Vec<[&str; 5]> = rules
+            .iter()
+            .map(|r| ["Direction", "Protocol", "PortRange", "Source", "Description"].map(|key| r[key].as_str().unwrap()))
+            .collect();
+        assert_eq!(rows, vec![
+            ["inbound", "tcp", "443", "0.0.0.0/0", "HTTPS"],
+            ["inbound", "tcp", "443", "::/0", "-"],
+            ["outbound", "all", "all", "10.0.0.0/8", "-"],
+            ["outbound", "all", "all", "172.16.0.0/12", "-"],
+            ["outbound", "udp", "1000-2000", "sg-2", "-"],
+            ["outbound", "udp", "1000-2000", "pl-1", "S3"],
+        ]);
+        assert_eq!(rules[0]["RuleId"], "inbound tcp 443 0.0.0.0/0");
+        assert!(security_group_rules(&json!({ "groupId": "sg-empty" })).is_empty());
+    }
+
+    #[test]
+    fn test_kms_aliases_across_pages() {
+        let mut aliases = HashMap::new();
+        let first = json!({
+            "Aliases": [
+                { "AliasName": "alias/app", "TargetKeyId": "key-1" },
+                { "AliasName": "alias/unused" },
+            ],
+            "Truncated": true,
+            "NextMarker": "page-2"
+        });
+        assert_eq!(add_kms_aliases(&mut aliases, &first).as_deref(), Some("page-2"));
+
+        let last = json!({
+            "Aliases": [
+                { "AliasName": "alias/app-backup", "TargetKeyId": "key-1" },
+                { "AliasName": "alias/db", "TargetKeyId": "key-2" },
+            ],
+            "Truncated": false,
+            "NextMarker": "ignored"
+        });
+        assert_eq!(add_kms_aliases(&mut aliases, &last), None);
+
+        assert_eq!(aliases.len(), 2);
+        assert_eq!(aliases["key-1"], vec!["alias/app", "alias/app-backup"]);
+        assert_eq!(aliases["key-2"], vec!["alias/db"]);
+    }
+
+    #[test]
+    fn test_decode_policy_document() {
+        let encoded = "%7B%22Version%22%3A%222012-10-17%22%2C%22Statement%22%3A%5B%7B%22Effect%22%3A%22Allow%22%2C\
+                       %22Action%22%3A%22s3%3AGetObject%22%2C%22Resource%22%3A%22*%22%7D%5D%7D";
+        let document = decode_policy_document(encoded);
+        assert_eq!(document["Version"], "2012-10-17");
+        assert_eq!(document["Statement"][0]["Action"], "s3:GetObject");
+
+        // Text that isn't JSON is shown as it decodes
+        assert_eq!(decode_policy_document("not%20json"), json!("not json"));
+        assert_eq!(decode_policy_document("%E0%A4%A"), json!("%E0%A4%A"));
+    }
+
+    #[test]
+    fn test_dry_run_request_covers_ec2_actions_only() {
+        assert_eq!(dry_run_request("ec2", "stop_instance"), Some(("StopInstances", "InstanceId.1")));
+        assert_eq!(dry_run_request("ec2", "delete_snapshot"), Some(("DeleteSnapshot", "SnapshotId")));
+        assert_eq!(dry_run_request("lambda", "delete_function"), None);
+    }
+
+    #[test]
+    fn test_dry_run_outcome_reads_ec2_errors() {
+        let allowed = Err(anyhow!(
+            "AWS request failed (412 Precondition Failed): <Code>DryRunOperation</Code>\
+             <Message>Request would have succeeded, but DryRun flag is set.</Message>"
+        ));
+        assert_eq!(dry_run_outcome("StopInstances", allowed, "test").unwrap(), DryRunOutcome::Allowed);
+
+        let refused = Err(anyhow!(
+            "AWS request failed (403 Forbidden): <Code>UnauthorizedOperation</Code>\
+             <Message>You are not authorized to perform this operation. User: arn:aws:iam::123456789012:user/dev \
+             is not authorized to perform: ec2:StopInstances on resource: arn:aws:ec2:eu-west-1:123456789012:instance/i-1</Message>"
+        ));
+        assert_eq!(
+            dry_run_outcome("StopInstances", refused, "test").unwrap(),
+            DryRunOutcome::Refused("Missing permission: ec2:StopInstances".to_string())
+        );
+
+        // Any other answer is not a dry run result
+        assert!(dry_run_outcome("StopInstances", Ok(String::new()), "test").is_err());
+        assert!(dry_run_outcome("StopInstances", Err(anyhow!("InvalidInstanceID.NotFound")), "test").is_err());
+    }
+}
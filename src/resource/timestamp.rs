@@ -0,0 +1,131 @@
+//! Timestamp parsing and display for date columns
+//!
+//! AWS APIs return timestamps in several shapes: RFC 3339 strings from the
+//! Query/XML services, `+0000`-style offsets from Lambda, and epoch seconds or
+//! milliseconds from the JSON services. Everything is normalised to UTC here
+//! and converted to the configured display timezone when formatted.
+
+use super::registry::ColumnFormat;
+use crate::config::DisplayTimezone;
+use chrono::{DateTime, Local, NaiveDateTime, TimeZone, Utc};
+
+/// Epoch values below this are not treated as timestamps (early 1973)
+const MIN_EPOCH_SECONDS: f64 = 1e8;
+
+/// Epoch values at or above this are taken to be milliseconds
+const EPOCH_MILLIS_THRESHOLD: f64 = 1e11;
+
+/// Format `dt` in `timezone` with a chrono `pattern`
+fn format_in(timezone: DisplayTimezone, dt: DateTime<Utc>, pattern: &str) -> String {
+    match timezone {
+        DisplayTimezone::Utc => dt.format(pattern).to_string(),
+        DisplayTimezone::Local => dt.with_timezone(&Local).format(pattern).to_string(),
+        DisplayTimezone::Named(tz) => dt.with_timezone(&tz).format(pattern).to_string(),
+    }
+}
+
+/// Parse a timestamp in any of the formats AWS responses use
+pub fn parse_timestamp(value: &str) -> Option<DateTime<Utc>> {
+    let value = value.trim();
+
+    if let Ok(dt) = DateTime::parse_from_rfc3339(value) {
+        return Some(dt.with_timezone(&Utc));
+    }
+    // Lambda style: 2024-01-15T10:30:00.000+0000
+    if let Ok(dt) = DateTime::parse_from_str(value, "%Y-%m-%dT%H:%M:%S%.f%z") {
+        return Some(dt.with_timezone(&Utc));
+    }
+    // Already formatted by the dispatcher (UTC)
+    if let Ok(naive) = NaiveDateTime::parse_from_str(value, "%Y-%m-%d %H:%M:%S") {
+        return Some(naive.and_utc());
+    }
+
+    // Epoch seconds or milliseconds
+    let number: f64 = value.parse().ok()?;
+    if !number.is_finite() || number < MIN_EPOCH_SECONDS {
+        return None;
+    }
+    let millis = if number >= EPOCH_MILLIS_THRESHOLD {
+        number
+    } else {
+        number * 1000.0
+    };
+    Utc.timestamp_millis_opt(millis as i64).single()
+}
+
+/// Format a cell value for display in `timezone`; values that aren't timestamps pass through
+pub fn format_timestamp(value: &str, format: ColumnFormat, timezone: DisplayTimezone) -> String {
+    let Some(dt) = parse_timestamp(value) else {
+        return value.to_string();
+    };
+    match format {
+        ColumnFormat::Relative => format_age(Utc::now().signed_duration_since(dt).num_seconds()),
+        ColumnFormat::Datetime => format_in(timezone, dt, "%Y-%m-%d %H:%M:%S"),
+        ColumnFormat::Date => format_in(timezone, dt, "%Y-%m-%d"),
+    }
+}
+
+/// Whole days since a timestamp, or `None` if the value isn't one
+pub fn age_days(value: &str) -> Option<i64> {
+    parse_timestamp(value).map(|dt| Utc::now().signed_duration_since(dt).num_days())
+}
+
+/// Compact age such as `45s ago`, `3d ago` or `in 2h`
+fn format_age(seconds: i64) -> String {
+    let magnitude = seconds.unsigned_abs();
+    let short = match magnitude {
+        0..60 => format!("{}s", magnitude),
+        60..3_600 => format!("{}m", magnitude / 60),
+        3_600..86_400 => format!("{}h", magnitude / 3_600),
+        86_400..31_536_000 => format!("{}d", magnitude / 86_400),
+        _ => format!("{}y", magnitude / 31_536_000),
+    };
+    if seconds < 0 {
+        format!("in {}", short)
+    } else {
+        format!("{} ago", short)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_timestamp_formats() {
+        let expected = Utc.with_ymd_and_hms(2024, 1, 15, 10, 30, 0).unwrap();
+        assert_eq!(parse_timestamp("2024-01-15T10:30:00Z"), Some(expected));
+        assert_eq!(parse_timestamp("2024-01-15T10:30:00.000+0000"), Some(expected));
+        assert_eq!(parse_timestamp("2024-01-15 10:30:00"), Some(expected));
+        assert_eq!(parse_timestamp("1705314600"), Some(expected));
+        assert_eq!(parse_timestamp("1705314600000"), Some(expected));
+        assert_eq!(parse_timestamp("1705314600.0"), Some(expected));
+        assert_eq!(parse_timestamp("-"), None);
+        assert_eq!(parse_timestamp("42"), None);
+    }
+
+    #[test]
+    fn test_format_timestamp() {
+        let utc = DisplayTimezone::Utc;
+        assert_eq!(format_timestamp("2024-01-15T10:30:00Z", ColumnFormat::Date, utc), "2024-01-15");
+        assert_eq!(
+            format_timestamp("1705314600000", ColumnFormat::Datetime, utc),
+            "2024-01-15 10:30:00"
+        );
+        assert_eq!(format_timestamp("-", ColumnFormat::Relative, utc), "-");
+        assert_eq!(format_age(30), "30s ago");
+        assert_eq!(format_age(3 * 86_400 + 5), "3d ago");
+        assert_eq!(format_age(-7_200), "in 2h");
+        assert_eq!(format_age(400 * 86_400), "1y ago");
+    }
+
+    #[test]
+    fn test_format_timestamp_in_named_timezone() {
+        let tokyo = DisplayTimezone::parse("Asia/Tokyo").unwrap();
+        assert_eq!(
+            format_timestamp("2024-01-15T20:30:00Z", ColumnFormat::Datetime, tokyo),
+            "2024-01-16 05:30:00"
+        );
+        assert_eq!(format_timestamp("2024-01-15T20:30:00Z", ColumnFormat::Date, tokyo), "2024-01-16");
+    }
+}
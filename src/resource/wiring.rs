@@ -0,0 +1,102 @@
+//! Resource wiring check
+//!
+//! Verifies that every `sdk_method` referenced by the resource JSON has a
+//! dispatch arm, and that every sub-resource points at a known resource
+//! (with a `json_path` when it is read from the parent item).
+//! Run with `taws --check-resources`.
+
+use super::registry::{get_registry, SubResourceSource};
+use super::sdk_dispatch::{execute_action, execute_action_with_input, invoke_sdk, reveal_value, reveals_value};
+use crate::aws::client::AwsClients;
+use crate::aws::credentials::Credentials;
+use crate::aws::http::AwsHttpClient;
+use serde_json::Value;
+
+/// Actions handled by the UI rather than the dispatcher
+const UI_ACTIONS: &[&str] = &["tail_logs", "show_metrics"];
+
+/// Endpoint nothing listens on, so dispatched requests fail fast without reaching AWS
+const UNREACHABLE_ENDPOINT: &str = "http://127.0.0.1:1";
+
+/// Find resource definitions whose methods or sub-resources don't resolve
+///
+/// Each method is dispatched against an unreachable endpoint: wired methods
+/// fail with a connection error (or return early), unwired ones fail with the
+/// dispatcher's "unsupported"/"unknown" error.
+pub async fn find_wiring_gaps() -> Vec<String> {
+    let credentials = Credentials {
+        access_key_id: "taws-check".to_string(),
+        secret_access_key: "taws-check".to_string(),
+        session_token: None,
+    };
+    let clients = AwsClients {
+        http: AwsHttpClient::new(credentials, "us-east-1", Some(UNREACHABLE_ENDPOINT.to_string())),
+        region: "us-east-1".to_string(),
+        profile: "taws-check".to_string(),
+        max_concurrency: 1,
+        progress: Default::default(),
+    }
+    .without_retries();
+
+    let registry = get_registry();
+    let mut keys: Vec<&String> = registry.resources.keys().collect();
+    keys.sort();
+    let parent_sourced_keys: Vec<&str> = registry
+        .resources
+        .values()
+        .flat_map(|r| &r.sub_resources)
+        .filter(|sub| sub.source == SubResourceSource::Parent)
+        .map(|sub| sub.resource_key.as_str())
+        .collect();
+
+    let mut gaps = Vec::new();
+    for key in keys {
+        let resource = &registry.resources[key];
+        let service = resource.service.as_str();
+
+        // Resources without sdk_method are only listed from a parent item
+        if resource.sdk_method.is_empty() && !parent_sourced_keys.contains(&key.as_str()) {
+            gaps.push(format!("{}: no sdk_method and no parent-sourced sub-resource lists it", key));
+        }
+        let mut methods: Vec<&str> = Some(resource.sdk_method.as_str()).filter(|m| !m.is_empty()).into_iter().collect();
+        methods.extend(resource.detail_sdk_method.as_deref());
+        for method in methods {
+            if let Err(e) = invoke_sdk(service, method, &clients, &Value::Null).await {
+                if e.to_string().starts_with("Unsupported operation") {
+                    gaps.push(format!("{}: no dispatch arm for {}.{}", key, service, method));
+                }
+            }
+        }
+
+        for action in &resource.actions {
+            if UI_ACTIONS.contains(&action.sdk_method.as_str()) {
+                continue;
+            }
+            let result = if reveals_value(service, &action.sdk_method) {
+                reveal_value(service, &action.sdk_method, &clients, "taws-check").await.map(|_| ())
+            } else if action.input.is_some() {
+                execute_action_with_input(service, &action.sdk_method, &clients, "taws-check", "")
+                    .await
+                    .map(|_| ())
+            } else {
+                execute_action(service, &action.sdk_method, &clients, "taws-check").await
+            };
+            if let Err(e) = result {
+                if e.to_string().starts_with("Unknown action") {
+                    gaps.push(format!("{}: no action arm for {}.{}", key, service, action.sdk_method));
+                }
+            }
+        }
+
+        for sub in &resource.sub_resources {
+            if !registry.resources.contains_key(&sub.resource_key) {
+                gaps.push(format!("{}: sub-resource '{}' is not defined", key, sub.resource_key));
+            }
+            if sub.source == SubResourceSource::Parent && sub.json_path.is_none() {
+                gaps.push(format!("{}: sub-resource '{}' reads from the parent but has no json_path", key, sub.resource_key));
+            }
+        }
+    }
+
+    gaps
+}
@@ -0,0 +1,36 @@
+use super::picker;
+use crate::app::App;
+use ratatui::{
+    style::Modifier,
+    text::{Line, Span},
+    Frame,
+};
+
+/// Column visibility picker (`C`): one checkbox per column of the current resource
+pub fn render(f: &mut Frame, app: &App) {
+    let Some(resource) = app.current_resource() else {
+        return;
+    };
+
+    let lines: Vec<Line> = resource
+        .columns
+        .iter()
+        .enumerate()
+        .map(|(i, col)| {
+            let hidden = app.hidden_columns.contains(&col.header);
+            let checkbox = if hidden { "[ ]" } else { "[x]" };
+            let style = if i == app.columns_selected {
+                app.theme.selected().add_modifier(Modifier::BOLD)
+            } else if hidden {
+                app.theme.muted()
+            } else {
+                app.theme.text()
+            };
+            let grouped = if app.group_by == Some(i) { "  (grouped)" } else { "" };
+            Line::from(Span::styled(format!(" {} {}{}", checkbox, col.header, grouped), style))
+        })
+        .collect();
+
+    let title = format!(" {} columns ", resource.display_name);
+    picker::render(f, app, &title, 40, lines, "Space: show/hide | g: group by | q/Esc: close");
+}
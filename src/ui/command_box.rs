@@ -1,7 +1,7 @@
-use crate::app::App;
+use crate::app::{App, Mode};
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
-    style::{Color, Modifier, Style},
+    style::Modifier,
     text::{Line, Span},
     widgets::{Block, Borders, Clear, Paragraph},
     Frame,
@@ -21,18 +21,19 @@ pub fn render(f: &mut Frame, app: &App) {
         ])
         .split(area);
 
-    // Input box - show total resource count
-    let total_count = app.get_available_commands().len();
-    let title = format!(" Resource Types ({}) ", total_count);
+    // Input box - show total resource count (or matches when quick-switching)
+    let title = if app.mode == Mode::QuickSwitch {
+        format!(" Go to Resource ({}) ", app.command_suggestions.len())
+    } else {
+        format!(" Resource Types ({}) ", app.get_available_commands().len())
+    };
     let input_block = Block::default()
         .title(title)
         .title_style(
-            Style::default()
-                .fg(Color::Cyan)
-                .add_modifier(Modifier::BOLD),
+            app.theme.title().add_modifier(Modifier::BOLD),
         )
         .borders(Borders::ALL)
-        .border_style(Style::default().fg(Color::Cyan));
+        .border_style(app.theme.title());
 
     // Build input with ghost text preview
     let input_line = if let Some(preview) = &app.command_preview {
@@ -42,20 +43,20 @@ pub fn render(f: &mut Frame, app: &App) {
             let ghost_part = &preview[typed.len()..];
             Line::from(vec![
                 Span::raw("> "),
-                Span::styled(typed, Style::default().fg(Color::White)),
-                Span::styled(ghost_part, Style::default().fg(Color::DarkGray)),
+                Span::styled(typed, app.theme.text()),
+                Span::styled(ghost_part, app.theme.muted()),
             ])
         } else {
             // Preview doesn't match typed text, just show typed
             Line::from(vec![
                 Span::raw("> "),
-                Span::styled(typed, Style::default().fg(Color::White)),
+                Span::styled(typed, app.theme.text()),
             ])
         }
     } else {
         Line::from(vec![
             Span::raw("> "),
-            Span::styled(&app.command_text, Style::default().fg(Color::White)),
+            Span::styled(&app.command_text, app.theme.text()),
         ])
     };
 
@@ -66,7 +67,7 @@ pub fn render(f: &mut Frame, app: &App) {
     // Suggestions list with scroll
     let suggestions_block = Block::default()
         .borders(Borders::LEFT | Borders::RIGHT | Borders::BOTTOM)
-        .border_style(Style::default().fg(Color::Cyan));
+        .border_style(app.theme.title());
 
     let inner_height = suggestions_block.inner(chunks[1]).height as usize;
 
@@ -88,15 +89,14 @@ pub fn render(f: &mut Frame, app: &App) {
         .take(inner_height)
         .map(|(i, suggestion)| {
             let style = if i == app.command_suggestion_selected {
-                Style::default()
-                    .fg(Color::Black)
-                    .bg(Color::Cyan)
-                    .add_modifier(Modifier::BOLD)
+                app.theme.badge(app.theme.title).add_modifier(Modifier::BOLD)
             } else {
-                Style::default().fg(Color::White)
+                app.theme.text()
             };
 
-            Line::from(vec![Span::raw("  "), Span::styled(suggestion, style)])
+            // Favorites (F) are starred
+            let marker = if app.config.favorites.contains(suggestion) { "★ " } else { "  " };
+            Line::from(vec![Span::styled(marker, app.theme.accent()), Span::styled(suggestion, style)])
         })
         .collect();
 
@@ -114,16 +114,14 @@ pub fn render_filter(f: &mut Frame, app: &App) {
     let block = Block::default()
         .title(" Filter ")
         .title_style(
-            Style::default()
-                .fg(Color::Yellow)
-                .add_modifier(Modifier::BOLD),
+            app.theme.header().add_modifier(Modifier::BOLD),
         )
         .borders(Borders::ALL)
-        .border_style(Style::default().fg(Color::Yellow));
+        .border_style(app.theme.header());
 
     let text = format!("/ {}", app.filter_text);
     let paragraph = Paragraph::new(text)
-        .style(Style::default().fg(Color::White))
+        .style(app.theme.text())
         .block(block);
 
     f.render_widget(paragraph, area);
@@ -154,7 +152,7 @@ fn filter_box_area(r: Rect) -> Rect {
     let vertical = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
-            Constraint::Length(7), // Skip header
+            Constraint::Length(8), // Skip header
             Constraint::Length(3), // Filter box height
             Constraint::Min(0),    // Rest
         ])
@@ -1,113 +1,232 @@
-use crate::app::App;
+//! Formatted describe view
+//!
+//! Lays a resource out as aligned label/value rows instead of raw JSON:
+//! top-level scalars first, then one section per nested object or list.
+//! Tags get their own section with one row per tag.
+
+use super::theme::Theme;
 use ratatui::{
-    layout::Rect,
-    style::{Color, Modifier, Style},
+    style::Modifier,
     text::{Line, Span},
-    widgets::{Block, Borders, Paragraph, Wrap},
-    Frame,
 };
+use serde_json::Value;
+
+/// Labels longer than this are not padded further
+const MAX_LABEL_WIDTH: usize = 30;
 
-pub fn render(f: &mut Frame, app: &App, area: Rect) {
-    let Some(instance) = app.selected_instance() else {
-        return;
+/// Build the formatted lines for a describe value
+pub fn formatted_lines(value: &Value, theme: &Theme) -> Vec<Line<'static>> {
+    let Some(map) = value.as_object() else {
+        // Not an object (e.g., an action's raw output): show it as a single section
+        let mut rows = Vec::new();
+        flatten("Value", value, &mut rows);
+        return detail_lines(&rows, theme);
     };
 
-    let mut lines: Vec<Line> = vec![
-        create_detail_line_owned("Instance ID", &instance.instance_id),
-        create_detail_line_owned("Name", &instance.name),
-        create_detail_line_owned("State", &instance.state),
-        create_detail_line_owned("Type", &instance.instance_type),
-        create_detail_line_owned("AZ", &instance.availability_zone),
-        Line::from(""),
-        create_section_header("Network"),
-        create_detail_line_owned("VPC ID", instance.vpc_id.as_deref().unwrap_or("-")),
-        create_detail_line_owned("Subnet ID", instance.subnet_id.as_deref().unwrap_or("-")),
-        create_detail_line_owned("Private IP", instance.private_ip.as_deref().unwrap_or("-")),
-        create_detail_line_owned("Public IP", instance.public_ip.as_deref().unwrap_or("-")),
-        Line::from(""),
-        create_section_header("Instance Details"),
-        create_detail_line_owned("Platform", instance.platform.as_deref().unwrap_or("Linux/UNIX")),
-        create_detail_line_owned("Architecture", instance.architecture.as_deref().unwrap_or("-")),
-        create_detail_line_owned("Key Name", instance.key_name.as_deref().unwrap_or("-")),
-        create_detail_line_owned("Launch Time", instance.launch_time.as_deref().unwrap_or("-")),
-        create_detail_line_owned("Monitoring", instance.monitoring_state.as_deref().unwrap_or("-")),
-        Line::from(""),
-        create_section_header("Storage"),
-        create_detail_line_owned("Root Device", instance.root_device_name.as_deref().unwrap_or("-")),
-        create_detail_line_owned("Root Type", instance.root_device_type.as_deref().unwrap_or("-")),
-    ];
-
-    // Add block devices
-    for (i, device) in instance.block_devices.iter().enumerate() {
-        if i == 0 {
-            lines.push(Line::from(""));
-            lines.push(create_section_header("Block Devices"));
+    let mut lines = Vec::new();
+
+    // Scalars (and lists of scalars) at the top, without a header
+    let mut rows = Vec::new();
+    for (key, field) in map {
+        if !is_section(field) {
+            flatten(key, field, &mut rows);
         }
-        let device_info = format!("{} ({})", device.volume_id, device.status);
-        lines.push(create_detail_line_owned(&device.device_name, &device_info));
     }
+    lines.extend(detail_lines(&rows, theme));
 
-    // Add security groups
-    if !instance.security_groups.is_empty() {
-        lines.push(Line::from(""));
-        lines.push(create_section_header("Security Groups"));
-        for sg in &instance.security_groups {
-            lines.push(Line::from(vec![
-                Span::raw("  "),
-                Span::styled(sg.clone(), Style::default().fg(Color::White)),
-            ]));
+    for (key, field) in map {
+        if !is_section(field) {
+            continue;
         }
+        let rows = match tag_pairs(key, field) {
+            Some(tags) => tags,
+            None => {
+                let mut rows = Vec::new();
+                match field {
+                    Value::Object(inner) => {
+                        for (inner_key, inner_value) in inner {
+                            flatten(inner_key, inner_value, &mut rows);
+                        }
+                    }
+                    Value::Array(items) => {
+                        for (i, item) in items.iter().enumerate() {
+                            flatten(&format!("[{}]", i), item, &mut rows);
+                        }
+                    }
+                    _ => {}
+                }
+                rows
+            }
+        };
+        if !lines.is_empty() {
+            lines.push(Line::from(""));
+        }
+        lines.push(create_section_header(key, theme));
+        lines.extend(detail_lines(&rows, theme));
     }
 
-    // Add IAM profile
-    if let Some(iam) = &instance.iam_instance_profile {
-        lines.push(Line::from(""));
-        lines.push(create_section_header("IAM"));
-        lines.push(create_detail_line_owned("Profile", iam));
+    lines
+}
+
+/// Nested objects and lists of objects get their own section
+fn is_section(value: &Value) -> bool {
+    match value {
+        Value::Object(map) => !map.is_empty(),
+        Value::Array(items) => items.iter().any(|item| item.is_object()),
+        _ => false,
     }
+}
 
-    // Add tags
-    if !instance.tags.is_empty() {
-        lines.push(Line::from(""));
-        lines.push(create_section_header("Tags"));
-        for (key, value) in &instance.tags {
-            lines.push(create_detail_line_owned(key, value));
-        }
+/// Key/value rows for a tag field (`[{"Key": .., "Value": ..}]` or a string map)
+fn tag_pairs(key: &str, value: &Value) -> Option<Vec<(String, String)>> {
+    if !key.to_ascii_lowercase().contains("tag") {
+        return None;
     }
 
-    // Apply scroll offset
-    let visible_lines: Vec<Line> = lines
-        .into_iter()
-        .skip(app.describe_scroll)
-        .collect();
+    let mut pairs: Vec<(String, String)> = match value {
+        Value::Array(items) => items
+            .iter()
+            .map(|item| {
+                let key = item.get("Key").or_else(|| item.get("key"))?.as_str()?;
+                let value = item.get("Value").or_else(|| item.get("value"))?;
+                Some((key.to_string(), scalar_to_string(value)))
+            })
+            .collect::<Option<_>>()?,
+        Value::Object(map) => map
+            .iter()
+            .map(|(k, v)| (k.clone(), scalar_to_string(v)))
+            .collect(),
+        _ => return None,
+    };
+    pairs.sort();
+    Some(pairs)
+}
 
-    let block = Block::default()
-        .title(" Details ")
-        .title_style(Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD))
-        .borders(Borders::LEFT);
+/// Flatten a value into `label: value` rows, joining nested labels with `.`
+fn flatten(label: &str, value: &Value, rows: &mut Vec<(String, String)>) {
+    match value {
+        Value::Object(map) if !map.is_empty() => {
+            for (key, inner) in map {
+                flatten(&format!("{}.{}", label, key), inner, rows);
+            }
+        }
+        Value::Array(items) if items.iter().any(|item| item.is_object() || item.is_array()) => {
+            for (i, item) in items.iter().enumerate() {
+                flatten(&format!("{}[{}]", label, i), item, rows);
+            }
+        }
+        Value::Array(items) => {
+            let joined = items.iter().map(scalar_to_string).collect::<Vec<_>>().join(", ");
+            rows.push((label.to_string(), if joined.is_empty() { "-".to_string() } else { joined }));
+        }
+        _ => rows.push((label.to_string(), scalar_to_string(value))),
+    }
+}
 
-    let paragraph = Paragraph::new(visible_lines)
-        .block(block)
-        .wrap(Wrap { trim: true });
+fn scalar_to_string(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        Value::Null => "-".to_string(),
+        Value::Object(map) if map.is_empty() => "-".to_string(),
+        other => other.to_string(),
+    }
+}
 
-    f.render_widget(paragraph, area);
+/// Rows with labels right-aligned to the longest label in the group
+fn detail_lines(rows: &[(String, String)], theme: &Theme) -> Vec<Line<'static>> {
+    let width = rows
+        .iter()
+        .map(|(label, _)| label.chars().count())
+        .max()
+        .unwrap_or(0)
+        .min(MAX_LABEL_WIDTH);
+    rows.iter()
+        .map(|(label, value)| create_detail_line_owned(label, value, width, theme))
+        .collect()
 }
 
-fn create_detail_line_owned(label: &str, value: &str) -> Line<'static> {
+fn create_detail_line_owned(label: &str, value: &str, width: usize, theme: &Theme) -> Line<'static> {
     Line::from(vec![
-        Span::styled(
-            format!("{:>14}: ", label),
-            Style::default().fg(Color::DarkGray),
-        ),
-        Span::styled(value.to_string(), Style::default().fg(Color::White)),
+        Span::styled(format!("{:>width$}: ", label, width = width), theme.muted()),
+        Span::styled(value.to_string(), theme.text()),
     ])
 }
 
-fn create_section_header(title: &str) -> Line<'static> {
+fn create_section_header(title: &str, theme: &Theme) -> Line<'static> {
     Line::from(vec![Span::styled(
         format!("── {} ", title),
-        Style::default()
-            .fg(Color::Cyan)
-            .add_modifier(Modifier::BOLD),
+        theme.title().add_modifier(Modifier::BOLD),
     )])
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn text(lines: &[Line]) -> Vec<String> {
+        lines
+            .iter()
+            .map(|line| line.spans.iter().map(|span| span.content.as_ref()).collect())
+            .collect()
+    }
+
+    #[test]
+    fn test_tag_arrays_become_sorted_pairs() {
+        let tags = json!([
+            { "Key": "Name", "Value": "web-1" },
+            { "Key": "Env", "Value": "prod" }
+        ]);
+        assert_eq!(
+            tag_pairs("Tags", &tags),
+            Some(vec![
+                ("Env".to_string(), "prod".to_string()),
+                ("Name".to_string(), "web-1".to_string()),
+            ])
+        );
+        // Only tag fields are paired, and only when every entry has a key
+        assert_eq!(tag_pairs("Placement", &tags), None);
+        assert_eq!(tag_pairs("Tags", &json!([{ "Value": "orphan" }])), None);
+    }
+
+    #[test]
+    fn test_nested_objects_flatten_with_dotted_labels() {
+        let mut rows = Vec::new();
+        flatten(
+            "State",
+            &json!({ "Code": 16, "Reason": { "Message": null }, "Groups": ["a", "b"], "Empty": [] }),
+            &mut rows,
+        );
+        assert_eq!(
+            rows,
+            vec![
+                ("State.Code".to_string(), "16".to_string()),
+                ("State.Empty".to_string(), "-".to_string()),
+                ("State.Groups".to_string(), "a, b".to_string()),
+                ("State.Reason.Message".to_string(), "-".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_formatted_lines_put_sections_after_scalars() {
+        let value = json!({
+            "InstanceId": "i-1",
+            "Placement": { "AvailabilityZone": "eu-west-1a" },
+            "Tags": [{ "Key": "Name", "Value": "web-1" }]
+        });
+        let lines = text(&formatted_lines(&value, &Theme::default()));
+        assert_eq!(
+            lines,
+            vec![
+                "InstanceId: i-1",
+                "",
+                "── Placement ",
+                "AvailabilityZone: eu-west-1a",
+                "",
+                "── Tags ",
+                "Name: web-1",
+            ]
+        );
+    }
+}
@@ -1,9 +1,10 @@
-use crate::app::{App, Mode, SsoLoginState};
+use crate::app::{App, Mode, SsoLoginState, REVEAL_DURATION};
+use crate::resource::{supports_dry_run, DryRunOutcome};
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Rect},
-    style::{Color, Modifier, Style},
+    style::{Color, Modifier},
     text::{Line, Span},
-    widgets::{Block, Borders, Clear, Paragraph},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
     Frame,
 };
 
@@ -12,6 +13,10 @@ pub fn render(f: &mut Frame, app: &App) {
         Mode::Confirm => render_confirm_dialog(f, app),
         Mode::Warning => render_warning_dialog(f, app),
         Mode::SsoLogin => render_sso_dialog(f, app),
+        Mode::Input => render_input_dialog(f, app),
+        Mode::ErrorDetail => render_error_detail_dialog(f, app),
+        Mode::ConfirmQuit => render_confirm_quit_dialog(f, app),
+        Mode::Reveal => render_reveal_dialog(f, app),
         _ => {}
     }
 }
@@ -21,15 +26,17 @@ fn render_confirm_dialog(f: &mut Frame, app: &App) {
         return;
     };
 
-    let area = centered_rect(60, 9, f.area());
+    let can_dry_run = supports_dry_run(&pending.service, &pending.sdk_method);
+    let height = if pending.require_typed { 12 } else { 10 } + u16::from(can_dry_run);
+    let area = centered_rect(60, height, f.area());
 
     f.render_widget(Clear, area);
 
     // Determine title color based on destructive flag
     let title_color = if pending.destructive {
-        Color::Red
+        app.theme.error
     } else {
-        Color::Yellow
+        app.theme.warning
     };
 
     let title = if pending.destructive {
@@ -38,43 +45,98 @@ fn render_confirm_dialog(f: &mut Frame, app: &App) {
         "Confirm"
     };
 
-    // Build Cancel/OK buttons with selection indicator (Cancel = !selected_yes, OK = selected_yes)
-    let cancel_style = if !pending.selected_yes {
-        Style::default().fg(Color::Black).bg(Color::Magenta)
-    } else {
-        Style::default().fg(Color::White)
-    };
-
-    let ok_style = if pending.selected_yes {
-        Style::default().fg(Color::Black).bg(Color::Magenta)
-    } else {
-        Style::default().fg(Color::White)
-    };
+    // Always show where the action will land
+    let account = app
+        .identity
+        .as_ref()
+        .map(|identity| identity.account.as_str())
+        .unwrap_or("unknown account");
 
     // Build the dialog content
-    let text = vec![
+    let mut text = vec![
         Line::from(Span::styled(
             format!("<{}>", title),
-            Style::default()
-                .fg(title_color)
-                .add_modifier(Modifier::BOLD),
+            app.theme.fg(title_color).add_modifier(Modifier::BOLD),
         )),
         Line::from(""),
         Line::from(Span::styled(
             &pending.message,
-            Style::default().fg(Color::White),
+            app.theme.text(),
         )),
-        Line::from(""),
         Line::from(vec![
+            Span::styled("Account ", app.theme.muted()),
+            Span::styled(
+                account,
+                app.theme.accent().add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(" / profile ", app.theme.muted()),
+            Span::styled(
+                &app.profile,
+                app.theme.accent().add_modifier(Modifier::BOLD),
+            ),
+        ]),
+    ];
+
+    if can_dry_run {
+        text.push(match &pending.dry_run {
+            None => Line::from(Span::styled("Ctrl+r: dry run (check permissions only)", app.theme.muted())),
+            Some(DryRunOutcome::Allowed) => Line::from(Span::styled(
+                "Dry run: allowed",
+                app.theme.fg(app.theme.success).add_modifier(Modifier::BOLD),
+            )),
+            Some(DryRunOutcome::Refused(reason)) => Line::from(Span::styled(
+                format!("Dry run: {}", reason),
+                app.theme.error().add_modifier(Modifier::BOLD),
+            )),
+        });
+    }
+    text.push(Line::from(""));
+
+    if pending.require_typed {
+        // Typed text turns green once it matches
+        let input_color = if pending.matches_typed(&app.confirm_text) {
+            app.theme.success
+        } else {
+            app.theme.error
+        };
+        text.push(Line::from(Span::styled(
+            format!("Type '{}' or 'yes' to confirm:", pending.resource_name),
+            app.theme.muted(),
+        )));
+        text.push(Line::from(vec![
+            Span::styled("> ", app.theme.muted()),
+            Span::styled(&app.confirm_text, app.theme.fg(input_color)),
+            Span::styled("_", app.theme.muted()),
+        ]));
+        text.push(Line::from(""));
+        text.push(Line::from(Span::styled(
+            "Enter: confirm | Esc: cancel",
+            app.theme.muted(),
+        )));
+    } else {
+        // Build Cancel/OK buttons with selection indicator (Cancel = !selected_yes, OK = selected_yes)
+        let cancel_style = if !pending.selected_yes {
+            app.theme.badge(app.theme.accent)
+        } else {
+            app.theme.text()
+        };
+
+        let ok_style = if pending.selected_yes {
+            app.theme.badge(app.theme.accent)
+        } else {
+            app.theme.text()
+        };
+
+        text.push(Line::from(vec![
             Span::styled(" Cancel ", cancel_style),
             Span::raw("    "),
             Span::styled(" OK ", ok_style),
-        ]),
-    ];
+        ]));
+    }
 
     let block = Block::default()
         .borders(Borders::ALL)
-        .border_style(Style::default().fg(Color::DarkGray));
+        .border_style(app.theme.muted());
 
     let paragraph = Paragraph::new(text)
         .block(block)
@@ -83,6 +145,31 @@ fn render_confirm_dialog(f: &mut Frame, app: &App) {
     f.render_widget(paragraph, area);
 }
 
+fn render_error_detail_dialog(f: &mut Frame, app: &App) {
+    let Some(detail) = &app.error_detail else {
+        return;
+    };
+
+    let area = centered_rect(80, 20, f.area());
+
+    f.render_widget(Clear, area);
+
+    let block = Block::default()
+        .title(" Error Details ")
+        .title_style(app.theme.error().add_modifier(Modifier::BOLD))
+        .title_bottom(Line::from(" j/k: scroll | q/e/Esc: close ").right_aligned())
+        .borders(Borders::ALL)
+        .border_style(app.theme.error());
+
+    let paragraph = Paragraph::new(detail.as_str())
+        .style(app.theme.text())
+        .block(block)
+        .wrap(Wrap { trim: false })
+        .scroll((app.error_scroll.min(u16::MAX as usize) as u16, 0));
+
+    f.render_widget(paragraph, area);
+}
+
 fn render_warning_dialog(f: &mut Frame, app: &App) {
     let Some(message) = &app.warning_message else {
         return;
@@ -95,25 +182,152 @@ fn render_warning_dialog(f: &mut Frame, app: &App) {
     let text = vec![
         Line::from(Span::styled(
             "<Warning>",
-            Style::default()
-                .fg(Color::Yellow)
-                .add_modifier(Modifier::BOLD),
+            app.theme.warning().add_modifier(Modifier::BOLD),
         )),
         Line::from(""),
         Line::from(Span::styled(
             message.as_str(),
-            Style::default().fg(Color::White),
+            app.theme.text(),
         )),
         Line::from(""),
         Line::from(vec![Span::styled(
             " OK ",
-            Style::default().fg(Color::Black).bg(Color::Magenta),
+            app.theme.badge(app.theme.accent),
         )]),
     ];
 
     let block = Block::default()
         .borders(Borders::ALL)
-        .border_style(Style::default().fg(Color::DarkGray));
+        .border_style(app.theme.muted());
+
+    let paragraph = Paragraph::new(text)
+        .block(block)
+        .alignment(Alignment::Center);
+
+    f.render_widget(paragraph, area);
+}
+
+fn render_reveal_dialog(f: &mut Frame, app: &App) {
+    let Some(revealed) = &app.revealed else {
+        return;
+    };
+
+    let area = centered_rect(70, 12, f.area());
+
+    f.render_widget(Clear, area);
+
+    let text = vec![
+        Line::from(Span::styled(
+            format!("<{}>", revealed.display_name),
+            app.theme.warning().add_modifier(Modifier::BOLD),
+        )),
+        Line::from(""),
+        Line::from(Span::styled(
+            revealed.resource_name.as_str(),
+            app.theme.accent().add_modifier(Modifier::BOLD),
+        )),
+        Line::from(Span::styled(
+            format!(
+                "Visible to anyone who can see your screen - hides in {}s",
+                REVEAL_DURATION.saturating_sub(revealed.shown_at.elapsed()).as_secs() + 1
+            ),
+            app.theme.error(),
+        )),
+        Line::from(""),
+        Line::from(Span::styled(revealed.value.as_str(), app.theme.text())),
+    ];
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(app.theme.muted())
+        .title_bottom(Line::from(" any key: hide ").right_aligned());
+
+    let paragraph = Paragraph::new(text)
+        .block(block)
+        .alignment(Alignment::Center)
+        .wrap(Wrap { trim: false });
+
+    f.render_widget(paragraph, area);
+}
+
+fn render_confirm_quit_dialog(f: &mut Frame, app: &App) {
+    let area = centered_rect(60, 8, f.area());
+
+    f.render_widget(Clear, area);
+
+    let text = vec![
+        Line::from(Span::styled(
+            "<Quit>",
+            app.theme.warning().add_modifier(Modifier::BOLD),
+        )),
+        Line::from(""),
+        Line::from(Span::styled(
+            format!(
+                "{} action(s) still in progress. Quitting now won't show whether they completed.",
+                app.actions_in_flight
+            ),
+            app.theme.text(),
+        )),
+        Line::from(""),
+        Line::from(Span::styled(
+            "y: quit anyway | n/Esc: keep running",
+            app.theme.muted(),
+        )),
+    ];
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(app.theme.muted());
+
+    let paragraph = Paragraph::new(text)
+        .block(block)
+        .alignment(Alignment::Center)
+        .wrap(Wrap { trim: true });
+
+    f.render_widget(paragraph, area);
+}
+
+fn render_input_dialog(f: &mut Frame, app: &App) {
+    let Some(ref input) = app.action_input else {
+        return;
+    };
+
+    let area = centered_rect(60, 8, f.area());
+
+    f.render_widget(Clear, area);
+
+    let input_line = if input.text.is_empty() {
+        Line::from(vec![
+            Span::styled("> ", app.theme.header()),
+            Span::styled(
+                input.placeholder.clone().unwrap_or_default(),
+                app.theme.muted(),
+            ),
+        ])
+    } else {
+        Line::from(vec![
+            Span::styled("> ", app.theme.header()),
+            Span::styled(format!("{}_", input.text), app.theme.text()),
+        ])
+    };
+
+    let text = vec![
+        Line::from(Span::styled(
+            format!("<{}>", input.prompt),
+            app.theme.header().add_modifier(Modifier::BOLD),
+        )),
+        Line::from(""),
+        input_line,
+        Line::from(""),
+        Line::from(Span::styled(
+            "Enter: apply | Esc: cancel",
+            app.theme.muted(),
+        )),
+    ];
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(app.theme.muted());
 
     let paragraph = Paragraph::new(text)
         .block(block)
@@ -138,29 +352,27 @@ fn render_sso_dialog(f: &mut Frame, app: &App) {
             let text = vec![
                 Line::from(Span::styled(
                     "<SSO Login Required>",
-                    Style::default()
-                        .fg(Color::Cyan)
-                        .add_modifier(Modifier::BOLD),
+                    app.theme.title().add_modifier(Modifier::BOLD),
                 )),
                 Line::from(""),
                 Line::from(Span::styled(
                     format!("Profile '{}' requires SSO authentication.", profile),
-                    Style::default().fg(Color::White),
+                    app.theme.text(),
                 )),
                 Line::from(Span::styled(
                     format!("Session: {}", sso_session),
-                    Style::default().fg(Color::DarkGray),
+                    app.theme.muted(),
                 )),
                 Line::from(""),
                 Line::from(Span::styled(
                     "Press Enter to open browser for login, Esc to cancel",
-                    Style::default().fg(Color::Yellow),
+                    app.theme.header(),
                 )),
             ];
 
             let block = Block::default()
                 .borders(Borders::ALL)
-                .border_style(Style::default().fg(Color::Cyan));
+                .border_style(app.theme.title());
 
             let paragraph = Paragraph::new(text)
                 .block(block)
@@ -180,39 +392,35 @@ fn render_sso_dialog(f: &mut Frame, app: &App) {
             let text = vec![
                 Line::from(Span::styled(
                     "<Waiting for SSO Authentication>",
-                    Style::default()
-                        .fg(Color::Yellow)
-                        .add_modifier(Modifier::BOLD),
+                    app.theme.header().add_modifier(Modifier::BOLD),
                 )),
                 Line::from(""),
                 Line::from(Span::styled(
                     "Complete authentication in your browser.",
-                    Style::default().fg(Color::White),
+                    app.theme.text(),
                 )),
                 Line::from(""),
                 Line::from(vec![
-                    Span::styled("Code: ", Style::default().fg(Color::DarkGray)),
+                    Span::styled("Code: ", app.theme.muted()),
                     Span::styled(
                         user_code,
-                        Style::default()
-                            .fg(Color::Cyan)
-                            .add_modifier(Modifier::BOLD),
+                        app.theme.title().add_modifier(Modifier::BOLD),
                     ),
                 ]),
                 Line::from(vec![
-                    Span::styled("URL: ", Style::default().fg(Color::DarkGray)),
-                    Span::styled(verification_uri, Style::default().fg(Color::Blue)),
+                    Span::styled("URL: ", app.theme.muted()),
+                    Span::styled(verification_uri, app.theme.fg(Color::Blue)),
                 ]),
                 Line::from(""),
                 Line::from(Span::styled(
                     "Waiting... (Press Esc to cancel)",
-                    Style::default().fg(Color::DarkGray),
+                    app.theme.muted(),
                 )),
             ];
 
             let block = Block::default()
                 .borders(Borders::ALL)
-                .border_style(Style::default().fg(Color::Yellow));
+                .border_style(app.theme.header());
 
             let paragraph = Paragraph::new(text)
                 .block(block)
@@ -228,20 +436,18 @@ fn render_sso_dialog(f: &mut Frame, app: &App) {
             let text = vec![
                 Line::from(Span::styled(
                     "<SSO Login Successful>",
-                    Style::default()
-                        .fg(Color::Green)
-                        .add_modifier(Modifier::BOLD),
+                    app.theme.success().add_modifier(Modifier::BOLD),
                 )),
                 Line::from(""),
                 Line::from(Span::styled(
                     format!("Authentication complete for '{}'!", profile),
-                    Style::default().fg(Color::White),
+                    app.theme.text(),
                 )),
             ];
 
             let block = Block::default()
                 .borders(Borders::ALL)
-                .border_style(Style::default().fg(Color::Green));
+                .border_style(app.theme.success());
 
             let paragraph = Paragraph::new(text)
                 .block(block)
@@ -257,23 +463,23 @@ fn render_sso_dialog(f: &mut Frame, app: &App) {
             let text = vec![
                 Line::from(Span::styled(
                     "<SSO Login Failed>",
-                    Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+                    app.theme.error().add_modifier(Modifier::BOLD),
                 )),
                 Line::from(""),
                 Line::from(Span::styled(
                     error.as_str(),
-                    Style::default().fg(Color::White),
+                    app.theme.text(),
                 )),
                 Line::from(""),
                 Line::from(Span::styled(
                     "Press Enter or Esc to close",
-                    Style::default().fg(Color::DarkGray),
+                    app.theme.muted(),
                 )),
             ];
 
             let block = Block::default()
                 .borders(Borders::ALL)
-                .border_style(Style::default().fg(Color::Red));
+                .border_style(app.theme.error());
 
             let paragraph = Paragraph::new(text)
                 .block(block)
@@ -0,0 +1,35 @@
+use super::picker;
+use crate::app::App;
+use crate::resource::get_resource;
+use ratatui::{
+    style::Modifier,
+    text::{Line, Span},
+    Frame,
+};
+
+/// Favorite resources (`~`), numbered for one-key jumping
+pub fn render(f: &mut Frame, app: &App) {
+    let lines: Vec<Line> = app
+        .config
+        .favorites
+        .iter()
+        .enumerate()
+        .map(|(i, key)| {
+            let number = if i < 9 { format!("{}", i + 1) } else { " ".to_string() };
+            let name = get_resource(key).map_or(key.as_str(), |r| r.display_name.as_str());
+            let style = if i == app.favorites_selected {
+                app.theme.selected().add_modifier(Modifier::BOLD)
+            } else if *key == app.current_resource_key {
+                app.theme.success()
+            } else {
+                app.theme.text()
+            };
+            Line::from(vec![
+                Span::styled(format!(" {}  ", number), app.theme.muted()),
+                Span::styled(format!("{:<28} {}", name, key), style),
+            ])
+        })
+        .collect();
+
+    picker::render(f, app, " Favorites ", 50, lines, "1-9/Enter: open | q/Esc: close");
+}
@@ -1,7 +1,7 @@
 use crate::app::App;
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
-    style::{Color, Modifier, Style},
+    style::Modifier,
     text::{Line, Span},
     widgets::Paragraph,
     Frame,
@@ -23,8 +23,8 @@ pub fn render(f: &mut Frame, app: &App, area: Rect) {
     render_context_column(f, app, columns[0]);
     render_shortcuts_column(f, app, columns[1]);
     render_keybindings_col1(f, app, columns[2]);
-    render_keybindings_col2(f, columns[3]);
-    render_logo(f, columns[4]);
+    render_keybindings_col2(f, app, columns[3]);
+    render_logo(f, app, columns[4]);
 }
 
 fn render_context_column(f: &mut Frame, app: &App, area: Rect) {
@@ -35,33 +35,46 @@ fn render_context_column(f: &mut Frame, app: &App, area: Rect) {
 
     let mut lines = vec![
         Line::from(vec![
-            Span::styled("Profile:", Style::default().fg(Color::DarkGray)),
+            Span::styled("Profile:", app.theme.muted()),
             Span::raw(" "),
             Span::styled(
                 &app.profile,
-                Style::default()
-                    .fg(Color::Magenta)
-                    .add_modifier(Modifier::BOLD),
+                app.theme.accent().add_modifier(Modifier::BOLD),
             ),
         ]),
+        Line::from(match &app.identity {
+            Some(identity) => vec![
+                Span::styled("Account:", app.theme.muted()),
+                Span::raw(" "),
+                Span::styled(
+                    identity.account.clone(),
+                    app.theme.accent().add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(
+                    format!(" ({})", identity.principal_name()),
+                    app.theme.muted(),
+                ),
+            ],
+            None => vec![
+                Span::styled("Account:", app.theme.muted()),
+                Span::raw(" "),
+                Span::styled("-", app.theme.muted()),
+            ],
+        }),
         Line::from(vec![
-            Span::styled("Region: ", Style::default().fg(Color::DarkGray)),
+            Span::styled("Region: ", app.theme.muted()),
             Span::raw(" "),
             Span::styled(
                 &app.region,
-                Style::default()
-                    .fg(Color::Magenta)
-                    .add_modifier(Modifier::BOLD),
+                app.theme.accent().add_modifier(Modifier::BOLD),
             ),
         ]),
         Line::from(vec![
-            Span::styled("Resource:", Style::default().fg(Color::DarkGray)),
+            Span::styled("Resource:", app.theme.muted()),
             Span::raw(" "),
             Span::styled(
                 resource_name.to_string(),
-                Style::default()
-                    .fg(Color::Cyan)
-                    .add_modifier(Modifier::BOLD),
+                app.theme.title().add_modifier(Modifier::BOLD),
             ),
         ]),
     ];
@@ -69,21 +82,19 @@ fn render_context_column(f: &mut Frame, app: &App, area: Rect) {
     // Show parent context if navigating
     if let Some(parent) = &app.parent_context {
         lines.push(Line::from(vec![
-            Span::styled("Context:", Style::default().fg(Color::DarkGray)),
+            Span::styled("Context:", app.theme.muted()),
             Span::raw(" "),
-            Span::styled(&parent.display_name, Style::default().fg(Color::Yellow)),
+            Span::styled(&parent.display_name, app.theme.header()),
         ]));
     }
 
     // Show read-only mode indicator
     if app.readonly {
         lines.push(Line::from(vec![
-            Span::styled("Mode:    ", Style::default().fg(Color::DarkGray)),
+            Span::styled("Mode:    ", app.theme.muted()),
             Span::styled(
                 "READONLY",
-                Style::default()
-                    .fg(Color::Yellow)
-                    .add_modifier(Modifier::BOLD),
+                app.theme.warning().add_modifier(Modifier::BOLD),
             ),
         ]));
     }
@@ -91,12 +102,10 @@ fn render_context_column(f: &mut Frame, app: &App, area: Rect) {
     // Show custom endpoint indicator
     if app.endpoint_url.is_some() {
         lines.push(Line::from(vec![
-            Span::styled("Endpoint:", Style::default().fg(Color::DarkGray)),
+            Span::styled("Endpoint:", app.theme.muted()),
             Span::styled(
                 " CUSTOM",
-                Style::default()
-                    .fg(Color::Magenta)
-                    .add_modifier(Modifier::BOLD),
+                app.theme.accent().add_modifier(Modifier::BOLD),
             ),
         ]));
     }
@@ -119,60 +128,65 @@ fn render_shortcuts_column(f: &mut Frame, app: &App, area: Rect) {
 }
 
 fn render_region_shortcuts(f: &mut Frame, app: &App, area: Rect) {
-    let regions = [
-        ("0", "us-east-1"),
-        ("1", "us-west-2"),
-        ("2", "eu-west-1"),
-        ("3", "eu-central-1"),
-        ("4", "ap-northeast-1"),
-        ("5", "ap-southeast-1"),
-    ];
+    let regions = app.config.region_shortcuts();
+
+    // Keep the last line for a "+n more" hint when the list doesn't fit
+    let fits = regions.len() <= area.height as usize;
+    let shown = if fits {
+        regions.len()
+    } else {
+        (area.height as usize).saturating_sub(1)
+    };
 
-    let lines: Vec<Line> = regions
+    let mut lines: Vec<Line> = regions
         .iter()
+        .take(shown)
         .map(|(key, region)| {
             let is_current = *region == app.region;
             let style = if is_current {
-                Style::default()
-                    .fg(Color::Green)
-                    .add_modifier(Modifier::BOLD)
+                app.theme.success().add_modifier(Modifier::BOLD)
             } else {
-                Style::default().fg(Color::White)
+                app.theme.text()
             };
 
             Line::from(vec![
-                Span::styled(format!("<{}>", key), Style::default().fg(Color::Yellow)),
+                Span::styled(format!("<A-{}>", key), app.theme.header()),
                 Span::raw(" "),
-                Span::styled(*region, style),
+                Span::styled(region.clone(), style),
             ])
         })
         .collect();
 
+    if !fits {
+        lines.push(Line::from(Span::styled(
+            format!("  +{} more", regions.len() - shown),
+            app.theme.muted(),
+        )));
+    }
+
     let paragraph = Paragraph::new(lines);
     f.render_widget(paragraph, area);
 }
 
 fn render_subresource_shortcuts(
     f: &mut Frame,
-    _app: &App,
+    app: &App,
     resource: &crate::resource::ResourceDef,
     area: Rect,
 ) {
     let mut lines: Vec<Line> = vec![Line::from(Span::styled(
         "Sub-resources:",
-        Style::default()
-            .fg(Color::DarkGray)
-            .add_modifier(Modifier::BOLD),
+        app.theme.muted().add_modifier(Modifier::BOLD),
     ))];
 
     for sub in resource.sub_resources.iter().take(5) {
         lines.push(Line::from(vec![
             Span::styled(
                 format!("<{}>", sub.shortcut),
-                Style::default().fg(Color::Yellow),
+                app.theme.header(),
             ),
             Span::raw(" "),
-            Span::styled(sub.display_name.clone(), Style::default().fg(Color::White)),
+            Span::styled(sub.display_name.clone(), app.theme.text()),
         ]));
     }
 
@@ -180,7 +194,7 @@ fn render_subresource_shortcuts(
     if resource.sub_resources.len() > 5 {
         lines.push(Line::from(Span::styled(
             format!("  +{} more", resource.sub_resources.len() - 5),
-            Style::default().fg(Color::DarkGray),
+            app.theme.muted(),
         )));
     }
 
@@ -193,8 +207,12 @@ fn render_keybindings_col1(f: &mut Frame, app: &App, area: Rect) {
     let bindings: Vec<(String, String)> = if let Some(resource) = app.current_resource() {
         let mut b: Vec<(String, String)> = vec![("<d>".to_string(), "Describe".to_string())];
 
-        // Add resource-specific actions
-        for action in resource.actions.iter().take(3) {
+        // Add resource-specific actions that apply to the selected item
+        let applicable = resource
+            .actions
+            .iter()
+            .filter(|action| app.selected_item().is_none_or(|item| action.is_enabled_for(item)));
+        for action in applicable.take(3) {
             if let Some(ref shortcut) = action.shortcut {
                 b.push((format!("<{}>", shortcut), action.display_name.clone()));
             }
@@ -229,8 +247,8 @@ fn render_keybindings_col1(f: &mut Frame, app: &App, area: Rect) {
         .iter()
         .map(|(key, desc)| {
             Line::from(vec![
-                Span::styled(format!("{:<9}", key), Style::default().fg(Color::Yellow)),
-                Span::styled(desc.clone(), Style::default().fg(Color::DarkGray)),
+                Span::styled(format!("{:<9}", key), app.theme.header()),
+                Span::styled(desc.clone(), app.theme.muted()),
             ])
         })
         .collect();
@@ -239,8 +257,8 @@ fn render_keybindings_col1(f: &mut Frame, app: &App, area: Rect) {
     f.render_widget(paragraph, area);
 }
 
-fn render_keybindings_col2(f: &mut Frame, area: Rect) {
-    let bindings = vec![
+fn render_keybindings_col2(f: &mut Frame, app: &App, area: Rect) {
+    let bindings = [
         ("</>", "Filter"),
         ("<:>", "Resources"),
         ("<R>", "Refresh"),
@@ -256,8 +274,8 @@ fn render_keybindings_col2(f: &mut Frame, area: Rect) {
                 Line::from("")
             } else {
                 Line::from(vec![
-                    Span::styled(format!("{:<9}", key), Style::default().fg(Color::Yellow)),
-                    Span::styled(*desc, Style::default().fg(Color::DarkGray)),
+                    Span::styled(format!("{:<9}", key), app.theme.header()),
+                    Span::styled(*desc, app.theme.muted()),
                 ])
             }
         })
@@ -267,28 +285,24 @@ fn render_keybindings_col2(f: &mut Frame, area: Rect) {
     f.render_widget(paragraph, area);
 }
 
-fn render_logo(f: &mut Frame, area: Rect) {
+fn render_logo(f: &mut Frame, app: &App, area: Rect) {
     let logo = vec![
         Line::from(Span::styled(
             "▀█▀ ▄▀█ █ █ █ █▀",
-            Style::default()
-                .fg(Color::Cyan)
-                .add_modifier(Modifier::BOLD),
+            app.theme.title().add_modifier(Modifier::BOLD),
         )),
         Line::from(Span::styled(
             " █  █▀█ ▀▄▀▄▀ ▄█",
-            Style::default()
-                .fg(Color::Cyan)
-                .add_modifier(Modifier::BOLD),
+            app.theme.title().add_modifier(Modifier::BOLD),
         )),
         Line::from(""),
         Line::from(Span::styled(
             "AWS TUI",
-            Style::default().fg(Color::DarkGray),
+            app.theme.muted(),
         )),
         Line::from(Span::styled(
             crate::VERSION,
-            Style::default().fg(Color::DarkGray),
+            app.theme.muted(),
         )),
     ];
 
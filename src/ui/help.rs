@@ -1,101 +1,159 @@
+use super::theme::Theme;
 use crate::app::App;
+use crate::resource::ResourceDef;
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
-    style::{Color, Modifier, Style},
+    style::Modifier,
     text::{Line, Span},
     widgets::{Block, Borders, Clear, Paragraph},
     Frame,
 };
+use std::borrow::Cow;
 
-pub fn render(f: &mut Frame, _app: &App) {
+pub fn render(f: &mut Frame, app: &App) {
+    let theme = &app.theme;
     let area = centered_rect(60, 70, f.area());
 
     f.render_widget(Clear, area);
 
-    let help_text = vec![
-        Line::from(""),
-        create_section("Navigation"),
-        create_key_line("j / ↓", "Move down"),
-        create_key_line("k / ↑", "Move up"),
-        create_key_line("gg / Home", "Go to top"),
-        create_key_line("G / End", "Go to bottom"),
-        create_key_line("Ctrl+d", "Page down"),
-        create_key_line("Ctrl+u", "Page up"),
-        create_key_line("]", "Next page (load more)"),
-        create_key_line("[", "Previous page"),
-        create_key_line("R", "Refresh list"),
+    // Keys of the resource on screen come first, straight from its definition
+    let mut help_text = vec![Line::from("")];
+    if let Some(resource) = app.current_resource() {
+        help_text.extend(resource_lines(theme, resource));
+    }
+
+    help_text.extend([
+        create_section(theme, "Navigation"),
+        create_key_line(theme, "j / ↓", "Move down"),
+        create_key_line(theme, "k / ↑", "Move up"),
+        create_key_line(theme, "gg / Home", "Go to top"),
+        create_key_line(theme, "G / End", "Go to bottom"),
+        create_key_line(theme, "5j / 5G", "Move 5 rows / go to row 5"),
+        create_key_line(theme, "Ctrl+d", "Page down"),
+        create_key_line(theme, "Ctrl+u", "Page up"),
+        create_key_line(theme, "]", "Next page (load more)"),
+        create_key_line(theme, "[", "Previous page"),
+        create_key_line(theme, "R", "Refresh list"),
         Line::from(""),
-        create_section("Views"),
-        create_key_line("d / Enter", "Show details panel"),
-        create_key_line("J", "Show JSON view"),
-        create_key_line("?", "Toggle help"),
+        create_section(theme, "Views"),
+        create_key_line(theme, "d / Enter", "Show details panel"),
+        create_key_line(theme, "I", "Toggle details beside table"),
+        create_key_line(theme, "J", "Show JSON view"),
+        create_key_line(theme, "C", "Show/hide columns, group rows"),
+        create_key_line(theme, "F / ~", "Toggle favorite / list favorites"),
+        create_key_line(theme, "o", "Open in AWS Console"),
+        create_key_line(theme, "?", "Toggle help"),
         Line::from(""),
-        create_section("EC2 Actions"),
-        create_key_line("s", "Start instance"),
-        create_key_line("S", "Stop instance"),
-        create_key_line("r", "Reboot instance"),
-        create_key_line("Ctrl+d", "Terminate instance"),
+        create_section(theme, "Describe Mode"),
+        create_key_line(theme, "/", "Search within details"),
+        create_key_line(theme, "n / N", "Next/previous match"),
+        create_key_line(theme, "r", "Toggle full/projected JSON"),
+        create_key_line(theme, "f", "Toggle JSON/formatted view"),
         Line::from(""),
-        create_section("Log Tail Mode"),
-        create_key_line("t", "Tail logs (on log stream)"),
-        create_key_line("j / k", "Scroll up/down"),
-        create_key_line("G", "Go to bottom (live mode)"),
-        create_key_line("g", "Go to top"),
-        create_key_line("SPACE", "Pause/resume"),
-        create_key_line("q / Esc", "Exit log tail"),
+        create_section(theme, "Log Tail Mode"),
+        create_key_line(theme, "t", "Tail logs (on log stream)"),
+        create_key_line(theme, "j / k", "Scroll up/down"),
+        create_key_line(theme, "G", "Go to bottom (live mode)"),
+        create_key_line(theme, "g", "Go to top"),
+        create_key_line(theme, "t / SPACE", "Stop/start tailing"),
+        create_key_line(theme, "q / Esc", "Exit log tail"),
         Line::from(""),
-        create_section("Auto-refresh"),
-        create_key_line("", "List refreshes every 5s"),
+        create_section(theme, "Refresh"),
+        create_key_line(theme, "", "Lists reload after actions and on R"),
+        create_key_line(theme, "", "keeping the filter and selected row"),
         Line::from(""),
-        create_section("Modes"),
-        create_key_line("/", "Filter mode"),
-        create_key_line(":", "Resources mode"),
+        create_section(theme, "Modes"),
+        create_key_line(theme, "/", "Filter mode"),
+        create_key_line(theme, ":", "Resources mode"),
+        create_key_line(theme, "Ctrl+p", "Jump to resource"),
+        create_key_line(theme, "", "Ctrl+p/n in : recalls commands"),
+        create_key_line(theme, "e", "Show full error"),
+        create_key_line(theme, "X", "Dismiss error banner"),
+        create_key_line(theme, "M", "Toggle mouse capture"),
+        create_key_line(theme, "L", "SSO login for current profile"),
         Line::from(""),
-        create_section("Resources"),
-        create_key_line(":ec2", "EC2 instances view"),
-        create_key_line(":vpc", "VPC view"),
-        create_key_line(":profiles", "List AWS profiles"),
-        create_key_line(":regions", "List AWS regions"),
+        create_section(theme, "Resources"),
+        create_key_line(theme, ":ec2", "EC2 instances view"),
+        create_key_line(theme, ":vpc", "VPC view"),
+        create_key_line(theme, ":profiles", "List AWS profiles (/ filters)"),
+        create_key_line(theme, ":regions", "List AWS regions (/ filters)"),
+        create_key_line(theme, "Alt+0-9", "Switch to a header region"),
+        create_key_line(theme, ":search <term>", "Find EC2, S3, Lambda, RDS by name/ID"),
+        create_key_line(theme, ":yank", "Copy filtered table as TSV"),
+        create_key_line(theme, ":export <dir>", "Write marked rows (Space) as JSON"),
+        create_key_line(theme, ":allregions", "List resource from every region"),
         Line::from(""),
-        create_key_line("Esc", "Close / Cancel"),
-        create_key_line("Ctrl+c", "Quit application"),
-    ];
+        create_key_line(theme, "Esc", "Close / Cancel"),
+        create_key_line(theme, "Ctrl+c", "Quit application"),
+    ]);
 
     let block = Block::default()
         .title(" Help ")
-        .title_style(
-            Style::default()
-                .fg(Color::Cyan)
-                .add_modifier(Modifier::BOLD),
-        )
+        .title_style(theme.title().add_modifier(Modifier::BOLD))
         .borders(Borders::ALL)
-        .border_style(Style::default().fg(Color::Cyan));
+        .border_style(theme.title());
 
     let paragraph = Paragraph::new(help_text).block(block);
 
     f.render_widget(paragraph, area);
 }
 
-fn create_section(title: &str) -> Line<'_> {
+/// Sub-resource, action and command shortcuts of `resource`, under its display name
+fn resource_lines(theme: &Theme, resource: &'static ResourceDef) -> Vec<Line<'static>> {
+    if resource.sub_resources.is_empty() && resource.actions.is_empty() && resource.commands.is_empty() {
+        return Vec::new();
+    }
+
+    let mut lines = vec![create_section(theme, &resource.display_name)];
+    for sub in &resource.sub_resources {
+        lines.push(create_key_line(theme, &sub.shortcut, format!("Open {}", sub.display_name)));
+    }
+    for action in &resource.actions {
+        let Some(shortcut) = &action.shortcut else {
+            continue;
+        };
+        let description = match &action.enabled_when {
+            Some(condition) => Cow::Owned(format!(
+                "{} (when {} is {})",
+                action.display_name,
+                condition.field,
+                condition.values.join("/")
+            )),
+            None => Cow::Borrowed(action.display_name.as_str()),
+        };
+        lines.push(create_key_line(theme, &display_shortcut(shortcut), description));
+    }
+    for command in &resource.commands {
+        lines.push(create_key_line(theme, &command.shortcut, format!("Run {}", command.display_name)));
+    }
+    lines.push(Line::from(""));
+    lines
+}
+
+/// `ctrl+d` as `Ctrl+d`, matching the rest of the help text
+fn display_shortcut(shortcut: &str) -> String {
+    match shortcut.strip_prefix("ctrl+") {
+        Some(key) => format!("Ctrl+{}", key),
+        None => shortcut.to_string(),
+    }
+}
+
+fn create_section<'a>(theme: &Theme, title: &str) -> Line<'a> {
     Line::from(vec![Span::styled(
         format!("  {} ", title),
-        Style::default()
-            .fg(Color::Yellow)
-            .add_modifier(Modifier::BOLD),
+        theme.header().add_modifier(Modifier::BOLD),
     )])
 }
 
-fn create_key_line<'a>(key: &'a str, description: &'a str) -> Line<'a> {
+fn create_key_line<'a>(theme: &Theme, key: &str, description: impl Into<Cow<'a, str>>) -> Line<'a> {
     Line::from(vec![
         Span::raw("    "),
         Span::styled(
             format!("{:>15}", key),
-            Style::default()
-                .fg(Color::Green)
-                .add_modifier(Modifier::BOLD),
+            theme.success().add_modifier(Modifier::BOLD),
         ),
         Span::raw("  "),
-        Span::styled(description, Style::default().fg(Color::White)),
+        Span::styled(description, theme.text()),
     ])
 }
 
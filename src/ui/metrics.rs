@@ -0,0 +1,102 @@
+use crate::app::App;
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::Modifier,
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Sparkline},
+    Frame,
+};
+
+pub fn render(f: &mut Frame, app: &App) {
+    let Some(ref state) = app.metrics_state else {
+        return;
+    };
+
+    let area = centered_rect(70, 14, f.area());
+    f.render_widget(Clear, area);
+
+    let block = Block::default()
+        .title(format!(" {} - {} (last hour) ", state.metric_name, state.resource_id))
+        .title_style(
+            app.theme.title().add_modifier(Modifier::BOLD),
+        )
+        .borders(Borders::ALL)
+        .border_style(app.theme.title());
+
+    let inner = block.inner(area);
+    f.render_widget(block, area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Min(1),    // Sparkline
+            Constraint::Length(1), // Summary
+            Constraint::Length(1), // Hints
+        ])
+        .split(inner);
+
+    if let Some(ref err) = state.error {
+        let paragraph = Paragraph::new(err.as_str())
+            .style(app.theme.error())
+            .alignment(Alignment::Center);
+        f.render_widget(paragraph, chunks[0]);
+    } else if state.values.is_empty() {
+        let paragraph = Paragraph::new("No datapoints in the last hour")
+            .style(app.theme.muted())
+            .alignment(Alignment::Center);
+        f.render_widget(paragraph, chunks[0]);
+    } else {
+        // Sparkline needs integers; keep two decimal places of precision
+        let data: Vec<u64> = state
+            .values
+            .iter()
+            .map(|v| (v * 100.0).round().max(0.0) as u64)
+            .collect();
+        let sparkline = Sparkline::default()
+            .data(&data)
+            .style(app.theme.success());
+        f.render_widget(sparkline, chunks[0]);
+
+        let min = state.values.iter().cloned().fold(f64::INFINITY, f64::min);
+        let max = state.values.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+        let avg = state.values.iter().sum::<f64>() / state.values.len() as f64;
+        let latest = state.values.last().copied().unwrap_or(0.0);
+
+        let summary = Line::from(vec![
+            Span::styled("latest ", app.theme.muted()),
+            Span::styled(format!("{:.2}%", latest), app.theme.text()),
+            Span::styled("  min ", app.theme.muted()),
+            Span::styled(format!("{:.2}%", min), app.theme.text()),
+            Span::styled("  avg ", app.theme.muted()),
+            Span::styled(format!("{:.2}%", avg), app.theme.text()),
+            Span::styled("  max ", app.theme.muted()),
+            Span::styled(format!("{:.2}%", max), app.theme.text()),
+        ]);
+        f.render_widget(Paragraph::new(summary).alignment(Alignment::Center), chunks[1]);
+    }
+
+    let hints = Paragraph::new("R: refresh | q/m/Esc: close")
+        .style(app.theme.muted())
+        .alignment(Alignment::Center);
+    f.render_widget(hints, chunks[2]);
+}
+
+fn centered_rect(percent_x: u16, height: u16, r: Rect) -> Rect {
+    let popup_layout = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Percentage(40),
+            Constraint::Length(height),
+            Constraint::Percentage(40),
+        ])
+        .split(r);
+
+    Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Percentage((100 - percent_x) / 2),
+            Constraint::Percentage(percent_x),
+            Constraint::Percentage((100 - percent_x) / 2),
+        ])
+        .split(popup_layout[1])[1]
+}
@@ -1,53 +1,99 @@
+mod columns;
 mod command_box;
+pub mod details;
 mod dialog;
+mod favorites;
 mod header;
 mod help;
+mod metrics;
+mod picker;
 mod profiles;
 mod regions;
+mod search;
+pub mod theme;
 pub mod splash;
 
-use crate::app::{App, Mode};
-use crate::resource::{extract_json_value, get_color_for_value, ColumnDef};
+use crate::app::{App, FetchOutcome, Mode, TableLayout};
+use crate::config::DisplayTimezone;
+use crate::resource::{age_days, format_timestamp, get_color_for_value, ColumnDef, ResourceDef, RAW_FIELD};
+use theme::Theme;
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
     text::{Line, Span},
     widgets::{
-        Block, Borders, Cell, Paragraph, Row, Scrollbar, ScrollbarOrientation, ScrollbarState,
-        Table, TableState,
+        Block, Borders, Cell, Clear, Paragraph, Row, Scrollbar, ScrollbarOrientation, ScrollbarState,
+        Table, TableState, Wrap,
     },
     Frame,
 };
+use serde_json::Value;
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
 
 pub fn render(f: &mut Frame, app: &App) {
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
-            Constraint::Length(6), // Header (multi-line)
+            Constraint::Length(7), // Header (multi-line)
             Constraint::Min(1),    // Main content (table or describe)
             Constraint::Length(1), // Footer/crumb
         ])
         .split(f.area());
 
+    // Tables record their layout as they render; views without one leave it cleared
+    app.table_layout.set(None);
+
     // Header - multi-line with context info
     header::render(f, app, chunks[0]);
 
+    // Sticky error banner takes the first line of the main area
+    let main_area = match &app.sticky_error {
+        Some(message) => {
+            let split = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([Constraint::Length(1), Constraint::Min(1)])
+                .split(chunks[1]);
+            render_sticky_error(f, app, message, split[0]);
+            split[1]
+        }
+        None => chunks[1],
+    };
+
     // Main content - depends on mode and view
     match app.mode {
         Mode::Profiles => {
-            profiles::render(f, app, chunks[1]);
+            profiles::render(f, app, main_area);
         }
         Mode::Regions => {
-            regions::render(f, app, chunks[1]);
+            regions::render(f, app, main_area);
+        }
+        Mode::Search => {
+            search::render(f, app, main_area);
         }
         Mode::Describe => {
-            render_describe_view(f, app, chunks[1]);
+            render_describe_view(f, app, main_area);
         }
         Mode::LogTail => {
-            render_log_tail_view(f, app, chunks[1]);
+            render_log_tail_view(f, app, main_area);
         }
         _ => {
-            render_main_content(f, app, chunks[1]);
+            // Too narrow for a useful table next to the panel: table only
+            let (table_area, panel_area) = if app.details_panel && main_area.width >= MIN_SPLIT_WIDTH {
+                let split = Layout::default()
+                    .direction(Direction::Horizontal)
+                    .constraints([Constraint::Percentage(60), Constraint::Percentage(40)])
+                    .split(main_area);
+                (split[0], Some(split[1]))
+            } else {
+                (main_area, None)
+            };
+            render_main_content(f, app, table_area);
+            if let Some(panel_area) = panel_area {
+                render_details_panel(f, app, panel_area);
+            }
+            if app.loading {
+                render_loading_overlay(f, app, main_area);
+            }
         }
     }
 
@@ -59,16 +105,99 @@ pub fn render(f: &mut Frame, app: &App) {
         Mode::Help => {
             help::render(f, app);
         }
-        Mode::Confirm | Mode::Warning | Mode::SsoLogin => {
+        Mode::Confirm | Mode::Warning | Mode::SsoLogin | Mode::Input | Mode::ErrorDetail | Mode::ConfirmQuit | Mode::Reveal => {
             dialog::render(f, app);
         }
-        Mode::Command => {
+        Mode::Command | Mode::QuickSwitch => {
             command_box::render(f, app);
         }
+        Mode::Metrics => {
+            metrics::render(f, app);
+        }
+        Mode::Columns => {
+            columns::render(f, app);
+        }
+        Mode::Favorites => {
+            favorites::render(f, app);
+        }
         _ => {}
     }
 }
 
+/// Narrowest main area that is split into table and details panel
+const MIN_SPLIT_WIDTH: u16 = 100;
+
+/// Fields of the selected row beside the table (toggled with `I`)
+fn render_details_panel(f: &mut Frame, app: &App, area: Rect) {
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(app.theme.muted())
+        .title(Span::styled(
+            " Details ",
+            app.theme.title().add_modifier(Modifier::BOLD),
+        ))
+        .title_alignment(Alignment::Center);
+
+    let lines = match app.selected_item() {
+        Some(item) => {
+            // The table's projected fields; the untouched API item is for Describe
+            let mut item = item.clone();
+            if let Some(map) = item.as_object_mut() {
+                map.remove(RAW_FIELD);
+            }
+            details::formatted_lines(&item, &app.theme)
+        }
+        None => vec![Line::from(Span::styled(" No selection", app.theme.muted()))],
+    };
+
+    let paragraph = Paragraph::new(lines).block(block).wrap(Wrap { trim: false });
+    f.render_widget(paragraph, area);
+}
+
+/// "Loading KMS Keys", or "Describing 37/120 KMS Keys" once an N+1 list reports progress
+fn loading_text(app: &App) -> String {
+    let name = app
+        .current_resource()
+        .map(|r| r.display_name.as_str())
+        .unwrap_or("resources");
+    match app.fetch_progress.get() {
+        Some((done, total)) => format!("Describing {}/{} {}", done, total, name),
+        None => format!("Loading {}", name),
+    }
+}
+
+/// One-line banner holding the last error until a list loads or `X` dismisses it
+fn render_sticky_error(f: &mut Frame, app: &App, message: &str, area: Rect) {
+    // The keys go first so a long message can't push them off screen
+    let text = format!(" Error (e: details, X: dismiss): {}", message);
+    let width = area.width as usize;
+    let banner = Paragraph::new(truncate_string(&text, width)).style(app.theme.badge(app.theme.error));
+    f.render_widget(banner, area);
+}
+
+fn render_loading_overlay(f: &mut Frame, app: &App, area: Rect) {
+    let spinner = splash::SPINNER_FRAMES[app.spinner_frame % splash::SPINNER_FRAMES.len()];
+    let text = format!(" {} {}... ", spinner, loading_text(app));
+
+    let width = (text.chars().count() as u16 + 2).min(area.width);
+    let height = 3.min(area.height);
+    let overlay = Rect {
+        x: area.x + (area.width.saturating_sub(width)) / 2,
+        y: area.y + (area.height.saturating_sub(height)) / 2,
+        width,
+        height,
+    };
+
+    f.render_widget(Clear, overlay);
+    let paragraph = Paragraph::new(Span::styled(text, app.theme.warning()))
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .border_style(app.theme.warning()),
+        );
+    f.render_widget(paragraph, overlay);
+}
+
 fn render_main_content(f: &mut Frame, app: &App, area: Rect) {
     // If filter is active or has text, show filter input above table
     let show_filter = app.filter_active || !app.filter_text.is_empty();
@@ -88,11 +217,9 @@ fn render_main_content(f: &mut Frame, app: &App, area: Rect) {
 
 fn render_filter_bar(f: &mut Frame, app: &App, area: Rect) {
     let cursor_style = if app.filter_active {
-        Style::default()
-            .fg(Color::Yellow)
-            .add_modifier(Modifier::BOLD)
+        app.theme.header().add_modifier(Modifier::BOLD)
     } else {
-        Style::default().fg(Color::DarkGray)
+        app.theme.muted()
     };
 
     let filter_display = if app.filter_active {
@@ -105,10 +232,16 @@ fn render_filter_bar(f: &mut Frame, app: &App, area: Rect) {
     f.render_widget(paragraph, area);
 }
 
+/// Cell text beyond this many columns is cut in percentage-width columns
+const FIXED_COLUMN_MAX_WIDTH: usize = 38;
+
+/// Widest a `flex` column grows when it doesn't set `max_width`
+const DEFAULT_FLEX_MAX_WIDTH: usize = 60;
+
 /// Render dynamic table based on current resource definition
 fn render_dynamic_table(f: &mut Frame, app: &App, area: Rect) {
     let Some(resource) = app.current_resource() else {
-        let msg = Paragraph::new("Unknown resource").style(Style::default().fg(Color::Red));
+        let msg = Paragraph::new("Unknown resource").style(app.theme.error());
         f.render_widget(msg, area);
         return;
     };
@@ -130,6 +263,13 @@ fn render_dynamic_table(f: &mut Frame, app: &App, area: Rect) {
             String::new()
         };
 
+        let region = if app.all_regions { "all regions" } else { app.region.as_str() };
+        let page_info = if app.marked.is_empty() {
+            page_info
+        } else {
+            format!("{} {} marked", page_info, app.marked.len())
+        };
+
         if is_global {
             if app.filter_text.is_empty() {
                 format!(" {}[{}]{} ", resource.display_name, count, page_info)
@@ -142,12 +282,12 @@ fn render_dynamic_table(f: &mut Frame, app: &App, area: Rect) {
         } else if app.filter_text.is_empty() {
             format!(
                 " {}({})[{}]{} ",
-                resource.display_name, app.region, count, page_info
+                resource.display_name, region, count, page_info
             )
         } else {
             format!(
                 " {}({})[{}/{}]{} ",
-                resource.display_name, app.region, count, total, page_info
+                resource.display_name, region, count, total, page_info
             )
         }
     };
@@ -155,12 +295,10 @@ fn render_dynamic_table(f: &mut Frame, app: &App, area: Rect) {
     // Create the bordered box with centered title
     let block = Block::default()
         .borders(Borders::ALL)
-        .border_style(Style::default().fg(Color::DarkGray))
+        .border_style(app.theme.muted())
         .title(Span::styled(
             title,
-            Style::default()
-                .fg(Color::Cyan)
-                .add_modifier(Modifier::BOLD),
+            app.theme.title().add_modifier(Modifier::BOLD),
         ))
         .title_alignment(Alignment::Center);
 
@@ -168,58 +306,171 @@ fn render_dynamic_table(f: &mut Frame, app: &App, area: Rect) {
     f.render_widget(block, area);
 
     // Build header from column definitions with left padding
-    let header_cells = resource.columns.iter().map(|col| {
+    let columns = app.table_columns();
+    let header_cells = columns.iter().map(|col| {
         Cell::from(format!(" {}", col.header)).style(
-            Style::default()
-                .fg(Color::Yellow)
-                .add_modifier(Modifier::BOLD),
+            app.theme.header().add_modifier(Modifier::BOLD),
         )
     });
     let header = Row::new(header_cells).height(1);
 
-    // Build rows from filtered items with left padding
-    let rows = app.filtered_items.iter().map(|item| {
-        let cells = resource.columns.iter().map(|col| {
-            let value = extract_json_value(item, &col.json_path);
-            let style = get_cell_style(&value, col);
-            let display_value = format_cell_value(&value, col);
-            Cell::from(format!(" {}", truncate_string(&display_value, 38))).style(style)
+    // Flex columns fit their longest value; the rest keep their share of the width
+    let flex_widths: Vec<Option<usize>> = columns
+        .iter()
+        .map(|col| col.flex.then(|| content_width(col, &app.filtered_items, app.timezone)))
+        .collect();
+
+    // Build rows from filtered items with left padding; marked rows get a `*` in it
+    let item_rows = app.filtered_items.iter().map(|item| {
+        let marked = app.is_marked(item);
+        let cells = columns.iter().zip(&flex_widths).enumerate().map(|(i, (col, flex_width))| {
+            let value = col.value(item);
+            let style = get_cell_style(&value, col, &app.theme);
+            let display_value = format_cell_value(&value, col, app.timezone);
+            let max_width = flex_width.unwrap_or(FIXED_COLUMN_MAX_WIDTH);
+            let padding = if marked && i == 0 { "*" } else { " " };
+            Cell::from(format!("{}{}", padding, truncate_string(&display_value, max_width))).style(style)
         });
-        Row::new(cells)
+        let row = Row::new(cells);
+        if marked {
+            row.style(app.theme.accent())
+        } else {
+            row
+        }
     });
 
-    // Build column widths
-    let widths: Vec<Constraint> = resource
-        .columns
+    // When grouped, a header row with the group's value and size precedes each group
+    let group_starts = app.group_starts();
+    let (rows, selected_row): (Vec<Row>, usize) = match app.group_column() {
+        Some(group_column) => {
+            let mut rows = Vec::with_capacity(app.filtered_items.len() + group_starts.len());
+            let mut next_group = 0;
+            for (i, row) in item_rows.enumerate() {
+                if group_starts.get(next_group) == Some(&i) {
+                    let end = group_starts.get(next_group + 1).copied().unwrap_or(app.filtered_items.len());
+                    let value = group_column.value(&app.filtered_items[i]);
+                    let label = format!(
+                        " ▾ {} ({})",
+                        format_cell_value(&value, group_column, app.timezone),
+                        end - i
+                    );
+                    rows.push(Row::new(vec![Cell::from(label)]).style(app.theme.title().add_modifier(Modifier::BOLD)));
+                    next_group += 1;
+                }
+                rows.push(row);
+            }
+            let headers_before = group_starts.iter().filter(|&&start| start <= app.selected).count();
+            (rows, app.selected + headers_before)
+        }
+        None => (item_rows.collect(), app.selected),
+    };
+    let row_count = rows.len();
+
+    // Build column widths (+1 for the left padding)
+    let widths: Vec<Constraint> = columns
         .iter()
-        .map(|col| Constraint::Percentage(col.width))
+        .zip(&flex_widths)
+        .map(|(col, flex_width)| match flex_width {
+            Some(width) => Constraint::Length(*width as u16 + 1),
+            None => Constraint::Percentage(col.width),
+        })
         .collect();
 
-    let table = Table::new(rows, widths).header(header).row_highlight_style(
-        Style::default()
-            .bg(Color::DarkGray)
-            .fg(Color::White)
-            .add_modifier(Modifier::BOLD),
-    );
+    let table = Table::new(rows, widths)
+        .header(header)
+        .row_highlight_style(app.theme.selected().add_modifier(Modifier::BOLD));
 
-    let mut state = TableState::default();
-    state.select(Some(app.selected));
+    // Start from the previous frame's offset so ratatui only scrolls as far as
+    // needed to keep the selection visible, instead of recomputing from the top.
+    // Don't leave empty space below the last row when the list has shrunk.
+    let visible_rows = inner_area.height.saturating_sub(1) as usize;
+    let max_offset = row_count.saturating_sub(visible_rows);
+    let mut state = TableState::default()
+        .with_offset(app.table_offset.get().min(max_offset))
+        .with_selected(Some(selected_row));
 
     f.render_stateful_widget(table, inner_area, &mut state);
+    app.table_offset.set(state.offset());
+    record_table_layout(app, inner_area, &state);
+
+    if app.filtered_items.is_empty() && !app.loading {
+        render_empty_state(f, app, resource, inner_area);
+    }
+}
+
+/// Say why the table is empty, so an empty list doesn't look like a broken one
+fn render_empty_state(f: &mut Frame, app: &App, resource: &ResourceDef, table_area: Rect) {
+    let (message, style) = if !app.items.is_empty() {
+        (
+            format!("No {} match '{}' - Esc clears the filter", resource.display_name, app.filter_text),
+            app.theme.muted(),
+        )
+    } else {
+        match app.last_fetch {
+            Some(FetchOutcome::Empty) => (
+                format!(
+                    "No {} returned - permissions or filters can also hide items; R refreshes",
+                    resource.display_name
+                ),
+                app.theme.muted(),
+            ),
+            Some(FetchOutcome::Failed) => (
+                format!("Couldn't list {} - press e for the full error", resource.display_name),
+                app.theme.error(),
+            ),
+            // Still loading, or nothing fetched for this view yet
+            Some(FetchOutcome::Items) | None => return,
+        }
+    };
+
+    // Leave the header row visible
+    if table_area.height < 3 {
+        return;
+    }
+    let area = Rect {
+        y: table_area.y + 2,
+        height: 1,
+        ..table_area
+    };
+    let paragraph = Paragraph::new(Span::styled(message, style)).alignment(Alignment::Center);
+    f.render_widget(paragraph, area);
+}
+
+/// Remember where table rows landed so mouse clicks can be mapped back to rows
+fn record_table_layout(app: &App, table_area: Rect, state: &TableState) {
+    // Every table has a one-line header above the rows
+    let rows = Rect {
+        y: table_area.y + 1,
+        height: table_area.height.saturating_sub(1),
+        ..table_area
+    };
+    app.table_layout.set(Some(TableLayout {
+        rows,
+        offset: state.offset(),
+    }));
 }
 
 /// Get cell style based on value and column definition
-fn get_cell_style(value: &str, col: &ColumnDef) -> Style {
+fn get_cell_style(value: &str, col: &ColumnDef, theme: &Theme) -> Style {
+    if let Some(warn_days) = col.age_warn_days {
+        if age_days(value).is_some_and(|age| age >= i64::from(warn_days)) {
+            return theme.error().add_modifier(Modifier::BOLD);
+        }
+    }
     if let Some(ref color_map_name) = col.color_map {
         if let Some([r, g, b]) = get_color_for_value(color_map_name, value) {
-            return Style::default().fg(Color::Rgb(r, g, b));
+            return theme.fg(Color::Rgb(r, g, b));
         }
     }
     Style::default()
 }
 
-/// Format cell value, adding indicators for transitional states
-fn format_cell_value(value: &str, col: &ColumnDef) -> String {
+/// Format cell value: timestamps per the column format, plus indicators for transitional states
+fn format_cell_value(value: &str, col: &ColumnDef, timezone: DisplayTimezone) -> String {
+    if let Some(format) = col.format {
+        return format_timestamp(value, format, timezone);
+    }
+
     // Check if this is a state/status column with transitional states
     if col.color_map.is_some() {
         let lower = value.to_lowercase();
@@ -243,38 +494,104 @@ fn format_cell_value(value: &str, col: &ColumnDef) -> String {
     value.to_string()
 }
 
-/// Truncate string for display
-fn truncate_string(s: &str, max_len: usize) -> String {
-    if s.len() > max_len {
-        format!("{}...", &s[..max_len.saturating_sub(3)])
-    } else {
-        s.to_string()
+/// Longest text (header or cell) of a flex column, capped at its `max_width`
+fn content_width(col: &ColumnDef, items: &[Value], timezone: DisplayTimezone) -> usize {
+    let max_width = col.max_width.map_or(DEFAULT_FLEX_MAX_WIDTH, usize::from);
+    items
+        .iter()
+        .map(|item| format_cell_value(&col.value(item), col, timezone).width())
+        .chain(std::iter::once(col.header.width()))
+        .max()
+        .unwrap_or(0)
+        .min(max_width)
+}
+
+/// Shorten `s` to at most `max_width` terminal columns, ending in `...` when cut
+///
+/// Counts display width rather than bytes, so wide characters (CJK, emoji)
+/// take two columns and multi-byte characters are never split.
+fn truncate_string(s: &str, max_width: usize) -> String {
+    if s.width() <= max_width {
+        return s.to_string();
+    }
+    let budget = max_width.saturating_sub(3);
+    let mut width = 0;
+    let mut truncated = String::new();
+    for c in s.chars() {
+        let char_width = c.width().unwrap_or(0);
+        if width + char_width > budget {
+            break;
+        }
+        width += char_width;
+        truncated.push(c);
     }
+    truncated.push_str("...");
+    truncated
 }
 
 fn render_describe_view(f: &mut Frame, app: &App, area: Rect) {
-    let json = app
-        .selected_item_json()
-        .unwrap_or_else(|| "No item selected".to_string());
+    // Formatted sections or JSON syntax highlighting, then overlay search matches
+    let base_lines: Vec<Line> = match app.describe_value() {
+        Some(value) if app.describe_formatted => details::formatted_lines(&value, &app.theme),
+        _ => app
+            .selected_item_json()
+            .unwrap_or_else(|| "No item selected".to_string())
+            .lines()
+            .map(|l| highlight_json_line(l, &app.theme))
+            .collect(),
+    };
 
-    // Apply JSON syntax highlighting
-    let lines: Vec<Line> = json.lines().map(|l| highlight_json_line(l)).collect();
+    let term = app.describe_search_text.to_ascii_lowercase();
+    let matches = app.describe_search_matches();
+    let current_match = matches.get(app.describe_search_match).copied();
+    let lines: Vec<Line> = base_lines
+        .into_iter()
+        .enumerate()
+        .map(|(i, line)| {
+            if term.is_empty() {
+                return line;
+            }
+            let bg = if Some(i) == current_match {
+                app.theme.accent
+            } else {
+                app.theme.warning
+            };
+            highlight_search_matches(line, &term, app.theme.badge(bg))
+        })
+        .collect();
     let total_lines = lines.len();
 
-    let title = if let Some(resource) = app.current_resource() {
-        format!(" {} Details ", resource.display_name)
+    let view = match (app.describe_projected, app.describe_formatted) {
+        (true, true) => "[projected, formatted] ",
+        (true, false) => "[projected] ",
+        (false, true) => "[formatted] ",
+        (false, false) => "",
+    };
+    let base_title = if let Some(resource) = app.current_resource() {
+        format!(" {} Details {}", resource.display_name, view)
     } else {
-        " Details ".to_string()
+        format!(" Details {}", view)
+    };
+    let title = if term.is_empty() {
+        base_title
+    } else if matches.is_empty() {
+        format!("{}[/{}: no matches] ", base_title, app.describe_search_text)
+    } else {
+        format!(
+            "{}[/{}: {}/{}] ",
+            base_title,
+            app.describe_search_text,
+            app.describe_search_match + 1,
+            matches.len()
+        )
     };
 
     let block = Block::default()
         .borders(Borders::ALL)
-        .border_style(Style::default().fg(Color::Cyan))
+        .border_style(app.theme.title())
         .title(Span::styled(
             title,
-            Style::default()
-                .fg(Color::Cyan)
-                .add_modifier(Modifier::BOLD),
+            app.theme.title().add_modifier(Modifier::BOLD),
         ));
 
     let inner_area = block.inner(area);
@@ -300,9 +617,59 @@ fn render_describe_view(f: &mut Frame, app: &App, area: Rect) {
     }
 }
 
+/// Apply `style` on top of every occurrence of `term` (already ASCII-lowercased) in a line
+fn highlight_search_matches(line: Line<'static>, term: &str, style: Style) -> Line<'static> {
+    let text: String = line.spans.iter().map(|s| s.content.as_ref()).collect();
+    // ASCII lowercasing keeps byte offsets identical to the original text
+    let ranges: Vec<(usize, usize)> = text
+        .to_ascii_lowercase()
+        .match_indices(term)
+        .map(|(start, m)| (start, start + m.len()))
+        .collect();
+    if ranges.is_empty() {
+        return line;
+    }
+
+    let mut spans: Vec<Span<'static>> = Vec::new();
+    let mut offset = 0;
+    for span in line.spans {
+        let content = span.content.as_ref();
+        let span_start = offset;
+        let span_end = offset + content.len();
+        let mut cursor = span_start;
+
+        for &(match_start, match_end) in &ranges {
+            if match_end <= cursor || match_start >= span_end {
+                continue;
+            }
+            let start = match_start.max(cursor);
+            let end = match_end.min(span_end);
+            if start > cursor {
+                spans.push(Span::styled(
+                    content[cursor - span_start..start - span_start].to_string(),
+                    span.style,
+                ));
+            }
+            spans.push(Span::styled(
+                content[start - span_start..end - span_start].to_string(),
+                span.style.patch(style),
+            ));
+            cursor = end;
+        }
+        if cursor < span_end {
+            spans.push(Span::styled(
+                content[cursor - span_start..].to_string(),
+                span.style,
+            ));
+        }
+        offset = span_end;
+    }
+    Line::from(spans)
+}
+
 fn render_log_tail_view(f: &mut Frame, app: &App, area: Rect) {
     let Some(ref state) = app.log_tail_state else {
-        let msg = Paragraph::new("No log tail state").style(Style::default().fg(Color::Red));
+        let msg = Paragraph::new("No log tail state").style(app.theme.error());
         f.render_widget(msg, area);
         return;
     };
@@ -310,20 +677,18 @@ fn render_log_tail_view(f: &mut Frame, app: &App, area: Rect) {
     // Build title with stream info and status
     let status = if state.paused { "PAUSED" } else { "LIVE" };
     let status_color = if state.paused {
-        Color::Yellow
+        app.theme.warning
     } else {
-        Color::Green
+        app.theme.success
     };
     let title = format!(" {} | {} ", state.log_stream, status);
 
     let block = Block::default()
         .borders(Borders::ALL)
-        .border_style(Style::default().fg(Color::Cyan))
+        .border_style(app.theme.title())
         .title(Span::styled(
             title,
-            Style::default()
-                .fg(status_color)
-                .add_modifier(Modifier::BOLD),
+            app.theme.fg(status_color).add_modifier(Modifier::BOLD),
         ));
 
     let inner_area = block.inner(area);
@@ -331,9 +696,9 @@ fn render_log_tail_view(f: &mut Frame, app: &App, area: Rect) {
 
     if state.events.is_empty() {
         let msg = if let Some(ref err) = state.error {
-            Paragraph::new(format!("Error: {}", err)).style(Style::default().fg(Color::Red))
+            Paragraph::new(format!("Error: {}", err)).style(app.theme.error())
         } else {
-            Paragraph::new("Waiting for log events...").style(Style::default().fg(Color::DarkGray))
+            Paragraph::new("Waiting for log events...").style(app.theme.muted())
         };
         f.render_widget(msg, inner_area);
         return;
@@ -352,24 +717,24 @@ fn render_log_tail_view(f: &mut Frame, app: &App, area: Rect) {
                 || message.contains("error")
                 || message.contains("Error")
             {
-                Style::default().fg(Color::Red)
+                app.theme.error()
             } else if message.contains("WARN")
                 || message.contains("warn")
                 || message.contains("Warning")
             {
-                Style::default().fg(Color::Yellow)
+                app.theme.warning()
             } else if message.contains("INFO") || message.contains("info") {
-                Style::default().fg(Color::Green)
+                app.theme.success()
             } else if message.contains("DEBUG") || message.contains("debug") {
-                Style::default().fg(Color::Blue)
+                app.theme.fg(Color::Blue)
             } else {
-                Style::default().fg(Color::White)
+                app.theme.text()
             };
 
             Line::from(vec![
                 Span::styled(
                     format!("[{}] ", timestamp),
-                    Style::default().fg(Color::DarkGray),
+                    app.theme.muted(),
                 ),
                 Span::styled(message.trim_end().to_string(), msg_style),
             ])
@@ -395,7 +760,7 @@ fn render_log_tail_view(f: &mut Frame, app: &App, area: Rect) {
 }
 
 /// Apply JSON syntax highlighting to a single line
-fn highlight_json_line(line: &str) -> Line<'static> {
+fn highlight_json_line(line: &str, theme: &Theme) -> Line<'static> {
     let mut spans: Vec<Span<'static>> = Vec::new();
     let mut chars = line.chars().peekable();
     let mut current = String::new();
@@ -427,9 +792,9 @@ fn highlight_json_line(line: &str) -> Line<'static> {
 
                 // Color based on whether it's a key or value
                 let style = if is_key {
-                    Style::default().fg(Color::Cyan) // Keys in cyan
+                    theme.title()
                 } else {
-                    Style::default().fg(Color::Green) // String values in green
+                    theme.success()
                 };
                 spans.push(Span::styled(string_content, style));
             }
@@ -437,7 +802,7 @@ fn highlight_json_line(line: &str) -> Line<'static> {
                 current.push(c);
                 spans.push(Span::styled(
                     current.clone(),
-                    Style::default().fg(Color::White),
+                    theme.text(),
                 ));
                 current.clear();
                 is_key = false; // After colon, we're parsing a value
@@ -445,25 +810,25 @@ fn highlight_json_line(line: &str) -> Line<'static> {
             ',' => {
                 if !current.is_empty() {
                     // Check if it's a number or keyword
-                    let style = get_json_value_style(&current);
+                    let style = get_json_value_style(&current, theme);
                     spans.push(Span::styled(current.clone(), style));
                     current.clear();
                 }
                 spans.push(Span::styled(
                     ",".to_string(),
-                    Style::default().fg(Color::White),
+                    theme.text(),
                 ));
                 is_key = true; // After comma, next string is a key
             }
             '{' | '}' | '[' | ']' => {
                 if !current.is_empty() {
-                    let style = get_json_value_style(&current);
+                    let style = get_json_value_style(&current, theme);
                     spans.push(Span::styled(current.clone(), style));
                     current.clear();
                 }
                 spans.push(Span::styled(
                     c.to_string(),
-                    Style::default().fg(Color::Yellow),
+                    theme.header(),
                 ));
                 if c == '{' || c == '[' {
                     is_key = c == '{'; // After {, next is key; after [, next is value
@@ -471,7 +836,7 @@ fn highlight_json_line(line: &str) -> Line<'static> {
             }
             ' ' | '\t' => {
                 if !current.is_empty() {
-                    let style = get_json_value_style(&current);
+                    let style = get_json_value_style(&current, theme);
                     spans.push(Span::styled(current.clone(), style));
                     current.clear();
                 }
@@ -484,7 +849,7 @@ fn highlight_json_line(line: &str) -> Line<'static> {
     }
 
     if !current.is_empty() {
-        let style = get_json_value_style(&current);
+        let style = get_json_value_style(&current, theme);
         spans.push(Span::styled(current, style));
     }
 
@@ -492,23 +857,22 @@ fn highlight_json_line(line: &str) -> Line<'static> {
 }
 
 /// Get style for JSON values (numbers, booleans, null)
-fn get_json_value_style(value: &str) -> Style {
+fn get_json_value_style(value: &str, theme: &Theme) -> Style {
     let trimmed = value.trim();
     if trimmed == "null" {
-        Style::default().fg(Color::DarkGray)
+        theme.muted()
     } else if trimmed == "true" || trimmed == "false" {
-        Style::default().fg(Color::Magenta)
+        theme.accent()
     } else if trimmed.parse::<f64>().is_ok() {
-        Style::default().fg(Color::LightBlue)
+        theme.fg(Color::LightBlue)
     } else {
-        Style::default().fg(Color::White)
+        theme.text()
     }
 }
 
 fn render_crumb(f: &mut Frame, app: &App, area: Rect) {
     // Build breadcrumb from navigation
     let breadcrumb = app.get_breadcrumb();
-    let crumb_display = breadcrumb.join(" > ");
 
     // Build sub-resource shortcuts hint
     let shortcuts_hint = if let Some(resource) = app.current_resource() {
@@ -540,32 +904,64 @@ fn render_crumb(f: &mut Frame, app: &App, area: Rect) {
         String::new()
     };
 
-    let status_text = if let Some(err) = &app.error_message {
-        format!("Error: {}", err)
+    let status_text = if let Some(status) = app.status() {
+        status.to_string()
+    } else if let Some(err) = &app.error_message {
+        format!("Error: {} (e: details)", err)
+    } else if app.actions_in_flight > 0 {
+        format!(
+            "{} {} action(s) running...",
+            splash::SPINNER_FRAMES[app.spinner_frame % splash::SPINNER_FRAMES.len()],
+            app.actions_in_flight
+        )
     } else if app.loading {
-        "Loading...".to_string()
+        let spinner = splash::SPINNER_FRAMES[app.spinner_frame % splash::SPINNER_FRAMES.len()];
+        match app.fetch_progress.get() {
+            Some(_) => format!("{} {}...", spinner, loading_text(app)),
+            None => format!("{} Loading...", spinner),
+        }
+    } else if app.mode == Mode::Describe && app.describe_search_active {
+        format!("/{}_ | Enter: search | Esc: cancel", app.describe_search_text)
+    } else if app.mode == Mode::Describe && !app.describe_search_text.is_empty() {
+        "n/N: next/prev match | /: search | Esc: clear | q/d: back".to_string()
     } else if app.mode == Mode::Describe {
-        "j/k: scroll | q/d/Esc: back".to_string()
+        "j/k: scroll | /: search | f: json/formatted | r: raw/projected | q/d/Esc: back".to_string()
+    } else if app.mode == Mode::Search {
+        match app.search_state.as_ref().filter(|s| !s.failed.is_empty()) {
+            Some(search) => format!("Enter: open | Esc: back | could not list: {}", search.failed.join(", ")),
+            None => "Enter: open | Esc: back".to_string(),
+        }
     } else if app.mode == Mode::LogTail {
-        "j/k: scroll | G: bottom (live) | g: top | SPACE: pause | q: exit".to_string()
+        "j/k: scroll | G: bottom (live) | g: top | t/SPACE: stop/start | q: exit".to_string()
+    } else if app.picker_filter_active {
+        "Type to filter | ↑/↓: move | Enter: pick | Esc: clear".to_string()
     } else if app.filter_active {
-        "Type to filter | Enter: apply | Esc: clear".to_string()
+        "Type to filter | ↑/↓: history | Enter: apply | Esc: clear".to_string()
+    } else if let Some(count) = app.pending_count() {
+        format!("{} (j/k: move, G: go to row)", count)
     } else {
         format!("{}{}", shortcuts_hint, pagination_hint)
     };
 
-    let style = if app.error_message.is_some() {
-        Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)
-    } else if app.loading {
-        Style::default().fg(Color::Yellow)
+    let style = if app.status().is_some() {
+        app.theme.success()
+    } else if app.error_message.is_some() {
+        app.theme.error().add_modifier(Modifier::BOLD)
+    } else if app.loading || app.actions_in_flight > 0 {
+        app.theme.warning()
     } else {
-        Style::default().fg(Color::DarkGray)
+        app.theme.muted()
     };
 
+    // The crumb gets priority; status text keeps up to half the line
+    let status_width = status_text.chars().count().min(area.width as usize / 2);
+    let crumb_budget = (area.width as usize).saturating_sub(status_width + 3);
+    let crumb_display = truncate_breadcrumb(&breadcrumb, crumb_budget);
+
     let crumb = Line::from(vec![
         Span::styled(
             format!("<{}>", crumb_display),
-            Style::default().fg(Color::Black).bg(Color::Cyan),
+            app.theme.badge(app.theme.title),
         ),
         Span::raw(" "),
         Span::styled(status_text, style),
@@ -574,3 +970,50 @@ fn render_crumb(f: &mut Frame, app: &App, area: Rect) {
     let paragraph = Paragraph::new(crumb);
     f.render_widget(paragraph, area);
 }
+
+/// Join breadcrumb segments with " > ", collapsing the middle to "…" when
+/// they don't fit in `max_width`. The current resource and its immediate
+/// parent are always kept; the root is kept when there is room for it.
+fn truncate_breadcrumb(segments: &[String], max_width: usize) -> String {
+    let full = segments.join(" > ");
+    if full.chars().count() <= max_width || segments.len() <= 2 {
+        return full;
+    }
+
+    let tail = &segments[segments.len() - 2..];
+    let with_root = format!("{} > … > {}", segments[0], tail.join(" > "));
+    if segments.len() > 3 && with_root.chars().count() <= max_width {
+        return with_root;
+    }
+    format!("… > {}", tail.join(" > "))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn segments(names: &[&str]) -> Vec<String> {
+        names.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_truncate_breadcrumb_collapses_the_middle() {
+        let path = segments(&["ec2-instances", "i-0abc", "volumes", "vol-1"]);
+        assert_eq!(truncate_breadcrumb(&path, 80), "ec2-instances > i-0abc > volumes > vol-1");
+        assert_eq!(truncate_breadcrumb(&path, 36), "ec2-instances > … > volumes > vol-1");
+        assert_eq!(truncate_breadcrumb(&path, 20), "… > volumes > vol-1");
+        // The last two segments are never dropped, even when they overflow
+        assert_eq!(truncate_breadcrumb(&segments(&["iam-users", "alice"]), 5), "iam-users > alice");
+    }
+
+    #[test]
+    fn test_truncate_string_counts_display_width() {
+        assert_eq!(truncate_string("my-bucket", 20), "my-bucket");
+        assert_eq!(truncate_string("my-long-bucket-name", 10), "my-long...");
+        // Multi-byte characters are kept whole
+        assert_eq!(truncate_string("café-münchen-prod", 10), "café-mü...");
+        // Wide characters take two columns each
+        assert_eq!(truncate_string("日本語のバケット", 9), "日本語...");
+        assert_eq!(truncate_string("日本語のバケット", 8), "日本...");
+    }
+}
@@ -0,0 +1,57 @@
+use crate::app::App;
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::Modifier,
+    text::Line,
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+/// Centered popup listing `lines` above a hint line, sized to fit the rows
+pub fn render(f: &mut Frame, app: &App, title: &str, percent_x: u16, lines: Vec<Line>, hints: &str) {
+    // Rows, hint line and borders
+    let height = lines.len() as u16 + 3;
+    let area = centered_rect(percent_x, height, f.area());
+    f.render_widget(Clear, area);
+
+    let block = Block::default()
+        .title(title)
+        .title_style(app.theme.title().add_modifier(Modifier::BOLD))
+        .borders(Borders::ALL)
+        .border_style(app.theme.title());
+
+    let inner = block.inner(area);
+    f.render_widget(block, area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(1), Constraint::Length(1)])
+        .split(inner);
+
+    f.render_widget(Paragraph::new(lines), chunks[0]);
+
+    let hints = Paragraph::new(hints)
+        .style(app.theme.muted())
+        .alignment(Alignment::Center);
+    f.render_widget(hints, chunks[1]);
+}
+
+fn centered_rect(percent_x: u16, height: u16, r: Rect) -> Rect {
+    let popup_layout = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Min(0),
+            Constraint::Length(height),
+            Constraint::Min(0),
+        ])
+        .split(r);
+
+    Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Percentage((100 - percent_x) / 2),
+            Constraint::Percentage(percent_x),
+            Constraint::Percentage((100 - percent_x) / 2),
+        ])
+        .split(popup_layout[1])[1]
+}
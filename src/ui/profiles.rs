@@ -1,7 +1,7 @@
 use crate::app::App;
 use ratatui::{
-    layout::{Alignment, Rect},
-    style::{Color, Modifier, Style},
+    layout::{Alignment, Constraint, Rect},
+    style::{Modifier, Style},
     text::Span,
     widgets::{Block, Borders, Cell, Row, Table, TableState},
     Frame,
@@ -9,60 +9,76 @@ use ratatui::{
 
 pub fn render(f: &mut Frame, app: &App, area: Rect) {
     // Create bordered box with centered title
-    let title = format!(" Profiles[{}] ", app.available_profiles.len());
+    let profiles = app.visible_profiles();
+    let title = if app.picker_filter_active || !app.picker_filter.is_empty() {
+        let cursor = if app.picker_filter_active { "_" } else { "" };
+        format!(
+            " Profiles[{}/{}] /{}{} ",
+            profiles.len(),
+            app.available_profiles.len(),
+            app.picker_filter,
+            cursor
+        )
+    } else {
+        format!(" Profiles[{}] ", profiles.len())
+    };
     let block = Block::default()
         .borders(Borders::ALL)
-        .border_style(Style::default().fg(Color::DarkGray))
+        .border_style(app.theme.muted())
         .title(Span::styled(
             title,
-            Style::default()
-                .fg(Color::Cyan)
-                .add_modifier(Modifier::BOLD),
+            app.theme.title().add_modifier(Modifier::BOLD),
         ))
         .title_alignment(Alignment::Center);
 
     let inner_area = block.inner(area);
     f.render_widget(block, area);
 
-    let header_cells = [" PROFILE"].iter().map(|h| {
+    let header_cells = [" PROFILE", " REGION", " AUTH", " SSO SESSION"].iter().map(|h| {
         Cell::from(*h).style(
-            Style::default()
-                .fg(Color::Yellow)
-                .add_modifier(Modifier::BOLD),
+            app.theme.header().add_modifier(Modifier::BOLD),
         )
     });
 
     let header = Row::new(header_cells).height(1);
 
-    let rows = app.available_profiles.iter().map(|profile| {
-        let style = if profile == &app.profile {
-            Style::default().fg(Color::Green)
+    let rows = profiles.iter().map(|profile| {
+        let is_current = profile.name == app.profile;
+        let style = if is_current {
+            app.theme.success()
         } else {
             Style::default()
         };
 
-        let marker = if profile == &app.profile {
+        let marker = if is_current {
             " * "
         } else {
             "   "
         };
 
         Row::new(vec![
-            Cell::from(format!("{}{}", marker, profile)).style(style)
+            Cell::from(format!("{}{}", marker, profile.name)),
+            Cell::from(format!(" {}", profile.region.as_deref().unwrap_or("-"))),
+            Cell::from(format!(" {}", profile.auth_kind())),
+            Cell::from(format!(" {}", profile.sso_session.as_deref().unwrap_or("-"))),
         ])
+        .style(style)
     });
 
-    let widths = [ratatui::layout::Constraint::Percentage(100)];
+    let widths = [
+        Constraint::Percentage(40),
+        Constraint::Percentage(20),
+        Constraint::Percentage(10),
+        Constraint::Percentage(30),
+    ];
 
-    let table = Table::new(rows, widths).header(header).row_highlight_style(
-        Style::default()
-            .bg(Color::DarkGray)
-            .fg(Color::White)
-            .add_modifier(Modifier::BOLD),
-    );
+    let table = Table::new(rows, widths)
+        .header(header)
+        .row_highlight_style(app.theme.selected().add_modifier(Modifier::BOLD));
 
     let mut state = TableState::default();
     state.select(Some(app.profiles_selected));
 
     f.render_stateful_widget(table, inner_area, &mut state);
+    super::record_table_layout(app, inner_area, &state);
 }
@@ -1,7 +1,8 @@
 use crate::app::App;
+use crate::aws::profiles::region_name;
 use ratatui::{
-    layout::{Alignment, Rect},
-    style::{Color, Modifier, Style},
+    layout::{Alignment, Constraint, Rect},
+    style::{Modifier, Style},
     text::Span,
     widgets::{Block, Borders, Cell, Row, Table, TableState},
     Frame,
@@ -9,34 +10,42 @@ use ratatui::{
 
 pub fn render(f: &mut Frame, app: &App, area: Rect) {
     // Create bordered box with centered title
-    let title = format!(" Regions[{}] ", app.available_regions.len());
+    let regions = app.visible_regions();
+    let title = if app.picker_filter_active || !app.picker_filter.is_empty() {
+        let cursor = if app.picker_filter_active { "_" } else { "" };
+        format!(
+            " Regions[{}/{}] /{}{} ",
+            regions.len(),
+            app.available_regions.len(),
+            app.picker_filter,
+            cursor
+        )
+    } else {
+        format!(" Regions[{}] ", regions.len())
+    };
     let block = Block::default()
         .borders(Borders::ALL)
-        .border_style(Style::default().fg(Color::DarkGray))
+        .border_style(app.theme.muted())
         .title(Span::styled(
             title,
-            Style::default()
-                .fg(Color::Cyan)
-                .add_modifier(Modifier::BOLD),
+            app.theme.title().add_modifier(Modifier::BOLD),
         ))
         .title_alignment(Alignment::Center);
 
     let inner_area = block.inner(area);
     f.render_widget(block, area);
 
-    let header_cells = [" REGION"].iter().map(|h| {
+    let header_cells = [" REGION", " NAME"].iter().map(|h| {
         Cell::from(*h).style(
-            Style::default()
-                .fg(Color::Yellow)
-                .add_modifier(Modifier::BOLD),
+            app.theme.header().add_modifier(Modifier::BOLD),
         )
     });
 
     let header = Row::new(header_cells).height(1);
 
-    let rows = app.available_regions.iter().map(|region| {
+    let rows = regions.iter().map(|region| {
         let style = if region == &app.region {
-            Style::default().fg(Color::Green)
+            app.theme.success()
         } else {
             Style::default()
         };
@@ -44,21 +53,21 @@ pub fn render(f: &mut Frame, app: &App, area: Rect) {
         let marker = if region == &app.region { " * " } else { "   " };
 
         Row::new(vec![
-            Cell::from(format!("{}{}", marker, region)).style(style)
+            Cell::from(format!("{}{}", marker, region)),
+            Cell::from(format!(" {}", region_name(region).unwrap_or("-"))),
         ])
+        .style(style)
     });
 
-    let widths = [ratatui::layout::Constraint::Percentage(100)];
+    let widths = [Constraint::Percentage(30), Constraint::Percentage(70)];
 
-    let table = Table::new(rows, widths).header(header).row_highlight_style(
-        Style::default()
-            .bg(Color::DarkGray)
-            .fg(Color::White)
-            .add_modifier(Modifier::BOLD),
-    );
+    let table = Table::new(rows, widths)
+        .header(header)
+        .row_highlight_style(app.theme.selected().add_modifier(Modifier::BOLD));
 
     let mut state = TableState::default();
     state.select(Some(app.regions_selected));
 
     f.render_stateful_widget(table, inner_area, &mut state);
+    super::record_table_layout(app, inner_area, &state);
 }
@@ -0,0 +1,75 @@
+use crate::app::App;
+use ratatui::{
+    layout::{Alignment, Constraint, Rect},
+    style::Modifier,
+    text::Span,
+    widgets::{Block, Borders, Cell, Paragraph, Row, Table, TableState},
+    Frame,
+};
+
+pub fn render(f: &mut Frame, app: &App, area: Rect) {
+    let Some(search) = &app.search_state else {
+        return;
+    };
+
+    // Create bordered box with centered title
+    let title = format!(" Search({})[{}] '{}' ", app.region, search.results.len(), search.term);
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(app.theme.muted())
+        .title(Span::styled(
+            title,
+            app.theme.title().add_modifier(Modifier::BOLD),
+        ))
+        .title_alignment(Alignment::Center);
+
+    let inner_area = block.inner(area);
+    f.render_widget(block, area);
+
+    if search.searching {
+        let message = format!(" Searching for '{}'...", search.term);
+        f.render_widget(Paragraph::new(message).style(app.theme.muted()), inner_area);
+        return;
+    }
+
+    if search.results.is_empty() {
+        let mut message = format!(" No matches for '{}'", search.term);
+        if !search.failed.is_empty() {
+            message.push_str(&format!(" (could not list: {})", search.failed.join(", ")));
+        }
+        f.render_widget(Paragraph::new(message).style(app.theme.muted()), inner_area);
+        return;
+    }
+
+    let header_cells = [" KIND", " ID", " NAME"].iter().map(|h| {
+        Cell::from(*h).style(
+            app.theme.header().add_modifier(Modifier::BOLD),
+        )
+    });
+
+    let header = Row::new(header_cells).height(1);
+
+    let rows = search.results.iter().map(|result| {
+        Row::new(vec![
+            Cell::from(format!(" {}", result.kind)).style(app.theme.accent()),
+            Cell::from(format!(" {}", result.id)),
+            Cell::from(format!(" {}", result.name)),
+        ])
+    });
+
+    let widths = [
+        Constraint::Percentage(20),
+        Constraint::Percentage(40),
+        Constraint::Percentage(40),
+    ];
+
+    let table = Table::new(rows, widths)
+        .header(header)
+        .row_highlight_style(app.theme.selected().add_modifier(Modifier::BOLD));
+
+    let mut state = TableState::default();
+    state.select(Some(search.selected));
+
+    f.render_stateful_widget(table, inner_area, &mut state);
+    super::record_table_layout(app, inner_area, &state);
+}
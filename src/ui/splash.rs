@@ -1,31 +1,37 @@
+use super::theme::Theme;
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Rect},
-    style::{Color, Modifier, Style},
+    style::Modifier,
     text::{Line, Span},
     widgets::Paragraph,
     Frame,
 };
 
+/// Spinner animation frames (also used by the loading overlay)
+pub const SPINNER_FRAMES: [&str; 4] = ["⠋", "⠙", "⠹", "⠸"];
+
 pub struct SplashState {
     pub current_step: usize,
     pub total_steps: usize,
     pub current_message: String,
     pub spinner_frame: usize,
+    pub theme: Theme,
 }
 
 impl SplashState {
-    pub fn new() -> Self {
+    pub fn new(theme: Theme) -> Self {
         Self {
             current_step: 0,
             total_steps: 6,
             current_message: "Initializing...".to_string(),
             spinner_frame: 0,
+            theme,
         }
     }
 
     pub fn set_message(&mut self, message: &str) {
         self.current_message = message.to_string();
-        self.spinner_frame = (self.spinner_frame + 1) % 4;
+        self.spinner_frame = (self.spinner_frame + 1) % SPINNER_FRAMES.len();
     }
 
     pub fn complete_step(&mut self) {
@@ -61,7 +67,7 @@ pub fn render(f: &mut Frame, splash: &SplashState) {
         .split(center_area);
 
     // Render big ASCII logo
-    render_big_logo(f, content[0]);
+    render_big_logo(f, splash, content[0]);
 
     // Render loading bar
     render_loading_bar(f, splash, content[2]);
@@ -70,52 +76,40 @@ pub fn render(f: &mut Frame, splash: &SplashState) {
     render_status(f, splash, content[4]);
 }
 
-fn render_big_logo(f: &mut Frame, area: Rect) {
+fn render_big_logo(f: &mut Frame, splash: &SplashState, area: Rect) {
     let logo_lines = vec![
         Line::from(Span::styled(
             r"  ████████╗ █████╗ ██╗    ██╗███████╗",
-            Style::default()
-                .fg(Color::Cyan)
-                .add_modifier(Modifier::BOLD),
+            splash.theme.title().add_modifier(Modifier::BOLD),
         )),
         Line::from(Span::styled(
             r"  ╚══██╔══╝██╔══██╗██║    ██║██╔════╝",
-            Style::default()
-                .fg(Color::Cyan)
-                .add_modifier(Modifier::BOLD),
+            splash.theme.title().add_modifier(Modifier::BOLD),
         )),
         Line::from(Span::styled(
             r"     ██║   ███████║██║ █╗ ██║███████╗",
-            Style::default()
-                .fg(Color::Cyan)
-                .add_modifier(Modifier::BOLD),
+            splash.theme.title().add_modifier(Modifier::BOLD),
         )),
         Line::from(Span::styled(
             r"     ██║   ██╔══██║██║███╗██║╚════██║",
-            Style::default()
-                .fg(Color::Cyan)
-                .add_modifier(Modifier::BOLD),
+            splash.theme.title().add_modifier(Modifier::BOLD),
         )),
         Line::from(Span::styled(
             r"     ██║   ██║  ██║╚███╔███╔╝███████║",
-            Style::default()
-                .fg(Color::Cyan)
-                .add_modifier(Modifier::BOLD),
+            splash.theme.title().add_modifier(Modifier::BOLD),
         )),
         Line::from(Span::styled(
             r"     ╚═╝   ╚═╝  ╚═╝ ╚══╝╚══╝ ╚══════╝",
-            Style::default()
-                .fg(Color::Cyan)
-                .add_modifier(Modifier::BOLD),
+            splash.theme.title().add_modifier(Modifier::BOLD),
         )),
         Line::from(""),
         Line::from(Span::styled(
             "Terminal UI for AWS",
-            Style::default().fg(Color::DarkGray),
+            splash.theme.muted(),
         )),
         Line::from(Span::styled(
             crate::VERSION,
-            Style::default().fg(Color::DarkGray),
+            splash.theme.muted(),
         )),
     ];
 
@@ -130,13 +124,13 @@ fn render_loading_bar(f: &mut Frame, splash: &SplashState, area: Rect) {
     let empty = bar_width.saturating_sub(filled);
 
     let bar = Line::from(vec![
-        Span::styled("  [", Style::default().fg(Color::DarkGray)),
-        Span::styled("█".repeat(filled), Style::default().fg(Color::Cyan)),
-        Span::styled("░".repeat(empty), Style::default().fg(Color::DarkGray)),
-        Span::styled("]", Style::default().fg(Color::DarkGray)),
+        Span::styled("  [", splash.theme.muted()),
+        Span::styled("█".repeat(filled), splash.theme.title()),
+        Span::styled("░".repeat(empty), splash.theme.muted()),
+        Span::styled("]", splash.theme.muted()),
         Span::styled(
             format!(" {}%", (progress * 100.0) as u8),
-            Style::default().fg(Color::White),
+            splash.theme.text(),
         ),
     ]);
 
@@ -145,12 +139,11 @@ fn render_loading_bar(f: &mut Frame, splash: &SplashState, area: Rect) {
 }
 
 fn render_status(f: &mut Frame, splash: &SplashState, area: Rect) {
-    let spinner_chars = ["⠋", "⠙", "⠹", "⠸"];
-    let spinner = spinner_chars[splash.spinner_frame % spinner_chars.len()];
+    let spinner = SPINNER_FRAMES[splash.spinner_frame % SPINNER_FRAMES.len()];
 
     let status = Line::from(vec![
-        Span::styled(format!("{} ", spinner), Style::default().fg(Color::Yellow)),
-        Span::styled(&splash.current_message, Style::default().fg(Color::White)),
+        Span::styled(format!("{} ", spinner), splash.theme.warning()),
+        Span::styled(&splash.current_message, splash.theme.text()),
     ]);
 
     let paragraph = Paragraph::new(status).alignment(Alignment::Center);
@@ -0,0 +1,196 @@
+//! Color theme
+//!
+//! Maps semantic roles (title, header, selected, ...) to colors so rendering
+//! code never hardcodes a palette. Loaded from the `theme` section of the
+//! config file. In no-color mode every color is dropped and emphasis comes
+//! from bold and reverse video instead.
+
+use crate::config::ThemeConfig;
+use ratatui::style::{Color, Modifier, Style};
+use tracing::warn;
+
+/// Colors for each semantic role in the UI
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Theme {
+    /// Popup titles, borders and the current resource
+    pub title: Color,
+    /// Table headers and key hints
+    pub header: Color,
+    /// Regular text
+    pub text: Color,
+    /// Labels, hints and secondary text
+    pub muted: Color,
+    /// Profile/region values and active selections in dialogs
+    pub accent: Color,
+    pub error: Color,
+    pub success: Color,
+    /// Loading and read-only indicators
+    pub warning: Color,
+    /// Selected table row
+    pub selected_fg: Color,
+    pub selected_bg: Color,
+    /// Text drawn on a colored background (breadcrumb, buttons, search matches)
+    pub badge_text: Color,
+    /// Render with the terminal's default colors only (`--no-color` / `NO_COLOR`)
+    pub monochrome: bool,
+}
+
+impl Default for Theme {
+    /// Dark terminal palette
+    fn default() -> Self {
+        Self {
+            title: Color::Cyan,
+            header: Color::Yellow,
+            text: Color::White,
+            muted: Color::DarkGray,
+            accent: Color::Magenta,
+            error: Color::Red,
+            success: Color::Green,
+            warning: Color::Yellow,
+            selected_fg: Color::White,
+            selected_bg: Color::DarkGray,
+            badge_text: Color::Black,
+            monochrome: false,
+        }
+    }
+}
+
+impl Theme {
+    /// Palette for light terminal backgrounds
+    pub fn light() -> Self {
+        Self {
+            title: Color::Blue,
+            header: Color::Rgb(150, 90, 0),
+            text: Color::Black,
+            muted: Color::Rgb(100, 100, 100),
+            accent: Color::Magenta,
+            error: Color::Red,
+            success: Color::Rgb(0, 130, 0),
+            warning: Color::Rgb(170, 110, 0),
+            selected_fg: Color::Black,
+            selected_bg: Color::Rgb(200, 200, 200),
+            badge_text: Color::White,
+            monochrome: false,
+        }
+    }
+
+    /// Build a theme from the config's preset and per-role overrides
+    pub fn from_config(config: &ThemeConfig) -> Self {
+        let mut theme = match config.preset.as_deref() {
+            None | Some("default") | Some("dark") => Self::default(),
+            Some("light") => Self::light(),
+            Some(other) => {
+                warn!("Unknown theme preset '{}', using default", other);
+                Self::default()
+            }
+        };
+
+        let overrides = [
+            (&config.title, &mut theme.title),
+            (&config.header, &mut theme.header),
+            (&config.text, &mut theme.text),
+            (&config.muted, &mut theme.muted),
+            (&config.accent, &mut theme.accent),
+            (&config.error, &mut theme.error),
+            (&config.success, &mut theme.success),
+            (&config.warning, &mut theme.warning),
+            (&config.selected_fg, &mut theme.selected_fg),
+            (&config.selected_bg, &mut theme.selected_bg),
+            (&config.badge_text, &mut theme.badge_text),
+        ];
+        for (value, slot) in overrides {
+            if let Some(value) = value {
+                match value.parse::<Color>() {
+                    Ok(color) => *slot = color,
+                    Err(_) => warn!("Invalid theme color '{}', keeping preset", value),
+                }
+            }
+        }
+
+        theme
+    }
+
+    /// Same palette with all colors dropped
+    pub fn monochrome(self) -> Self {
+        Self {
+            monochrome: true,
+            ..self
+        }
+    }
+
+    /// Foreground style for an arbitrary color (e.g., from a color map)
+    pub fn fg(&self, color: Color) -> Style {
+        if self.monochrome {
+            Style::default()
+        } else {
+            Style::default().fg(color)
+        }
+    }
+
+    /// Text on a colored background
+    pub fn badge(&self, bg: Color) -> Style {
+        if self.monochrome {
+            Style::default().add_modifier(Modifier::REVERSED)
+        } else {
+            Style::default().fg(self.badge_text).bg(bg)
+        }
+    }
+
+    /// Background fill for full-screen views
+    pub fn background(&self, bg: Color) -> Style {
+        if self.monochrome {
+            Style::default()
+        } else {
+            Style::default().bg(bg)
+        }
+    }
+
+    pub fn title(&self) -> Style {
+        self.fg(self.title)
+    }
+
+    pub fn header(&self) -> Style {
+        self.fg(self.header)
+    }
+
+    pub fn text(&self) -> Style {
+        self.fg(self.text)
+    }
+
+    pub fn muted(&self) -> Style {
+        self.fg(self.muted)
+    }
+
+    pub fn accent(&self) -> Style {
+        self.fg(self.accent)
+    }
+
+    pub fn error(&self) -> Style {
+        self.emphasized(self.error)
+    }
+
+    pub fn success(&self) -> Style {
+        self.fg(self.success)
+    }
+
+    pub fn warning(&self) -> Style {
+        self.emphasized(self.warning)
+    }
+
+    pub fn selected(&self) -> Style {
+        if self.monochrome {
+            Style::default().add_modifier(Modifier::REVERSED)
+        } else {
+            Style::default().fg(self.selected_fg).bg(self.selected_bg)
+        }
+    }
+
+    /// Colored, or bold when colors are off so the role still stands out
+    fn emphasized(&self, color: Color) -> Style {
+        if self.monochrome {
+            Style::default().add_modifier(Modifier::BOLD)
+        } else {
+            self.fg(color)
+        }
+    }
+}